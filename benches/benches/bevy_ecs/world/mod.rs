@@ -1,10 +1,12 @@
 use criterion::criterion_group;
 
 mod commands;
+mod snapshot;
 mod spawn;
 mod world_get;
 
 use commands::*;
+use snapshot::*;
 use spawn::*;
 use world_get::*;
 
@@ -24,6 +26,8 @@ criterion_group!(
     world_query_iter,
     world_query_for_each,
     world_spawn,
+    world_snapshot,
+    world_restore_snapshot,
     query_get_component_simple,
     query_get_component,
     query_get,
@@ -0,0 +1,55 @@
+use bevy_ecs::prelude::*;
+use criterion::{black_box, Criterion};
+use glam::*;
+
+#[derive(Component, Clone)]
+struct Transform(Mat4);
+
+#[derive(Component, Clone)]
+struct Velocity(Vec3);
+
+#[derive(Component, Clone)]
+#[component(storage = "SparseSet")]
+struct Boost(f32);
+
+fn setup(entity_count: u32) -> World {
+    let mut world = World::default();
+    world.register_rollback_component::<Transform>();
+    world.register_rollback_component::<Velocity>();
+    world.register_rollback_component::<Boost>();
+    world.spawn_batch(
+        (0..entity_count).map(|_| (Transform(Mat4::IDENTITY), Velocity(Vec3::X), Boost(1.0))),
+    );
+    world
+}
+
+pub fn world_snapshot(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("world_snapshot");
+    group.warm_up_time(std::time::Duration::from_millis(500));
+    group.measurement_time(std::time::Duration::from_secs(4));
+
+    for entity_count in (1..5).map(|i| 10_u32.pow(i)) {
+        group.bench_function(format!("{}_entities", entity_count), |bencher| {
+            let world = setup(entity_count);
+            bencher.iter(|| black_box(world.snapshot()));
+        });
+    }
+
+    group.finish();
+}
+
+pub fn world_restore_snapshot(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("world_restore_snapshot");
+    group.warm_up_time(std::time::Duration::from_millis(500));
+    group.measurement_time(std::time::Duration::from_secs(4));
+
+    for entity_count in (1..5).map(|i| 10_u32.pow(i)) {
+        group.bench_function(format!("{}_entities", entity_count), |bencher| {
+            let mut world = setup(entity_count);
+            let snapshot = world.snapshot();
+            bencher.iter(|| black_box(world.restore_snapshot(&snapshot)));
+        });
+    }
+
+    group.finish();
+}
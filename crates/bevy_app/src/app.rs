@@ -1,17 +1,17 @@
 use crate::{CoreStage, Plugin, PluginGroup, StartupSchedule, StartupStage};
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
-    event::{Event, Events},
+    event::{Event, EventStorage, Events},
     prelude::FromWorld,
     schedule::{
         IntoSystemDescriptor, Schedule, ShouldRun, Stage, StageLabel, State, StateData, SystemSet,
         SystemStage,
     },
-    system::Resource,
+    system::{AsyncSystems, AsyncWorld, Resource},
     world::World,
 };
 use bevy_utils::{tracing::debug, HashMap, HashSet};
-use std::fmt::Debug;
+use std::{fmt::Debug, future::Future};
 
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
@@ -681,6 +681,74 @@ impl App {
         self
     }
 
+    /// Setup the application to manage events of type `T`, stored as selected by `storage`.
+    ///
+    /// This is the same as [`add_event`](Self::add_event), except that events can be kept until
+    /// every [`EventReader`](bevy_ecs::event::EventReader) read them with
+    /// [`EventStorage::Persistent`]. This is useful for events read by systems that do not run
+    /// every frame, such as the systems of a fixed timestep stage.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::{prelude::*, event::EventStorage};
+    /// #
+    /// # struct Collision;
+    /// # let mut app = App::new();
+    /// #
+    /// app.add_event_with_storage::<Collision>(EventStorage::Persistent { max_age: Some(60) });
+    /// ```
+    pub fn add_event_with_storage<T>(&mut self, storage: EventStorage) -> &mut Self
+    where
+        T: Event,
+    {
+        self.add_event::<T>();
+        self.world.resource_mut::<Events<T>>().set_storage(storage);
+        self
+    }
+
+    /// Spawns an async system, polled once per frame until it finishes.
+    ///
+    /// The first call adds the [`AsyncSystems`] resource, and inserts its
+    /// [`poll_system`](AsyncSystems::poll_system) into [`CoreStage::Update`].
+    /// Regular systems can spawn async systems through `ResMut<AsyncSystems>`.
+    ///
+    /// [`AsyncWorld::sleep`] waits on a clock advanced by `TimePlugin`, and never finishes
+    /// without it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::AsyncWorld;
+    /// # use bevy_utils::Duration;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Enemy;
+    /// # let mut app = App::new();
+    /// #
+    /// app.add_async_system(|world: AsyncWorld| async move {
+    ///     world.sleep(Duration::from_secs(2)).await;
+    ///     world.with(|world| {
+    ///         world.spawn(Enemy);
+    ///     });
+    /// });
+    /// ```
+    pub fn add_async_system<S, Fut>(&mut self, system: S) -> &mut Self
+    where
+        S: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        if !self.world.contains_resource::<AsyncSystems>() {
+            self.init_resource::<AsyncSystems>()
+                .add_system_to_stage(CoreStage::Update, AsyncSystems::poll_system);
+        }
+        self.world.resource_mut::<AsyncSystems>().spawn(system);
+        self
+    }
+
     /// Inserts a [`Resource`] to the current [`App`] and overwrites any [`Resource`] previously added of the same type.
     ///
     /// A [`Resource`] in Bevy represents globally unique data. [`Resource`]s must be added to Bevy apps
@@ -1103,4 +1171,32 @@ mod tests {
     fn can_add_twice_the_same_plugin_not_unique() {
         App::new().add_plugin(PluginD).add_plugin(PluginD);
     }
+
+    #[test]
+    fn async_systems_run_once_per_update() {
+        use bevy_ecs::system::{AsyncSystems, AsyncWorld, Resource};
+
+        #[derive(Resource, Default)]
+        struct Counter(u32);
+
+        async fn count(world: AsyncWorld, frames: usize) {
+            for _ in 0..frames {
+                world.with(|world| world.resource_mut::<Counter>().0 += 1);
+                world.next_frame().await;
+            }
+        }
+
+        let mut app = App::new();
+        app.init_resource::<Counter>()
+            .add_async_system(|world| count(world, 2))
+            .add_async_system(|world| count(world, 3));
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 2);
+        app.update();
+        app.update();
+        assert_eq!(app.world.resource::<Counter>().0, 5);
+        assert_eq!(app.world.resource::<AsyncSystems>().len(), 1);
+        app.update();
+        assert!(app.world.resource::<AsyncSystems>().is_empty());
+    }
 }
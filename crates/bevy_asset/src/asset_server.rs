@@ -97,7 +97,7 @@ pub struct AssetServerInternal {
 /// use bevy_asset::{AssetServer, Handle};
 /// use bevy_ecs::prelude::{Commands, Res};
 ///
-/// # #[derive(Debug, bevy_reflect::TypeUuid)]
+/// # #[derive(Debug, bevy_reflect::TypeUuid, bevy_reflect::TypePath)]
 /// # #[uuid = "00000000-0000-0000-0000-000000000000"]
 /// # struct Image;
 ///
@@ -608,18 +608,30 @@ impl AssetServer {
                         }
                     }
 
+                    // an evicted asset that was loaded again because it was accessed through a
+                    // Weak handle can be evicted again
+                    let reloaded = assets.is_evicted(&Handle::weak(result.id));
                     assets.set_untracked(result.id, *result.asset);
+                    if reloaded
+                        && !self
+                            .server
+                            .asset_ref_counter
+                            .ref_counts
+                            .read()
+                            .get(&result.id)
+                            .map_or(false, |&count| count > 0)
+                    {
+                        assets.mark_evictable(result.id);
+                    }
                 }
                 Ok(AssetLifecycleEvent::Free(handle_id)) => {
-                    if let HandleId::AssetPathId(id) = handle_id {
-                        let asset_sources = asset_sources_guard
-                            .get_or_insert_with(|| self.server.asset_sources.write());
-                        if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
-                            source_info.committed_assets.remove(&id.label_id());
-                            source_info.load_state = LoadState::Unloaded;
-                        }
+                    // with a memory budget, the asset is kept loaded until it gets evicted
+                    if assets.mark_evictable(handle_id) {
+                        continue;
                     }
-                    assets.remove(handle_id);
+                    let asset_sources = asset_sources_guard
+                        .get_or_insert_with(|| self.server.asset_sources.write());
+                    Self::unload_asset(asset_sources, &mut assets, handle_id);
                 }
                 Err(TryRecvError::Empty) => {
                     break;
@@ -627,6 +639,42 @@ impl AssetServer {
                 Err(TryRecvError::Disconnected) => panic!("AssetChannel disconnected."),
             }
         }
+
+        // evict the least recently used assets until the memory budget is respected
+        let ref_counts = self.server.asset_ref_counter.ref_counts.read();
+        while let Some(handle_id) = assets.pop_evictable() {
+            // the asset got a new Strong handle since it was marked as evictable
+            if ref_counts.get(&handle_id).map_or(false, |&count| count > 0) {
+                continue;
+            }
+            let asset_sources =
+                asset_sources_guard.get_or_insert_with(|| self.server.asset_sources.write());
+            Self::unload_asset(asset_sources, &mut assets, handle_id);
+            assets.mark_evicted(handle_id);
+        }
+        drop(ref_counts);
+        drop(asset_sources_guard);
+
+        // load again the evicted assets that were accessed
+        for handle_id in assets.take_reload_requests() {
+            if let Some(asset_path) = self.get_handle_path(handle_id) {
+                self.load_untracked(asset_path, false);
+            }
+        }
+    }
+
+    fn unload_asset<T: Asset>(
+        asset_sources: &mut HashMap<SourcePathId, SourceInfo>,
+        assets: &mut Assets<T>,
+        handle_id: HandleId,
+    ) {
+        if let HandleId::AssetPathId(id) = handle_id {
+            if let Some(source_info) = asset_sources.get_mut(&id.source_path_id()) {
+                source_info.committed_assets.remove(&id.label_id());
+                source_info.load_state = LoadState::Unloaded;
+            }
+        }
+        assets.remove(handle_id);
     }
 }
 
@@ -646,13 +694,19 @@ mod test {
     use crate::{loader::LoadedAsset, update_asset_storage_system};
     use bevy_app::App;
     use bevy_ecs::prelude::*;
-    use bevy_reflect::TypeUuid;
+    use bevy_reflect::{TypePath, TypeUuid};
     use bevy_utils::BoxedFuture;
 
-    #[derive(Debug, TypeUuid)]
+    #[derive(Debug, TypeUuid, TypePath)]
     #[uuid = "a5189b72-0572-4290-a2e0-96f73a491c44"]
     struct PngAsset;
 
+    impl crate::AssetSize for PngAsset {
+        fn approximate_size(&self) -> usize {
+            10
+        }
+    }
+
     struct FakePngLoader;
     impl AssetLoader for FakePngLoader {
         fn load<'a>(
@@ -917,6 +971,188 @@ mod test {
         assert!(get_asset(&handle, &app.world).is_some());
     }
 
+    #[test]
+    fn test_asset_memory_budget() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["a.png", "b.png", "c.png"] {
+            std::fs::write(dir.path().join(file), []).unwrap();
+        }
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        let mut assets = asset_server.register_asset_type::<PngAsset>();
+        assets.set_memory_budget(25);
+
+        #[derive(SystemLabel, Clone, Hash, Debug, PartialEq, Eq)]
+        struct FreeUnusedAssets;
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server);
+        app.add_system(free_unused_assets_system.label(FreeUnusedAssets));
+        app.add_system(update_asset_storage_system::<PngAsset>.after(FreeUnusedAssets));
+
+        fn load_asset(path: &str, world: &World) -> Handle<PngAsset> {
+            let asset_server = world.resource::<AssetServer>();
+            let id = futures_lite::future::block_on(asset_server.load_async(path.into(), false))
+                .unwrap();
+            asset_server.get_handle(id)
+        }
+
+        fn is_loaded(path: &str, world: &World) -> bool {
+            let id: HandleId = AssetPath::from(path).into();
+            world
+                .resource::<Assets<PngAsset>>()
+                .contains(&Handle::weak(id))
+        }
+
+        let a = load_asset("a.png", &app.world);
+        let b = load_asset("b.png", &app.world);
+        app.update();
+        assert_eq!(
+            Some(20),
+            app.world.resource::<Assets<PngAsset>>().memory_usage()
+        );
+
+        // unused assets are kept loaded while under budget
+        drop(a);
+        app.update();
+        app.update();
+        assert!(is_loaded("a.png", &app.world));
+
+        // exceeding the budget evicts the least recently used asset
+        let c = load_asset("c.png", &app.world);
+        app.update();
+        assert!(!is_loaded("a.png", &app.world));
+        assert!(is_loaded("b.png", &app.world));
+        assert!(is_loaded("c.png", &app.world));
+        assert_eq!(
+            Some(20),
+            app.world.resource::<Assets<PngAsset>>().memory_usage()
+        );
+
+        // evicted assets are loaded again on request
+        let a = load_asset("a.png", &app.world);
+        assert_eq!(
+            LoadState::Loading,
+            app.world.resource::<AssetServer>().get_load_state(&a)
+        );
+        app.update();
+        assert!(is_loaded("a.png", &app.world));
+
+        // assets with Strong handles are never evicted
+        drop(b);
+        app.update();
+        app.update();
+        assert!(!is_loaded("b.png", &app.world));
+        assert!(is_loaded("a.png", &app.world));
+        assert!(is_loaded("c.png", &app.world));
+        drop(c);
+    }
+
+    #[test]
+    fn test_asset_memory_budget_recency() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["a.png", "b.png", "c.png"] {
+            std::fs::write(dir.path().join(file), []).unwrap();
+        }
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        let mut assets = asset_server.register_asset_type::<PngAsset>();
+        assets.set_memory_budget(25);
+
+        #[derive(SystemLabel, Clone, Hash, Debug, PartialEq, Eq)]
+        struct FreeUnusedAssets;
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server);
+        app.add_system(free_unused_assets_system.label(FreeUnusedAssets));
+        app.add_system(update_asset_storage_system::<PngAsset>.after(FreeUnusedAssets));
+
+        let load_asset = |path: &str, world: &World| {
+            let asset_server = world.resource::<AssetServer>();
+            let id = futures_lite::future::block_on(asset_server.load_async(path.into(), false))
+                .unwrap();
+            asset_server.get_handle::<PngAsset, _>(id)
+        };
+
+        let a = load_asset("a.png", &app.world);
+        let b = load_asset("b.png", &app.world);
+        app.update();
+        let weak_a = a.clone_weak();
+        let weak_b = b.clone_weak();
+        drop(a);
+        drop(b);
+        app.update();
+        app.update();
+
+        // `a` lost its Strong handle first, but was accessed since then
+        assert!(app
+            .world
+            .resource::<Assets<PngAsset>>()
+            .get(&weak_a)
+            .is_some());
+        let _c = load_asset("c.png", &app.world);
+        app.update();
+
+        let assets = app.world.resource::<Assets<PngAsset>>();
+        assert!(assets.contains(&weak_a));
+        assert!(!assets.contains(&weak_b));
+    }
+
+    #[test]
+    fn test_asset_memory_budget_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        for file in ["a.png", "b.png"] {
+            std::fs::write(dir.path().join(file), []).unwrap();
+        }
+        let asset_server = setup(dir.path());
+        asset_server.add_loader(FakePngLoader);
+        let mut assets = asset_server.register_asset_type::<PngAsset>();
+        assets.set_memory_budget(15);
+
+        #[derive(SystemLabel, Clone, Hash, Debug, PartialEq, Eq)]
+        struct FreeUnusedAssets;
+        let mut app = App::new();
+        app.insert_resource(assets);
+        app.insert_resource(asset_server);
+        app.add_system(free_unused_assets_system.label(FreeUnusedAssets));
+        app.add_system(update_asset_storage_system::<PngAsset>.after(FreeUnusedAssets));
+
+        fn update_until_loaded(app: &mut App, handle: &Handle<PngAsset>) {
+            for _ in 0..1000 {
+                app.update();
+                if app.world.resource::<Assets<PngAsset>>().contains(handle) {
+                    return;
+                }
+                std::thread::sleep(std::time::Duration::from_millis(1));
+            }
+            panic!("asset was not loaded");
+        }
+
+        let a = app.world.resource::<AssetServer>().load("a.png");
+        update_until_loaded(&mut app, &a);
+        let weak_a = a.clone_weak();
+        drop(a);
+        let b = app.world.resource::<AssetServer>().load("b.png");
+        update_until_loaded(&mut app, &b);
+        app.update();
+
+        let assets = app.world.resource::<Assets<PngAsset>>();
+        assert!(!assets.contains(&weak_a));
+        assert!(assets.is_evicted(&weak_a));
+
+        // accessing an evicted asset loads it again
+        assert!(assets.get(&weak_a).is_none());
+        drop(b);
+        update_until_loaded(&mut app, &weak_a);
+        assert!(!app.world.resource::<Assets<PngAsset>>().is_evicted(&weak_a));
+
+        // the asset only has a Weak handle, so it can be evicted again
+        let b = app.world.resource::<AssetServer>().load("b.png");
+        update_until_loaded(&mut app, &b);
+        app.update();
+        assert!(app.world.resource::<Assets<PngAsset>>().is_evicted(&weak_a));
+    }
+
     #[test]
     fn test_get_handle_path() {
         const PATH: &str = "path/file.png";
@@ -1,6 +1,6 @@
 use crate::{
-    update_asset_storage_system, Asset, AssetLoader, AssetServer, AssetStage, Handle, HandleId,
-    RefChange, ReflectAsset, ReflectHandle,
+    serialized_handle::reflect_deserialize_handle, update_asset_storage_system, Asset, AssetLoader,
+    AssetServer, AssetStage, Handle, HandleId, RefChange, ReflectAsset, ReflectHandle,
 };
 use bevy_app::{App, AppTypeRegistry};
 use bevy_ecs::{
@@ -9,9 +9,14 @@ use bevy_ecs::{
     world::FromWorld,
 };
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect};
-use bevy_utils::HashMap;
+use bevy_utils::{HashMap, HashSet};
 use crossbeam_channel::Sender;
-use std::fmt::Debug;
+use parking_lot::Mutex;
+use std::{
+    any::TypeId,
+    fmt::Debug,
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 /// Events that involve assets of type `T`.
 ///
@@ -54,6 +59,115 @@ impl<T: Asset> Debug for AssetEvent<T> {
     }
 }
 
+/// Reports the approximate amount of memory held by an asset.
+///
+/// This is used by [`Assets`] to enforce a memory budget, see [`Assets::set_memory_budget`].
+pub trait AssetSize {
+    /// Returns the approximate size of this asset in bytes.
+    fn approximate_size(&self) -> usize;
+}
+
+/// The maximum number of accesses since the last access to an asset that can be told apart.
+///
+/// Older access times are clamped to this age so that the wrapping `clock` can't make them look
+/// recent.
+const MAX_ACCESS_AGE: u32 = u32::MAX / 2;
+
+/// Memory accounting for an [`Assets`] collection with a budget.
+#[derive(Debug)]
+struct MemoryBudget<T> {
+    limit: usize,
+    size_of: fn(&T) -> usize,
+    sizes: HashMap<HandleId, usize>,
+    total: usize,
+    /// Assets without Strong handles that are kept loaded until the budget is exceeded.
+    evictable: HashSet<HandleId>,
+    /// Assets that were unloaded to respect the budget and haven't been loaded again.
+    evicted: HashSet<HandleId>,
+    /// Evicted assets that were accessed since the last update and should be loaded again.
+    reload_requests: Mutex<HashSet<HandleId>>,
+    /// The time of the last access to each asset, as counted by `clock`.
+    last_access: HashMap<HandleId, AtomicU32>,
+    clock: AtomicU32,
+}
+
+impl<T> MemoryBudget<T> {
+    fn new(limit: usize, size_of: fn(&T) -> usize) -> Self {
+        Self {
+            limit,
+            size_of,
+            sizes: HashMap::default(),
+            total: 0,
+            evictable: HashSet::default(),
+            evicted: HashSet::default(),
+            reload_requests: Mutex::default(),
+            last_access: HashMap::default(),
+            clock: AtomicU32::new(0),
+        }
+    }
+
+    fn track(&mut self, id: HandleId, asset: &T) {
+        let size = (self.size_of)(asset);
+        if let Some(old_size) = self.sizes.insert(id, size) {
+            self.total -= old_size;
+        }
+        self.total += size;
+        self.evicted.remove(&id);
+        let now = self.clock.fetch_add(1, Ordering::Relaxed);
+        self.last_access.insert(id, AtomicU32::new(now));
+    }
+
+    fn untrack(&mut self, id: HandleId) {
+        if let Some(size) = self.sizes.remove(&id) {
+            self.total -= size;
+        }
+        self.evictable.remove(&id);
+        self.last_access.remove(&id);
+    }
+
+    /// Marks the asset as the most recently used one, or requests it to be loaded again if it was
+    /// evicted.
+    ///
+    /// This only takes a shared reference, so that [`Assets::get`] can record accesses.
+    fn touch(&self, id: &HandleId) {
+        if let Some(last_access) = self.last_access.get(id) {
+            let now = self.clock.fetch_add(1, Ordering::Relaxed);
+            last_access.store(now, Ordering::Relaxed);
+        } else if self.evicted.contains(id) {
+            self.reload_requests.lock().insert(*id);
+        }
+    }
+
+    /// Returns the number of accesses since the last access to the asset.
+    fn access_age(&self, id: &HandleId) -> u32 {
+        let now = self.clock.load(Ordering::Relaxed);
+        self.last_access
+            .get(id)
+            .map_or(MAX_ACCESS_AGE, |last_access| {
+                now.wrapping_sub(last_access.load(Ordering::Relaxed))
+            })
+    }
+
+    /// Clamps the access times that are older than [`MAX_ACCESS_AGE`].
+    fn check_access_ages(&mut self) {
+        let now = *self.clock.get_mut();
+        for last_access in self.last_access.values_mut() {
+            if now.wrapping_sub(*last_access.get_mut()) > MAX_ACCESS_AGE {
+                *last_access.get_mut() = now.wrapping_sub(MAX_ACCESS_AGE);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.sizes.clear();
+        self.total = 0;
+        self.evictable.clear();
+        self.evicted.clear();
+        self.reload_requests.get_mut().clear();
+        self.last_access.clear();
+    }
+}
+
 /// Stores Assets of a given type and tracks changes to them.
 ///
 /// Each asset is mapped by a unique [`HandleId`], allowing any [`Handle`] with the same
@@ -67,10 +181,24 @@ impl<T: Asset> Debug for AssetEvent<T> {
 /// Remember, if there are no Strong handles for an asset (i.e. they have all been dropped), the
 /// asset will unload. Make sure you always have a Strong handle when you want to keep an asset
 /// loaded!
+///
+/// # Memory budget
+///
+/// A collection can be given a memory budget with [`Assets::set_memory_budget`]. Assets loaded by
+/// the [`AssetServer`] are then kept loaded after their last Strong handle is dropped, and are
+/// only unloaded, least recently used first, once the total size of the collection exceeds the
+/// budget. Accessing an asset with [`Assets::get`] or [`Assets::get_mut`] marks it as recently
+/// used.
+///
+/// Only assets that are referenced by Weak handles alone can be unloaded. Accessing an unloaded
+/// asset with [`Assets::get`] returns `None` and queues it to be loaded again by the
+/// [`AssetServer`], which makes it available again in a later frame. Use [`Assets::is_evicted`]
+/// to tell such assets apart from assets that were never loaded.
 #[derive(Debug, Resource)]
 pub struct Assets<T: Asset> {
     assets: HashMap<HandleId, T>,
     events: Events<AssetEvent<T>>,
+    memory_budget: Option<MemoryBudget<T>>,
     pub(crate) ref_change_sender: Sender<RefChange>,
 }
 
@@ -79,6 +207,7 @@ impl<T: Asset> Assets<T> {
         Assets {
             assets: HashMap::default(),
             events: Events::default(),
+            memory_budget: None,
             ref_change_sender,
         }
     }
@@ -90,6 +219,9 @@ impl<T: Asset> Assets<T> {
     /// * [`AssetEvent::Created`]
     pub fn add(&mut self, asset: T) -> Handle<T> {
         let id = HandleId::random::<T>();
+        if let Some(budget) = &mut self.memory_budget {
+            budget.track(id, &asset);
+        }
         self.assets.insert(id, asset);
         self.events.send(AssetEvent::Created {
             handle: Handle::weak(id),
@@ -121,6 +253,9 @@ impl<T: Asset> Assets<T> {
     /// * [`AssetEvent::Modified`]: Sent if the asset with given handle already existed.
     pub fn set_untracked<H: Into<HandleId>>(&mut self, handle: H, asset: T) {
         let id: HandleId = handle.into();
+        if let Some(budget) = &mut self.memory_budget {
+            budget.track(id, &asset);
+        }
         if self.assets.insert(id, asset).is_some() {
             self.events.send(AssetEvent::Modified {
                 handle: Handle::weak(id),
@@ -136,8 +271,16 @@ impl<T: Asset> Assets<T> {
     ///
     /// This is the main method for accessing asset data from an [Assets] collection. If you need
     /// mutable access to the asset, use [`get_mut`](Assets::get_mut).
+    ///
+    /// With a [memory budget](Assets::set_memory_budget), this returns `None` for an asset that
+    /// has been unloaded since its last Strong handle was dropped, and queues it to be loaded
+    /// again.
     pub fn get(&self, handle: &Handle<T>) -> Option<&T> {
-        self.assets.get(&handle.into())
+        let id: HandleId = handle.into();
+        if let Some(budget) = &self.memory_budget {
+            budget.touch(&id);
+        }
+        self.assets.get(&id)
     }
 
     /// Checks if an asset exists for the given handle
@@ -145,18 +288,123 @@ impl<T: Asset> Assets<T> {
         self.assets.contains_key(&handle.into())
     }
 
+    /// Returns `true` if the asset for the given handle was unloaded to respect the
+    /// [memory budget](Assets::set_memory_budget) and hasn't been loaded again yet.
+    pub fn is_evicted(&self, handle: &Handle<T>) -> bool {
+        self.memory_budget
+            .as_ref()
+            .map_or(false, |budget| budget.evicted.contains(&handle.into()))
+    }
+
     /// Get mutable access to the asset for the given handle.
     ///
     /// This is the main method for mutably accessing asset data from an [Assets] collection. If you
     /// do not need mutable access to the asset, you may also use [get](Assets::get).
     pub fn get_mut(&mut self, handle: &Handle<T>) -> Option<&mut T> {
         let id: HandleId = handle.into();
+        if let Some(budget) = &self.memory_budget {
+            budget.touch(&id);
+        }
         self.events.send(AssetEvent::Modified {
             handle: Handle::weak(id),
         });
         self.assets.get_mut(&id)
     }
 
+    /// Sets the memory budget of this collection to `limit` bytes, as reported by
+    /// [`AssetSize::approximate_size`].
+    ///
+    /// Assets loaded through the [`AssetServer`] that no longer have Strong handles are kept in
+    /// memory until the budget is exceeded. Assets with Strong handles are never unloaded, so the
+    /// total size of the collection may still exceed the budget.
+    ///
+    /// Sizes are measured when an asset is added, and at the end of the frame for assets that were
+    /// accessed mutably.
+    pub fn set_memory_budget(&mut self, limit: usize)
+    where
+        T: AssetSize,
+    {
+        if let Some(budget) = &mut self.memory_budget {
+            budget.limit = limit;
+            return;
+        }
+        let mut budget = MemoryBudget::new(limit, T::approximate_size);
+        for (id, asset) in &self.assets {
+            budget.track(*id, asset);
+        }
+        self.memory_budget = Some(budget);
+    }
+
+    /// Returns the memory budget of this collection in bytes, if one was set.
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget.as_ref().map(|budget| budget.limit)
+    }
+
+    /// Returns the approximate size of all assets in this collection in bytes, if a memory budget
+    /// was set.
+    pub fn memory_usage(&self) -> Option<usize> {
+        self.memory_budget.as_ref().map(|budget| budget.total)
+    }
+
+    /// Keeps an asset without Strong handles loaded until the memory budget is exceeded.
+    ///
+    /// Returns `false` if this collection has no budget or the asset can't be reloaded, in which
+    /// case it should be freed right away.
+    pub(crate) fn mark_evictable(&mut self, id: HandleId) -> bool {
+        match &mut self.memory_budget {
+            Some(budget)
+                if matches!(id, HandleId::AssetPathId(_)) && self.assets.contains_key(&id) =>
+            {
+                budget.evictable.insert(id);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the least recently used evictable asset if the memory budget is exceeded.
+    pub(crate) fn pop_evictable(&mut self) -> Option<HandleId> {
+        let budget = self.memory_budget.as_mut()?;
+        if budget.total <= budget.limit {
+            return None;
+        }
+        budget.check_access_ages();
+        let id = *budget
+            .evictable
+            .iter()
+            .max_by_key(|id| budget.access_age(id))?;
+        budget.evictable.remove(&id);
+        Some(id)
+    }
+
+    /// Records that an asset was unloaded to respect the memory budget.
+    pub(crate) fn mark_evicted(&mut self, id: HandleId) {
+        if let Some(budget) = &mut self.memory_budget {
+            budget.evicted.insert(id);
+        }
+    }
+
+    /// Takes the evicted assets that were accessed since the last call.
+    pub(crate) fn take_reload_requests(&mut self) -> Vec<HandleId> {
+        match &mut self.memory_budget {
+            Some(budget) => budget.reload_requests.get_mut().drain().collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Measures again the size of assets that may have been modified.
+    fn refresh_memory_usage(&mut self) {
+        if let Some(budget) = &mut self.memory_budget {
+            for event in self.events.iter_current_update_events() {
+                if let AssetEvent::Modified { handle } = event {
+                    if let Some(asset) = self.assets.get(&handle.id()) {
+                        budget.track(handle.id(), asset);
+                    }
+                }
+            }
+        }
+    }
+
     /// Gets a _Strong_ handle pointing to the same asset as the given one.
     pub fn get_handle<H: Into<HandleId>>(&self, handle: H) -> Handle<T> {
         Handle::strong(handle.into(), self.ref_change_sender.clone())
@@ -174,11 +422,16 @@ impl<T: Asset> Assets<T> {
     ) -> &mut T {
         let mut event = None;
         let id: HandleId = handle.into();
+        let budget = &mut self.memory_budget;
         let borrowed = self.assets.entry(id).or_insert_with(|| {
+            let asset = insert_fn();
+            if let Some(budget) = budget {
+                budget.track(id, &asset);
+            }
             event = Some(AssetEvent::Created {
                 handle: Handle::weak(id),
             });
-            insert_fn()
+            asset
         });
 
         if let Some(event) = event {
@@ -216,6 +469,9 @@ impl<T: Asset> Assets<T> {
     /// * [`AssetEvent::Removed`]
     pub fn remove<H: Into<HandleId>>(&mut self, handle: H) -> Option<T> {
         let id: HandleId = handle.into();
+        if let Some(budget) = &mut self.memory_budget {
+            budget.untrack(id);
+        }
         let asset = self.assets.remove(&id);
         if asset.is_some() {
             self.events.send(AssetEvent::Removed {
@@ -230,6 +486,9 @@ impl<T: Asset> Assets<T> {
     /// Keeps the allocated memory for reuse.
     pub fn clear(&mut self) {
         self.assets.clear();
+        if let Some(budget) = &mut self.memory_budget {
+            budget.clear();
+        }
     }
 
     /// Reserves capacity for at least additional more elements to be inserted into the assets.
@@ -256,6 +515,7 @@ impl<T: Asset> Assets<T> {
         // Check if the events are empty before calling `drain`.
         // As `drain` triggers change detection.
         if !assets.events.is_empty() {
+            assets.refresh_memory_usage();
             events.send_batch(assets.events.drain());
         }
     }
@@ -319,6 +579,14 @@ pub trait AddAsset {
     fn add_asset_loader<T>(&mut self, loader: T) -> &mut Self
     where
         T: AssetLoader;
+
+    /// Registers `T` as a supported asset in the application, with a memory budget of `budget`
+    /// bytes.
+    ///
+    /// See [`Assets::set_memory_budget`].
+    fn add_asset_with_memory_budget<T>(&mut self, budget: usize) -> &mut Self
+    where
+        T: Asset + AssetSize;
 }
 
 impl AddAsset for App {
@@ -338,7 +606,20 @@ impl AddAsset for App {
             .add_system_to_stage(AssetStage::AssetEvents, Assets::<T>::asset_event_system)
             .add_system_to_stage(AssetStage::LoadAssets, update_asset_storage_system::<T>)
             .register_type::<Handle<T>>()
-            .add_event::<AssetEvent<T>>()
+            .add_event::<AssetEvent<T>>();
+
+        {
+            let type_registry = self.world.resource::<AppTypeRegistry>();
+            let mut type_registry = type_registry.write();
+
+            type_registry.register_type_data::<Handle<T>, ReflectHandle>();
+            type_registry
+                .get_mut(TypeId::of::<Handle<T>>())
+                .unwrap()
+                .insert(reflect_deserialize_handle::<T>());
+        }
+
+        self
     }
 
     fn register_asset_reflect<T>(&mut self) -> &mut Self
@@ -403,6 +684,17 @@ impl AddAsset for App {
         self.world.resource_mut::<AssetServer>().add_loader(loader);
         self
     }
+
+    fn add_asset_with_memory_budget<T>(&mut self, budget: usize) -> &mut Self
+    where
+        T: Asset + AssetSize,
+    {
+        self.add_asset::<T>();
+        self.world
+            .resource_mut::<Assets<T>>()
+            .set_memory_budget(budget);
+        self
+    }
 }
 
 /// Loads an internal asset.
@@ -489,7 +781,7 @@ mod tests {
 
     #[test]
     fn asset_overwriting() {
-        #[derive(bevy_reflect::TypeUuid)]
+        #[derive(bevy_reflect::TypeUuid, bevy_reflect::TypePath)]
         #[uuid = "44115972-f31b-46e5-be5c-2b9aece6a52f"]
         struct MyAsset;
         let mut app = App::new();
@@ -26,6 +26,7 @@ mod io;
 mod loader;
 mod path;
 mod reflect;
+mod serialized_handle;
 
 /// The `bevy_asset` prelude.
 pub mod prelude {
@@ -45,6 +46,7 @@ pub use io::*;
 pub use loader::*;
 pub use path::*;
 pub use reflect::*;
+pub use serialized_handle::*;
 
 use bevy_app::{prelude::Plugin, App};
 use bevy_ecs::schedule::{StageLabel, SystemStage};
@@ -115,6 +117,7 @@ impl Plugin for AssetPlugin {
             SystemStage::parallel(),
         )
         .register_type::<HandleId>()
+        .register_type::<SerializedHandle>()
         .add_system_to_stage(
             bevy_app::CoreStage::PreUpdate,
             asset_server::free_unused_assets_system,
@@ -214,7 +214,7 @@ pub struct ReflectHandle {
     type_uuid: Uuid,
     asset_type_id: TypeId,
     downcast_handle_untyped: fn(&dyn Any) -> Option<HandleUntyped>,
-    typed: fn(HandleUntyped) -> Box<dyn Reflect>,
+    pub(crate) typed: fn(HandleUntyped) -> Box<dyn Reflect>,
 }
 impl ReflectHandle {
     /// The [`bevy_reflect::TypeUuid`] of the asset
@@ -0,0 +1,391 @@
+use std::{fmt, marker::PhantomData};
+
+use bevy_reflect::{
+    DynamicArray, DynamicEnum, DynamicList, DynamicMap, DynamicStruct, DynamicTuple,
+    DynamicTupleStruct, DynamicVariant, Reflect, ReflectDeserialize, ReflectRef, ReflectSerialize,
+    TypeRegistry, VariantType,
+};
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::SerializeStruct,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    Asset, AssetPath, AssetServer, Handle, HandleId, HandleUntyped, LoadContext, ReflectHandle,
+};
+
+const HANDLE_STRUCT: &str = "Handle";
+const HANDLE_FIELD_ID: &str = "id";
+const HANDLE_FIELD_PATH: &str = "path";
+
+/// A [`Handle`] that refers to its asset by [`AssetPath`] instead of by [`HandleId`].
+///
+/// The [`HandleId`] of an asset loaded from a path is a hash of that path, which can't be turned
+/// back into the path it came from. A handle serialized as-is can thus not be used to load its
+/// asset again in another session.
+///
+/// [`AssetServer::serialize_handles`] replaces the handles of a reflected value with
+/// `SerializedHandle`s, which serialize as `(path: "path/to/asset.ext#label")`. Deserializing a
+/// [`Handle`] in this form through the type registry produces a `SerializedHandle` again, which
+/// can be turned back into a [`Handle`] with [`resolve_serialized_handles`].
+#[derive(Clone, Reflect)]
+#[reflect_value(Serialize)]
+pub struct SerializedHandle {
+    path: AssetPath<'static>,
+    typed: fn(HandleUntyped) -> Box<dyn Reflect>,
+}
+
+impl SerializedHandle {
+    /// The path of the asset this handle refers to.
+    pub fn path(&self) -> &AssetPath<'static> {
+        &self.path
+    }
+
+    /// Converts `handle` into the [`Handle<T>`] type this handle was serialized from.
+    pub fn typed(&self, handle: HandleUntyped) -> Box<dyn Reflect> {
+        (self.typed)(handle)
+    }
+}
+
+impl fmt::Debug for SerializedHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SerializedHandle")
+            .field("path", &self.path)
+            .finish()
+    }
+}
+
+impl Serialize for SerializedHandle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(HANDLE_STRUCT, 1)?;
+        state.serialize_field(HANDLE_FIELD_PATH, &self.path.to_string())?;
+        state.end()
+    }
+}
+
+impl AssetServer {
+    /// Returns a copy of `value` in which every [`Handle`] to an asset loaded from a path is
+    /// replaced by a [`SerializedHandle`].
+    ///
+    /// Handles are found through the [`ReflectHandle`] type data registered by
+    /// [`AddAsset::add_asset`](crate::AddAsset::add_asset). Handles to assets that weren't
+    /// loaded from a path are kept as they are.
+    pub fn serialize_handles(
+        &self,
+        value: &dyn Reflect,
+        type_registry: &TypeRegistry,
+    ) -> Box<dyn Reflect> {
+        map_reflect(value, &mut |value| {
+            let reflect_handle = type_registry
+                .get_with_name(value.type_name())?
+                .data::<ReflectHandle>()?;
+            let id = match value.reflect_ref() {
+                ReflectRef::Struct(handle) => {
+                    *handle.field(HANDLE_FIELD_ID)?.downcast_ref::<HandleId>()?
+                }
+                _ => return None,
+            };
+            let path = self.get_handle_path(id)?;
+            Some(Box::new(SerializedHandle {
+                path: path.to_owned(),
+                typed: reflect_handle.typed,
+            }))
+        })
+    }
+}
+
+impl<'a> LoadContext<'a> {
+    /// Returns a copy of `value` in which every [`SerializedHandle`] is replaced by a handle to its
+    /// asset, and adds the path of these assets to `dependencies`.
+    ///
+    /// The assets are not loaded by this method: `dependencies` should be passed to
+    /// [`LoadedAsset::with_dependencies`](crate::LoadedAsset::with_dependencies).
+    pub fn resolve_serialized_handles(
+        &self,
+        value: &dyn Reflect,
+        dependencies: &mut Vec<AssetPath<'static>>,
+    ) -> Box<dyn Reflect> {
+        resolve_serialized_handles(value, |path| {
+            dependencies.push(path.to_owned());
+            self.get_handle_untyped(path.get_id())
+        })
+    }
+}
+
+/// Returns a copy of `value` in which every [`SerializedHandle`] is replaced by the handle
+/// returned by `get_handle` for its path.
+///
+/// With an [`AssetServer`], use [`AssetServer::load_untyped`] to load the assets.
+pub fn resolve_serialized_handles(
+    value: &dyn Reflect,
+    mut get_handle: impl FnMut(&AssetPath<'static>) -> HandleUntyped,
+) -> Box<dyn Reflect> {
+    map_reflect(value, &mut |value| {
+        let serialized = value.downcast_ref::<SerializedHandle>()?;
+        Some(serialized.typed(get_handle(&serialized.path)))
+    })
+}
+
+/// Creates the [`ReflectDeserialize`] type data of [`Handle<A>`].
+///
+/// Handles are deserialized from either their `id` or their `path`, the latter producing a
+/// [`SerializedHandle`].
+pub(crate) fn reflect_deserialize_handle<A: Asset>() -> ReflectDeserialize {
+    ReflectDeserialize {
+        func: |deserializer| {
+            deserializer.deserialize_struct(
+                HANDLE_STRUCT,
+                &[HANDLE_FIELD_ID, HANDLE_FIELD_PATH],
+                HandleVisitor::<A>(PhantomData),
+            )
+        },
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum HandleField {
+    Id,
+    Path,
+}
+
+struct HandleVisitor<A>(PhantomData<fn() -> A>);
+
+impl<'de, A: Asset> Visitor<'de> for HandleVisitor<A> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("handle struct with an `id` or a `path`")
+    }
+
+    fn visit_seq<V>(self, mut seq: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let id: HandleId = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        Ok(Box::new(Handle::<A>::weak(id)))
+    }
+
+    fn visit_map<V>(self, mut map: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        let value: Box<dyn Reflect> = match map.next_key()? {
+            Some(HandleField::Id) => Box::new(Handle::<A>::weak(map.next_value()?)),
+            Some(HandleField::Path) => Box::new(SerializedHandle {
+                path: AssetPath::from(map.next_value::<String>()?),
+                typed: |handle| Box::new(handle.typed::<A>()),
+            }),
+            None => {
+                return Err(de::Error::missing_field(HANDLE_FIELD_ID));
+            }
+        };
+        if map.next_key::<HandleField>()?.is_some() {
+            return Err(de::Error::custom(format_args!(
+                "expected either `{HANDLE_FIELD_ID}` or `{HANDLE_FIELD_PATH}`, not both"
+            )));
+        }
+        Ok(value)
+    }
+}
+
+/// Rebuilds `value` as a dynamic value, replacing the values for which `f` returns `Some`.
+fn map_reflect(
+    value: &dyn Reflect,
+    f: &mut dyn FnMut(&dyn Reflect) -> Option<Box<dyn Reflect>>,
+) -> Box<dyn Reflect> {
+    if let Some(mapped) = f(value) {
+        return mapped;
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => {
+            let mut dynamic = DynamicStruct::default();
+            dynamic.set_name(value.type_name().to_string());
+            for (index, field) in value.iter_fields().enumerate() {
+                let name = value.name_at(index).unwrap();
+                dynamic.insert_boxed(name, map_reflect(field, f));
+            }
+            Box::new(dynamic)
+        }
+        ReflectRef::TupleStruct(value) => {
+            let mut dynamic = DynamicTupleStruct::default();
+            dynamic.set_name(value.type_name().to_string());
+            for field in value.iter_fields() {
+                dynamic.insert_boxed(map_reflect(field, f));
+            }
+            Box::new(dynamic)
+        }
+        ReflectRef::Tuple(value) => {
+            let mut dynamic = DynamicTuple::default();
+            dynamic.set_name(value.type_name().to_string());
+            for field in value.iter_fields() {
+                dynamic.insert_boxed(map_reflect(field, f));
+            }
+            Box::new(dynamic)
+        }
+        ReflectRef::List(value) => {
+            let mut dynamic = DynamicList::default();
+            dynamic.set_name(value.type_name().to_string());
+            for item in value.iter() {
+                dynamic.push_box(map_reflect(item, f));
+            }
+            Box::new(dynamic)
+        }
+        ReflectRef::Array(value) => {
+            let mut dynamic =
+                DynamicArray::new(value.iter().map(|item| map_reflect(item, f)).collect());
+            dynamic.set_name(value.type_name().to_string());
+            Box::new(dynamic)
+        }
+        ReflectRef::Map(value) => {
+            let mut dynamic = DynamicMap::default();
+            dynamic.set_name(value.type_name().to_string());
+            for (key, item) in value.iter() {
+                bevy_reflect::Map::insert_boxed(
+                    &mut dynamic,
+                    key.clone_value(),
+                    map_reflect(item, f),
+                );
+            }
+            Box::new(dynamic)
+        }
+        ReflectRef::Enum(value) => {
+            let variant = match value.variant_type() {
+                VariantType::Unit => DynamicVariant::Unit,
+                VariantType::Tuple => {
+                    let mut tuple = DynamicTuple::default();
+                    for field in value.iter_fields() {
+                        tuple.insert_boxed(map_reflect(field.value(), f));
+                    }
+                    DynamicVariant::Tuple(tuple)
+                }
+                VariantType::Struct => {
+                    let mut dynamic = DynamicStruct::default();
+                    for field in value.iter_fields() {
+                        dynamic.insert_boxed(field.name().unwrap(), map_reflect(field.value(), f));
+                    }
+                    DynamicVariant::Struct(dynamic)
+                }
+            };
+            Box::new(DynamicEnum::new_with_index(
+                value.type_name(),
+                value.variant_index(),
+                value.variant_name(),
+                variant,
+            ))
+        }
+        ReflectRef::Value(value) => value.clone_value(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, AppTypeRegistry};
+    use bevy_reflect::{
+        serde::{ReflectSerializer, UntypedReflectDeserializer},
+        FromReflect, Reflect, TypePath, TypeUuid,
+    };
+    use serde::de::DeserializeSeed;
+
+    use crate::{
+        resolve_serialized_handles, AddAsset, AssetPath, AssetPlugin, AssetServer, Handle, HandleId,
+    };
+
+    #[derive(TypeUuid, TypePath)]
+    #[uuid = "2d0ebd34-6a6c-4b2d-8a11-8f0b6d6d1b5c"]
+    struct Image;
+
+    #[derive(Reflect, FromReflect)]
+    struct Sprite {
+        image: Handle<Image>,
+        layers: Vec<Handle<Image>>,
+        fallback: Option<Handle<Image>>,
+    }
+
+    #[test]
+    fn handles_roundtrip_as_paths() {
+        let mut app = App::new();
+        app.add_plugin(bevy_core::CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .register_type::<Sprite>()
+            .register_type::<Vec<Handle<Image>>>()
+            .register_type::<Option<Handle<Image>>>();
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let image: Handle<Image> = asset_server.load("sprites/hero.png");
+        let layer: Handle<Image> = asset_server.load("sprites/atlas.png#layer0");
+        let sprite = Sprite {
+            image: image.clone_weak(),
+            layers: vec![layer.clone_weak()],
+            fallback: None,
+        };
+
+        let type_registry = app.world.resource::<AppTypeRegistry>().read();
+        let serialized = asset_server.serialize_handles(&sprite, &type_registry);
+        let ron = ron::to_string(&ReflectSerializer::new(&*serialized, &type_registry)).unwrap();
+        assert!(ron.contains(r#"image:(path:"sprites/hero.png")"#));
+        assert!(ron.contains(r#"layers:[(path:"sprites/atlas.png#layer0")]"#));
+
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let deserialized = UntypedReflectDeserializer::new(&type_registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let mut loaded = Vec::new();
+        let resolved = resolve_serialized_handles(&*deserialized, |path| {
+            loaded.push(path.clone());
+            asset_server.load_untyped(path.clone())
+        });
+        let sprite = Sprite::from_reflect(&*resolved).unwrap();
+
+        assert_eq!(
+            loaded,
+            vec![
+                AssetPath::from("sprites/hero.png"),
+                AssetPath::from("sprites/atlas.png#layer0")
+            ]
+        );
+        assert_eq!(sprite.image.id(), image.id());
+        assert_eq!(sprite.layers[0].id(), layer.id());
+        assert!(sprite.fallback.is_none());
+    }
+
+    #[test]
+    fn handles_without_path_roundtrip_as_ids() {
+        let mut app = App::new();
+        app.add_plugin(bevy_core::CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .register_type::<Sprite>()
+            .register_type::<Vec<Handle<Image>>>()
+            .register_type::<Option<Handle<Image>>>();
+
+        let id = HandleId::random::<Image>();
+        let sprite = Sprite {
+            image: Handle::weak(id),
+            layers: Vec::new(),
+            fallback: Some(Handle::weak(id)),
+        };
+
+        let asset_server = app.world.resource::<AssetServer>().clone();
+        let type_registry = app.world.resource::<AppTypeRegistry>().read();
+        let serialized = asset_server.serialize_handles(&sprite, &type_registry);
+        let ron = ron::to_string(&ReflectSerializer::new(&*serialized, &type_registry)).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&ron).unwrap();
+        let deserialized = UntypedReflectDeserializer::new(&type_registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+        let sprite = Sprite::from_reflect(&*deserialized).unwrap();
+
+        assert_eq!(sprite.image.id(), id);
+        assert_eq!(sprite.fallback.unwrap().id(), id);
+    }
+}
@@ -44,6 +44,12 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             type Storage = #storage;
+
+            fn clone_fn() -> ::core::option::Option<#bevy_ecs_path::component::ComponentCloneFn> {
+                #[allow(unused_imports)]
+                use #bevy_ecs_path::component::{CloneFnFallback as _, CloneFnViaClone as _};
+                (&&#bevy_ecs_path::component::CloneFnOf::<Self>::default()).clone_fn()
+            }
         }
     })
 }
@@ -193,6 +193,36 @@ unsafe impl<C: Component> Bundle for C {
     }
 }
 
+/// A set of components that can be written into an entity's storage, in the order of the
+/// [`ComponentId`]s of its [`BundleInfo`].
+///
+/// This is implemented for every [`Bundle`], and for the type-erased components inserted with
+/// [`EntityMut::insert_by_ids`](crate::world::EntityMut::insert_by_ids).
+pub(crate) trait DynamicBundle {
+    /// Calls `func` on each value, in the order of this bundle's components. This passes
+    /// ownership of the component values to `func`.
+    fn for_each_component(self, func: &mut impl FnMut(OwningPtr<'_>));
+}
+
+impl<T: Bundle> DynamicBundle for T {
+    #[inline]
+    fn for_each_component(self, func: &mut impl FnMut(OwningPtr<'_>)) {
+        self.get_components(func);
+    }
+}
+
+/// Type-erased component values, written in the order of their [`ComponentId`]s.
+pub(crate) struct DynamicInsertBundle<'a, I: Iterator<Item = OwningPtr<'a>>> {
+    pub(crate) components: I,
+}
+
+impl<'a, I: Iterator<Item = OwningPtr<'a>>> DynamicBundle for DynamicInsertBundle<'a, I> {
+    #[inline]
+    fn for_each_component(self, func: &mut impl FnMut(OwningPtr<'_>)) {
+        self.components.for_each(func);
+    }
+}
+
 macro_rules! tuple_impl {
     ($($name: ident),*) => {
         // SAFETY:
@@ -373,7 +403,7 @@ impl BundleInfo {
     /// `entity`, `bundle` must match this [`BundleInfo`]'s type
     #[inline]
     #[allow(clippy::too_many_arguments)]
-    unsafe fn write_components<T: Bundle, S: BundleComponentStatus>(
+    unsafe fn write_components<T: DynamicBundle, S: BundleComponentStatus>(
         &self,
         table: &mut Table,
         sparse_sets: &mut SparseSets,
@@ -383,10 +413,10 @@ impl BundleInfo {
         change_tick: u32,
         bundle: T,
     ) {
-        // NOTE: get_components calls this closure on each component in "bundle order".
+        // NOTE: for_each_component calls this closure on each component in "bundle order".
         // bundle_info.component_ids are also in "bundle order"
         let mut bundle_component = 0;
-        bundle.get_components(&mut |component_ptr| {
+        bundle.for_each_component(&mut |component_ptr| {
             let component_id = *self.component_ids.get_unchecked(bundle_component);
             match self.storage_types[bundle_component] {
                 StorageType::Table => {
@@ -525,7 +555,7 @@ impl<'a, 'b> BundleInserter<'a, 'b> {
     /// `entity` must currently exist in the source archetype for this inserter. `archetype_index`
     /// must be `entity`'s location in the archetype. `T` must match this [`BundleInfo`]'s type
     #[inline]
-    pub unsafe fn insert<T: Bundle>(
+    pub unsafe fn insert<T: DynamicBundle>(
         &mut self,
         entity: Entity,
         archetype_index: usize,
@@ -688,6 +718,8 @@ impl<'a, 'b> BundleSpawner<'a, 'b> {
 pub struct Bundles {
     bundle_infos: Vec<BundleInfo>,
     bundle_ids: HashMap<TypeId, BundleId>,
+    /// Bundles of runtime-defined components, keyed by their [`ComponentId`]s
+    dynamic_bundle_ids: HashMap<Vec<ComponentId>, BundleId>,
 }
 
 impl Bundles {
@@ -721,6 +753,43 @@ impl Bundles {
         // SAFETY: index either exists, or was initialized
         unsafe { self.bundle_infos.get_unchecked(id.0) }
     }
+
+    /// Initializes a bundle from a list of [`ComponentId`]s, which are not required to
+    /// belong to a Rust type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the [`ComponentId`]s is not registered in `components`, or if it
+    /// is listed more than once.
+    pub(crate) fn init_dynamic_info<'a>(
+        &'a mut self,
+        components: &mut Components,
+        component_ids: &[ComponentId],
+    ) -> &'a BundleInfo {
+        let bundle_infos = &mut self.bundle_infos;
+        let id = match self.dynamic_bundle_ids.get(component_ids) {
+            Some(id) => *id,
+            None => {
+                for &component_id in component_ids {
+                    assert!(
+                        components.get_info(component_id).is_some(),
+                        "Component {:?} is not registered in this World",
+                        component_id
+                    );
+                }
+                let id = BundleId(bundle_infos.len());
+                // SAFETY: every component id was checked to be registered above
+                let bundle_info = unsafe {
+                    initialize_bundle("dynamic bundle", component_ids.to_vec(), id, components)
+                };
+                bundle_infos.push(bundle_info);
+                self.dynamic_bundle_ids.insert(component_ids.to_vec(), id);
+                id
+            }
+        };
+        // SAFETY: index either exists, or was initialized
+        unsafe { self.bundle_infos.get_unchecked(id.0) }
+    }
 }
 
 /// # Safety
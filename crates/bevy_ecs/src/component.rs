@@ -2,16 +2,18 @@
 
 use crate::{
     change_detection::MAX_CHANGE_AGE,
+    entity::Entity,
     storage::{SparseSetIndex, Storages},
     system::Resource,
 };
 pub use bevy_ecs_macros::Component;
-use bevy_ptr::OwningPtr;
+use bevy_ptr::{OwningPtr, Ptr};
 use std::{
     alloc::Layout,
     any::{Any, TypeId},
     borrow::Cow,
     mem::needs_drop,
+    ptr::NonNull,
 };
 
 /// A data type that can be used to store data for an [entity].
@@ -111,6 +113,16 @@ use std::{
 /// [newtype pattern]: https://doc.rust-lang.org/book/ch19-03-advanced-traits.html#using-the-newtype-pattern-to-implement-external-traits-on-external-types
 pub trait Component: Send + Sync + 'static {
     type Storage: ComponentStorage;
+
+    /// Returns the function cloning values of this component, used by
+    /// [`World::clone_entity`](crate::world::World::clone_entity).
+    ///
+    /// `#[derive(Component)]` returns the [`Clone`] implementation of non-generic components
+    /// that have one. Other components can register it with
+    /// [`World::register_component_clone`](crate::world::World::register_component_clone).
+    fn clone_fn() -> Option<ComponentCloneFn> {
+        None
+    }
 }
 
 pub struct TableStorage;
@@ -159,6 +171,8 @@ pub enum StorageType {
 pub struct ComponentInfo {
     id: ComponentId,
     descriptor: ComponentDescriptor,
+    hooks: ComponentHooks,
+    clone: Option<ComponentCloneFn>,
 }
 
 impl ComponentInfo {
@@ -203,8 +217,219 @@ impl ComponentInfo {
         self.descriptor.is_send_and_sync
     }
 
+    /// Returns the hooks run when this component is added to, inserted into or removed from
+    /// an entity.
+    #[inline]
+    pub fn hooks(&self) -> &ComponentHooks {
+        &self.hooks
+    }
+
+    /// Returns the function cloning values of this component, from [`Component::clone_fn`] or
+    /// [`World::register_component_clone`](crate::world::World::register_component_clone).
+    #[inline]
+    pub fn clone_fn(&self) -> Option<ComponentCloneFn> {
+        self.clone
+    }
+
     fn new(id: ComponentId, descriptor: ComponentDescriptor) -> Self {
-        ComponentInfo { id, descriptor }
+        ComponentInfo {
+            id,
+            descriptor,
+            hooks: ComponentHooks::default(),
+            clone: None,
+        }
+    }
+}
+
+/// A change to the components of an entity, which [`ComponentHooks`] and
+/// [observers](crate::observer) react to.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum ComponentEvent {
+    /// The component was added to an entity that did not have it.
+    Add,
+    /// The component was inserted into an entity, whether or not the entity already had it.
+    /// This happens after [`ComponentEvent::Add`].
+    Insert,
+    /// The component is about to be removed from an entity, either on its own or because the
+    /// entity is despawned. The component can still be accessed at this point.
+    Remove,
+}
+
+/// A function run when a component is added to, inserted into or removed from an entity.
+///
+/// It is called with the [`World`](crate::world::World), the affected entity and the
+/// [`ComponentId`] of the component. Hooks run synchronously, right after the component is
+/// added or inserted and right before it is removed.
+pub type ComponentHook = fn(&mut crate::world::World, Entity, ComponentId);
+
+/// A function cloning the component behind `source` into `destination`.
+///
+/// # Safety
+///
+/// `source` must point to a value of the component, and `destination` to uninitialized memory
+/// with the [`Layout`] of the component.
+pub type ComponentCloneFn = unsafe fn(source: Ptr<'_>, destination: NonNull<u8>);
+
+/// The [`ComponentCloneFn`] of the [`Clone`] component `T`.
+///
+/// # Safety
+///
+/// See [`ComponentCloneFn`].
+pub(crate) unsafe fn clone_component<T: Clone>(source: Ptr<'_>, destination: NonNull<u8>) {
+    destination
+        .cast::<T>()
+        .as_ptr()
+        .write(source.deref::<T>().clone());
+}
+
+/// Used by `#[derive(Component)]` to find the [`ComponentCloneFn`] of a component, which is
+/// only known if the component implements [`Clone`].
+///
+/// `(&&CloneFnOf::<T>::default()).clone_fn()` resolves to [`CloneFnViaClone`] if `T: Clone`,
+/// and to [`CloneFnFallback`] otherwise.
+#[doc(hidden)]
+pub struct CloneFnOf<T>(std::marker::PhantomData<T>);
+
+impl<T> Default for CloneFnOf<T> {
+    fn default() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait CloneFnViaClone {
+    fn clone_fn(&self) -> Option<ComponentCloneFn>;
+}
+
+impl<T: Clone + 'static> CloneFnViaClone for &CloneFnOf<T> {
+    fn clone_fn(&self) -> Option<ComponentCloneFn> {
+        Some(clone_component::<T>)
+    }
+}
+
+#[doc(hidden)]
+pub trait CloneFnFallback {
+    fn clone_fn(&self) -> Option<ComponentCloneFn>;
+}
+
+impl<T> CloneFnFallback for CloneFnOf<T> {
+    fn clone_fn(&self) -> Option<ComponentCloneFn> {
+        None
+    }
+}
+
+/// The [`ComponentHook`]s of a component, registered with
+/// [`World::register_component_hooks`](crate::world::World::register_component_hooks).
+///
+/// Each component has at most one hook per [`ComponentEvent`]. Hooks are meant for the
+/// invariants of the component itself; use [observers](crate::observer) to react to
+/// changes on specific entities.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Name(&'static str);
+///
+/// #[derive(Resource, Default)]
+/// struct Names(Vec<&'static str>);
+///
+/// let mut world = World::new();
+/// world.init_resource::<Names>();
+/// world
+///     .register_component_hooks::<Name>()
+///     .on_add(|world, entity, _| {
+///         let name = world.get::<Name>(entity).unwrap().0;
+///         world.resource_mut::<Names>().0.push(name);
+///     });
+///
+/// world.spawn(Name("Ferris"));
+/// assert_eq!(world.resource::<Names>().0, vec!["Ferris"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ComponentHooks {
+    on_add: Option<ComponentHook>,
+    on_insert: Option<ComponentHook>,
+    on_remove: Option<ComponentHook>,
+}
+
+impl ComponentHooks {
+    /// Registers a [`ComponentHook`] run when the component is added to an entity that did not
+    /// have it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an `on_add` hook is already registered for the component.
+    pub fn on_add(&mut self, hook: ComponentHook) -> &mut Self {
+        self.try_on_add(hook)
+            .expect("Component already has an on_add hook")
+    }
+
+    /// Registers a [`ComponentHook`] run when the component is inserted into an entity, whether
+    /// or not the entity already had it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an `on_insert` hook is already registered for the component.
+    pub fn on_insert(&mut self, hook: ComponentHook) -> &mut Self {
+        self.try_on_insert(hook)
+            .expect("Component already has an on_insert hook")
+    }
+
+    /// Registers a [`ComponentHook`] run right before the component is removed from an entity,
+    /// including when the entity is despawned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an `on_remove` hook is already registered for the component.
+    pub fn on_remove(&mut self, hook: ComponentHook) -> &mut Self {
+        self.try_on_remove(hook)
+            .expect("Component already has an on_remove hook")
+    }
+
+    /// Fallible version of [`ComponentHooks::on_add`]: returns `None` if an `on_add` hook is
+    /// already registered.
+    pub fn try_on_add(&mut self, hook: ComponentHook) -> Option<&mut Self> {
+        Self::try_set(&mut self.on_add, hook)?;
+        Some(self)
+    }
+
+    /// Fallible version of [`ComponentHooks::on_insert`]: returns `None` if an `on_insert` hook
+    /// is already registered.
+    pub fn try_on_insert(&mut self, hook: ComponentHook) -> Option<&mut Self> {
+        Self::try_set(&mut self.on_insert, hook)?;
+        Some(self)
+    }
+
+    /// Fallible version of [`ComponentHooks::on_remove`]: returns `None` if an `on_remove` hook
+    /// is already registered.
+    pub fn try_on_remove(&mut self, hook: ComponentHook) -> Option<&mut Self> {
+        Self::try_set(&mut self.on_remove, hook)?;
+        Some(self)
+    }
+
+    /// Returns the hook registered for `event`, if any.
+    #[inline]
+    pub fn get(&self, event: ComponentEvent) -> Option<ComponentHook> {
+        match event {
+            ComponentEvent::Add => self.on_add,
+            ComponentEvent::Insert => self.on_insert,
+            ComponentEvent::Remove => self.on_remove,
+        }
+    }
+
+    /// Returns `true` if no hook is registered.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.on_add.is_none() && self.on_insert.is_none() && self.on_remove.is_none()
+    }
+
+    fn try_set(slot: &mut Option<ComponentHook>, hook: ComponentHook) -> Option<()> {
+        if slot.is_some() {
+            return None;
+        }
+        *slot = Some(hook);
+        Some(())
     }
 }
 
@@ -380,7 +605,13 @@ impl Components {
             ..
         } = self;
         let index = indices.entry(type_id).or_insert_with(|| {
-            Components::init_component_inner(components, storages, ComponentDescriptor::new::<T>())
+            let index = Components::init_component_inner(
+                components,
+                storages,
+                ComponentDescriptor::new::<T>(),
+            );
+            components[index].clone = T::clone_fn();
+            index
         });
         ComponentId(*index)
     }
@@ -419,6 +650,22 @@ impl Components {
         self.components.len() == 0
     }
 
+    /// Returns the hooks of the component with the given [`ComponentId`], to register new ones.
+    #[inline]
+    pub(crate) fn get_hooks_mut(&mut self, id: ComponentId) -> Option<&mut ComponentHooks> {
+        self.components.get_mut(id.0).map(|info| &mut info.hooks)
+    }
+
+    /// Sets the function cloning values of the component with the given [`ComponentId`].
+    ///
+    /// # Safety
+    ///
+    /// `clone` must clone values of the type described by the component's descriptor.
+    #[inline]
+    pub(crate) unsafe fn set_clone_fn(&mut self, id: ComponentId, clone: ComponentCloneFn) {
+        self.components[id.0].clone = Some(clone);
+    }
+
     #[inline]
     pub fn get_info(&self, id: ComponentId) -> Option<&ComponentInfo> {
         self.components.get(id.0)
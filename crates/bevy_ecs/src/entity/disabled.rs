@@ -0,0 +1,37 @@
+use crate::component::{Component, TableStorage};
+
+/// A marker component for entities that are temporarily taken out of the simulation.
+///
+/// Disabled entities keep all their components, but are skipped by every query that does not
+/// refer to `Disabled` itself. To also iterate over disabled entities, add `Option<&Disabled>`
+/// to the query; to only iterate over them, add a `With<Disabled>` filter.
+///
+/// Archetypes containing `Disabled` are excluded when they are matched by a
+/// [`QueryState`](crate::query::QueryState), so disabled entities have no cost when iterating.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Bullet;
+///
+/// let mut world = World::new();
+/// let active = world.spawn(Bullet).id();
+/// let pooled = world.spawn((Bullet, Disabled)).id();
+///
+/// let mut query = world.query_filtered::<Entity, With<Bullet>>();
+/// assert_eq!(query.iter(&world).collect::<Vec<_>>(), [active]);
+///
+/// let mut query = world.query_filtered::<Entity, (With<Bullet>, With<Disabled>)>();
+/// assert_eq!(query.iter(&world).collect::<Vec<_>>(), [pooled]);
+///
+/// let mut query = world.query_filtered::<Option<&Disabled>, With<Bullet>>();
+/// assert_eq!(query.iter(&world).count(), 2);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Disabled;
+
+impl Component for Disabled {
+    // Disabled entities must be in their own tables, since dense queries iterate whole tables.
+    type Storage = TableStorage;
+}
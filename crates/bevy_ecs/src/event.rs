@@ -2,8 +2,14 @@
 
 use crate as bevy_ecs;
 use crate::system::{Local, Res, ResMut, Resource, SystemParam};
+use crate::world::{FromWorld, World};
 use bevy_utils::tracing::{trace, warn};
+use std::collections::VecDeque;
 use std::ops::{Deref, DerefMut};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Weak,
+};
 use std::{fmt, hash::Hash, marker::PhantomData};
 
 /// A type that can be stored in an [`Events<E>`] resource
@@ -121,6 +127,10 @@ struct EventInstance<E: Event> {
 /// but can be done by adding your event as a resource instead of using
 /// [`add_event`](https://docs.rs/bevy/*/bevy/app/struct.App.html#method.add_event).
 ///
+/// Systems behind a run criterion or in a fixed timestep stage may not read events every frame,
+/// and would miss some of them with this strategy. [`EventStorage::Persistent`] keeps the events
+/// until they were read by every registered reader instead.
+///
 /// [Example usage.](https://github.com/bevyengine/bevy/blob/latest/examples/ecs/event.rs)
 /// [Example usage standalone.](https://github.com/bevyengine/bevy/blob/latest/crates/bevy_ecs/examples/events.rs)
 ///
@@ -132,6 +142,12 @@ pub struct Events<E: Event> {
     /// Holds the newer events.
     events_b: EventSequence<E>,
     event_count: usize,
+    storage: EventStorage,
+    /// The number of events read by each reader registered with [`Events::register_reader`].
+    readers: Vec<Weak<AtomicUsize>>,
+    /// With [`EventStorage::Persistent`], the id of the first event sent during each update whose
+    /// events are still stored.
+    update_starts: VecDeque<usize>,
 }
 
 // Derived Default impl would incorrectly require E: Default
@@ -141,10 +157,32 @@ impl<E: Event> Default for Events<E> {
             events_a: Default::default(),
             events_b: Default::default(),
             event_count: Default::default(),
+            storage: Default::default(),
+            readers: Default::default(),
+            update_starts: Default::default(),
         }
     }
 }
 
+/// How long the events of an [`Events`] collection are stored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EventStorage {
+    /// Events are dropped after two calls to [`Events::update`].
+    #[default]
+    DoubleBuffered,
+    /// Events are dropped once they were read by every reader registered with
+    /// [`Events::register_reader`], which includes the [`EventReader`] system parameters.
+    ///
+    /// Events are kept through `max_age` calls to [`Events::update`], and dropped by the next
+    /// one even if they were not read, which is reported by [`ManualEventReader::missed_events`].
+    /// `Some(1)` stores events as long as [`EventStorage::DoubleBuffered`].
+    Persistent {
+        /// The number of updates an unread event is kept through, or `None` to store events until
+        /// they were read.
+        max_age: Option<usize>,
+    },
+}
+
 impl<E: Event> Events<E> {
     pub fn oldest_event_count(&self) -> usize {
         self.events_a
@@ -184,12 +222,42 @@ impl<E: Event> DerefMut for EventSequence<E> {
 }
 
 /// Reads events of type `T` in order and tracks which events have already been read.
+///
+/// The reader is registered with the [`Events<E>`] resource if it exists when the system is
+/// initialized, so that [`EventStorage::Persistent`] events are kept until it read them.
 #[derive(SystemParam)]
 pub struct EventReader<'w, 's, E: Event> {
-    reader: Local<'s, ManualEventReader<E>>,
+    reader: Local<'s, EventReaderState<E>>,
     events: Res<'w, Events<E>>,
 }
 
+/// The state of an [`EventReader`].
+#[doc(hidden)]
+pub struct EventReaderState<E: Event>(ManualEventReader<E>);
+
+impl<E: Event> FromWorld for EventReaderState<E> {
+    fn from_world(world: &mut World) -> Self {
+        match world.get_resource_mut::<Events<E>>() {
+            Some(mut events) => Self(events.register_reader()),
+            None => Self(ManualEventReader::default()),
+        }
+    }
+}
+
+impl<E: Event> Deref for EventReaderState<E> {
+    type Target = ManualEventReader<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<E: Event> DerefMut for EventReaderState<E> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 impl<'w, 's, E: Event> EventReader<'w, 's, E> {
     /// Iterates over the events this [`EventReader`] has not seen yet. This updates the
     /// [`EventReader`]'s event counter, which means subsequent event reads will not include events
@@ -213,6 +281,13 @@ impl<'w, 's, E: Event> EventReader<'w, 's, E> {
         self.reader.len(&self.events)
     }
 
+    /// Returns the number of events that were dropped before this [`EventReader`] read them.
+    ///
+    /// See [`ManualEventReader::missed_events`].
+    pub fn missed_events(&self) -> usize {
+        self.reader.missed_events(&self.events)
+    }
+
     /// Determines if no events are available to be read without consuming any.
     /// If you need to consume the iterator you can use [`EventReader::clear`].
     ///
@@ -324,6 +399,8 @@ impl<'w, 's, E: Event> EventWriter<'w, 's, E> {
 #[derive(Debug)]
 pub struct ManualEventReader<E: Event> {
     last_event_count: usize,
+    /// The number of events read, shared with the [`Events`] this reader is registered with.
+    registration: Option<Arc<AtomicUsize>>,
     _marker: PhantomData<E>,
 }
 
@@ -331,6 +408,7 @@ impl<E: Event> Default for ManualEventReader<E> {
     fn default() -> Self {
         ManualEventReader {
             last_event_count: 0,
+            registration: None,
             _marker: Default::default(),
         }
     }
@@ -370,12 +448,24 @@ impl<E: Event> ManualEventReader<E> {
         // Ensure `len` is implemented correctly
         debug_assert_eq!(unread_count, self.len(events));
         self.last_event_count = events.event_count - unread_count;
+        self.publish_last_event_count();
         // Iterate the oldest first, then the newer events
         let iterator = a.iter().chain(b.iter());
         iterator
             .map(|e| (&e.event, e.event_id))
             .with_exact_size(unread_count)
-            .inspect(move |(_, id)| self.last_event_count = (id.id + 1).max(self.last_event_count))
+            .inspect(move |(_, id)| {
+                self.last_event_count = (id.id + 1).max(self.last_event_count);
+                self.publish_last_event_count();
+            })
+    }
+
+    /// Lets the [`Events`] this reader is registered with drop the events it read.
+    #[inline]
+    fn publish_last_event_count(&self) {
+        if let Some(registration) = &self.registration {
+            registration.store(self.last_event_count, Ordering::Release);
+        }
     }
 
     /// See [`EventReader::len`]
@@ -390,7 +480,8 @@ impl<E: Event> ManualEventReader<E> {
             .min(events.len())
     }
 
-    /// Amount of events we missed.
+    /// Amount of events we missed: events that were dropped from `events` before this reader read
+    /// them, and that will be skipped by the next read.
     pub fn missed_events(&self, events: &Events<E>) -> usize {
         events
             .oldest_event_count()
@@ -495,18 +586,96 @@ impl<E: Event> Events<E> {
         }
     }
 
-    /// Swaps the event buffers and clears the oldest event buffer. In general, this should be
-    /// called once per frame/update.
+    /// Gets a new [`ManualEventReader`] including all events already stored, and registers it so
+    /// that, with [`EventStorage::Persistent`], events are stored until it read them.
+    ///
+    /// The reader is unregistered when it is dropped.
+    pub fn register_reader(&mut self) -> ManualEventReader<E> {
+        let last_event_count = self.oldest_event_count();
+        let registration = Arc::new(AtomicUsize::new(last_event_count));
+        self.readers.push(Arc::downgrade(&registration));
+        ManualEventReader {
+            last_event_count,
+            registration: Some(registration),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns how long events are stored.
+    pub fn storage(&self) -> EventStorage {
+        self.storage
+    }
+
+    /// Sets how long events are stored, starting from the next [`update`](Events::update).
+    pub fn set_storage(&mut self, storage: EventStorage) {
+        self.storage = storage;
+        if storage == EventStorage::DoubleBuffered {
+            self.update_starts.clear();
+        }
+    }
+
+    /// Drops the events that are not stored anymore. In general, this should be called once per
+    /// frame/update.
+    ///
+    /// With [`EventStorage::DoubleBuffered`], this swaps the event buffers and clears the oldest
+    /// event buffer.
     pub fn update(&mut self) {
-        std::mem::swap(&mut self.events_a, &mut self.events_b);
-        self.events_b.clear();
-        self.events_b.start_event_count = self.event_count;
+        match self.storage {
+            EventStorage::DoubleBuffered => {
+                std::mem::swap(&mut self.events_a, &mut self.events_b);
+                self.events_b.clear();
+                self.events_b.start_event_count = self.event_count;
+                self.readers.retain(|reader| reader.strong_count() > 0);
+            }
+            EventStorage::Persistent { max_age } => self.update_persistent(max_age),
+        }
         debug_assert_eq!(
             self.events_a.start_event_count + self.events_a.len(),
             self.events_b.start_event_count
         );
     }
 
+    /// Moves the events of the last update to `events_a`, and drops the events read by every
+    /// registered reader or kept through `max_age` updates already.
+    fn update_persistent(&mut self, max_age: Option<usize>) {
+        self.update_starts
+            .push_back(self.events_b.start_event_count);
+        let events = std::mem::take(&mut self.events_b.events);
+        self.events_a.extend(events);
+        self.events_b.start_event_count = self.event_count;
+
+        let mut oldest_unread = self.event_count;
+        self.readers.retain(|reader| match reader.upgrade() {
+            Some(reader) => {
+                oldest_unread = oldest_unread.min(reader.load(Ordering::Acquire));
+                true
+            }
+            None => false,
+        });
+        if let Some(max_age) = max_age {
+            let first_kept = self.update_starts.len().saturating_sub(max_age);
+            let oldest_kept = match self.update_starts.get(first_kept) {
+                Some(&start) => start,
+                None => self.event_count,
+            };
+            oldest_unread = oldest_unread.max(oldest_kept);
+        }
+
+        let dropped = oldest_unread
+            .saturating_sub(self.events_a.start_event_count)
+            .min(self.events_a.len());
+        self.events_a.drain(..dropped);
+        self.events_a.start_event_count += dropped;
+        while self.update_starts.len() > 1
+            && self.update_starts[1] <= self.events_a.start_event_count
+        {
+            self.update_starts.pop_front();
+        }
+        if self.events_a.is_empty() {
+            self.update_starts.clear();
+        }
+    }
+
     /// A system that calls [`Events::update`] once per frame.
     pub fn update_system(mut events: ResMut<Self>) {
         events.update();
@@ -516,6 +685,7 @@ impl<E: Event> Events<E> {
     fn reset_start_event_count(&mut self) {
         self.events_a.start_event_count = self.event_count;
         self.events_b.start_event_count = self.event_count;
+        self.update_starts.clear();
     }
 
     /// Removes all events.
@@ -870,4 +1040,114 @@ mod tests {
         }
         read_for::<EmptyTestEvent>();
     }
+
+    fn persistent(max_age: Option<usize>) -> Events<TestEvent> {
+        let mut events = Events::default();
+        events.set_storage(EventStorage::Persistent { max_age });
+        events
+    }
+
+    #[test]
+    fn persistent_events_wait_for_registered_readers() {
+        let mut events = persistent(None);
+        let mut reader_a = events.register_reader();
+        let mut reader_b = events.register_reader();
+        // Unregistered readers do not keep events.
+        let mut reader_c = events.get_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.update();
+        events.update();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            get_events(&events, &mut reader_a),
+            [TestEvent { i: 0 }, TestEvent { i: 1 }]
+        );
+
+        // Events are only dropped once every registered reader read them.
+        events.update();
+        assert_eq!(events.len(), 2);
+        assert_eq!(reader_b.iter(&events).next(), Some(&TestEvent { i: 0 }));
+        events.update();
+        assert_eq!(events.len(), 1);
+        assert_eq!(reader_c.missed_events(&events), 1);
+        assert_eq!(get_events(&events, &mut reader_c), [TestEvent { i: 1 }]);
+
+        // Dropped readers are unregistered.
+        drop(reader_b);
+        events.update();
+        assert!(events.is_empty());
+        assert_eq!(reader_a.missed_events(&events), 0);
+    }
+
+    #[test]
+    fn persistent_events_max_age() {
+        let mut events = persistent(Some(2));
+        let mut reader = events.register_reader();
+
+        events.send(TestEvent { i: 0 });
+        events.update();
+        events.send(TestEvent { i: 1 });
+        events.update();
+        events.send(TestEvent { i: 2 });
+        events.update();
+        assert_eq!(events.len(), 2);
+        assert_eq!(reader.missed_events(&events), 1);
+        assert_eq!(
+            get_events(&events, &mut reader),
+            [TestEvent { i: 1 }, TestEvent { i: 2 }]
+        );
+        assert_eq!(reader.missed_events(&events), 0);
+
+        events.send(TestEvent { i: 3 });
+        events.update();
+        assert_eq!(events.len(), 1);
+        assert_eq!(get_events(&events, &mut reader), [TestEvent { i: 3 }]);
+    }
+
+    #[test]
+    fn persistent_events_max_age_one_is_double_buffered() {
+        let mut persistent = persistent(Some(1));
+        let persistent_reader = persistent.register_reader();
+        let mut double_buffered = Events::<TestEvent>::default();
+        let double_buffered_reader = double_buffered.get_reader();
+
+        for i in 0..4 {
+            for events in [&mut persistent, &mut double_buffered] {
+                events.send(TestEvent { i });
+                if i % 2 == 0 {
+                    events.send(TestEvent { i: i + 10 });
+                }
+                events.update();
+            }
+            assert_eq!(
+                get_events(&persistent, &mut persistent.get_reader()),
+                get_events(&double_buffered, &mut double_buffered.get_reader())
+            );
+            assert_eq!(
+                persistent_reader.missed_events(&persistent),
+                double_buffered_reader.missed_events(&double_buffered)
+            );
+        }
+    }
+
+    #[test]
+    fn event_readers_are_registered() {
+        let mut world = World::new();
+        world.insert_resource(persistent(None));
+        let mut state = SystemState::<EventReader<TestEvent>>::new(&mut world);
+
+        for i in 0..3 {
+            world.send_event(TestEvent { i });
+            world.resource_mut::<Events<TestEvent>>().update();
+        }
+        let mut reader = state.get(&world);
+        assert_eq!(reader.missed_events(), 0);
+        assert_eq!(reader.iter().count(), 3);
+
+        world.resource_mut::<Events<TestEvent>>().update();
+        assert!(world.resource::<Events<TestEvent>>().is_empty());
+    }
 }
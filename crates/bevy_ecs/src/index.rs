@@ -0,0 +1,292 @@
+//! Indexes finding entities by the value of one of their components.
+
+use crate as bevy_ecs;
+use crate::{
+    change_detection::MAX_CHANGE_AGE,
+    component::{Component, ComponentId},
+    entity::{Disabled, Entity},
+    query::ChangeTrackers,
+    system::{Query, ResMut, Resource, SystemParam},
+    world::World,
+};
+use bevy_utils::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// A component type that can be indexed with [`World::init_component_index`].
+pub trait IndexedComponent: Component + Hash + Eq + Clone {}
+
+impl<C: Component + Hash + Eq + Clone> IndexedComponent for C {}
+
+/// The index of the component `C`, mapping each value of `C` to the entities that have it.
+///
+/// The index is created with [`World::init_component_index`], and is updated as soon as `C` is
+/// inserted or removed. Changes made through [`Mut<C>`](crate::change_detection::Mut) are only
+/// applied when the index is next used through an [`IndexQuery`], so this resource should not be
+/// read directly.
+#[derive(Resource)]
+pub struct ComponentIndex<C: IndexedComponent> {
+    entities: HashMap<C, HashSet<Entity>>,
+    values: HashMap<Entity, C>,
+    /// The change tick up to which changes of `C` were applied.
+    last_change_tick: u32,
+}
+
+impl<C: IndexedComponent> ComponentIndex<C> {
+    fn insert(&mut self, entity: Entity, value: C) {
+        if self.values.get(&entity) == Some(&value) {
+            return;
+        }
+        self.remove(entity);
+        self.entities
+            .entry(value.clone())
+            .or_default()
+            .insert(entity);
+        self.values.insert(entity, value);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(value) = self.values.remove(&entity) {
+            let entities = self.entities.get_mut(&value).unwrap();
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(&value);
+            }
+        }
+    }
+
+    /// Returns the entities whose `C` component is equal to `value`.
+    fn get(&self, value: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.get(value).into_iter().flatten().copied()
+    }
+}
+
+fn on_insert<C: IndexedComponent>(world: &mut World, entity: Entity, _: ComponentId) {
+    let value = world.get::<C>(entity).unwrap().clone();
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.insert(entity, value);
+    }
+}
+
+fn on_remove<C: IndexedComponent>(world: &mut World, entity: Entity, _: ComponentId) {
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.remove(entity);
+    }
+}
+
+impl World {
+    /// Creates the [`ComponentIndex`] of the component `C`, so that entities can be looked up by
+    /// the value of `C` with an [`IndexQuery`]. Does nothing if the index already exists.
+    ///
+    /// # Panics
+    ///
+    /// The index is kept up to date with [`ComponentHooks`](crate::component::ComponentHooks),
+    /// so this panics if `C` already has an `on_insert` or `on_remove` hook.
+    pub fn init_component_index<C: IndexedComponent>(&mut self) {
+        if self.contains_resource::<ComponentIndex<C>>() {
+            return;
+        }
+        self.register_component_hooks::<C>()
+            .on_insert(on_insert::<C>)
+            .on_remove(on_remove::<C>);
+
+        let mut index = ComponentIndex {
+            entities: HashMap::default(),
+            values: HashMap::default(),
+            // The next system runs with the current tick, so changes made at it are still new.
+            last_change_tick: self.change_tick().wrapping_sub(1),
+        };
+        let mut query = self.query::<(Entity, &C, Option<&Disabled>)>();
+        for (entity, value, _) in query.iter(self) {
+            index.insert(entity, value.clone());
+        }
+        self.insert_resource(index);
+    }
+}
+
+/// [`SystemParam`] looking up entities by the value of their component `C` in constant time,
+/// using the [`ComponentIndex`] created with [`World::init_component_index`].
+///
+/// Inserting or removing `C` updates the index right away. Values changed in place, in any way,
+/// are found with change detection and applied the first time the index is used in each run of
+/// the system. `C` is only read, so the param can be used alongside a `Query<&C>`.
+///
+/// Disabled entities are included in the results.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::index::IndexQuery;
+/// #[derive(Component, Clone, PartialEq, Eq, Hash)]
+/// struct PlayerId(u32);
+///
+/// #[derive(Component)]
+/// struct Score(u32);
+///
+/// fn reward_player(mut players: IndexQuery<PlayerId>, mut scores: Query<&mut Score>) {
+///     if let Some(player) = players.get_single(&PlayerId(7)) {
+///         scores.get_mut(player).unwrap().0 += 10;
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.init_component_index::<PlayerId>();
+/// let player = world.spawn((PlayerId(7), Score(0))).id();
+///
+/// let mut stage = SystemStage::single(reward_player);
+/// stage.run(&mut world);
+/// assert_eq!(world.get::<Score>(player).unwrap().0, 10);
+/// ```
+///
+/// # Panics
+///
+/// Panics when fetched if the index of `C` was not created.
+#[derive(SystemParam)]
+pub struct IndexQuery<'w, 's, C: IndexedComponent> {
+    index: ResMut<'w, ComponentIndex<C>>,
+    components: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static C,
+            ChangeTrackers<C>,
+            Option<&'static Disabled>,
+        ),
+    >,
+}
+
+impl<'w, 's, C: IndexedComponent> IndexQuery<'w, 's, C> {
+    /// Returns the entities whose `C` component is equal to `value`.
+    pub fn get(&mut self, value: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.update();
+        self.index.get(value)
+    }
+
+    /// Returns the entity whose `C` component is equal to `value`, or `None` if there are none
+    /// or several.
+    pub fn get_single(&mut self, value: &C) -> Option<Entity> {
+        let mut entities = self.get(value);
+        match (entities.next(), entities.next()) {
+            (Some(entity), None) => Some(entity),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if any entity has a `C` component equal to `value`.
+    pub fn contains(&mut self, value: &C) -> bool {
+        self.get(value).next().is_some()
+    }
+
+    /// Applies the changes of `C` made since the last update.
+    fn update(&mut self) {
+        let change_tick = self.components.change_tick;
+        let last_change_tick = self.index.last_change_tick;
+        if last_change_tick == change_tick {
+            return;
+        }
+        // Changes made with the tick of the last update may have happened after it.
+        let since = last_change_tick.wrapping_sub(1);
+        // Ticks older than `MAX_CHANGE_AGE` can not be compared, so every value is checked.
+        let check_all = change_tick.wrapping_sub(since) > MAX_CHANGE_AGE;
+        let index = &mut *self.index;
+        for (entity, value, trackers, _) in &self.components {
+            if check_all || trackers.component_ticks.is_changed(since, change_tick) {
+                index.insert(entity, value.clone());
+            }
+        }
+        index.last_change_tick = change_tick;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexQuery;
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component,
+        entity::{Disabled, Entity},
+        schedule::{Stage, SystemStage},
+        system::{Query, ResMut, Resource},
+        world::World,
+    };
+
+    #[derive(Component, Clone, Debug, PartialEq, Eq, Hash)]
+    struct Cell(i32, i32);
+
+    #[derive(Resource, Default)]
+    struct Found(Vec<Entity>);
+
+    fn find(mut cells: IndexQuery<Cell>, mut found: ResMut<Found>) {
+        let mut entities: Vec<_> = cells.get(&Cell(3, 4)).collect();
+        entities.sort();
+        found.0 = entities;
+    }
+
+    fn run(world: &mut World) -> Vec<Entity> {
+        SystemStage::single(find).run(world);
+        std::mem::take(&mut world.resource_mut::<Found>().0)
+    }
+
+    #[test]
+    fn lookup() {
+        let mut world = World::new();
+        world.init_resource::<Found>();
+        let a = world.spawn(Cell(3, 4)).id();
+        world.init_component_index::<Cell>();
+        let b = world.spawn(Cell(3, 4)).id();
+        let c = world.spawn(Cell(0, 0)).id();
+        assert_eq!(run(&mut world), [a, b]);
+
+        // Inserting, changing and removing the component.
+        world.entity_mut(c).insert(Cell(3, 4));
+        world.entity_mut(a).insert(Cell(1, 1));
+        world.get_mut::<Cell>(b).unwrap().0 = 5;
+        assert_eq!(run(&mut world), [c]);
+
+        world.get_mut::<Cell>(b).unwrap().0 = 3;
+        world.entity_mut(c).remove::<Cell>();
+        assert_eq!(run(&mut world), [b]);
+
+        world.entity_mut(a).insert((Cell(3, 4), Disabled));
+        world.despawn(b);
+        assert_eq!(run(&mut world), [a]);
+    }
+
+    #[test]
+    fn changes_from_other_systems() {
+        let mut world = World::new();
+        world.init_resource::<Found>();
+        world.init_component_index::<Cell>();
+        let a = world.spawn(Cell(0, 4)).id();
+
+        SystemStage::single(|mut cells: Query<&mut Cell>| {
+            for mut cell in &mut cells {
+                cell.0 = 3;
+            }
+        })
+        .run(&mut world);
+        SystemStage::single(move |mut index: IndexQuery<Cell>, cells: Query<&Cell>| {
+            assert_eq!(index.get_single(&Cell(3, 4)), Some(a));
+            assert_eq!(cells.get(a), Ok(&Cell(3, 4)));
+        })
+        .run(&mut world);
+    }
+
+    #[test]
+    fn get_single() {
+        let mut world = World::new();
+        world.init_component_index::<Cell>();
+        let a = world.spawn(Cell(1, 2)).id();
+        world.spawn(Cell(0, 0));
+        world.spawn(Cell(0, 0));
+
+        let mut stage = SystemStage::single(move |mut cells: IndexQuery<Cell>| {
+            assert_eq!(cells.get_single(&Cell(1, 2)), Some(a));
+            assert_eq!(cells.get_single(&Cell(0, 0)), None);
+            assert_eq!(cells.get_single(&Cell(5, 5)), None);
+            assert!(cells.contains(&Cell(0, 0)));
+            assert!(!cells.contains(&Cell(5, 5)));
+        });
+        stage.run(&mut world);
+    }
+}
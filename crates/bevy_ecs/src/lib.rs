@@ -10,10 +10,13 @@ pub mod change_detection;
 pub mod component;
 pub mod entity;
 pub mod event;
+pub mod index;
+pub mod observer;
 pub mod query;
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
 pub mod schedule;
+pub mod schedule_v3;
 pub mod storage;
 pub mod system;
 pub mod world;
@@ -30,7 +33,7 @@ pub mod prelude {
         bundle::Bundle,
         change_detection::DetectChanges,
         component::Component,
-        entity::Entity,
+        entity::{Disabled, Entity},
         event::{EventReader, EventWriter, Events},
         query::{Added, AnyOf, ChangeTrackers, Changed, Or, QueryState, With, Without},
         schedule::{
@@ -0,0 +1,492 @@
+//! Observers that react immediately to component changes on specific entities.
+//!
+//! An observer is a callback registered on an [`Entity`] with [`EntityMut::observe`]. It runs
+//! synchronously whenever a given [`ComponentEvent`] happens to a given component of that
+//! entity, whether the change was made directly on the [`World`] or through [`Commands`].
+//!
+//! Observers run right after the [`ComponentHooks`](crate::component::ComponentHooks) of the
+//! component, and are removed when their entity is despawned.
+//!
+//! ```
+//! use bevy_ecs::{component::ComponentEvent, prelude::*};
+//!
+//! #[derive(Component)]
+//! struct Health(u32);
+//!
+//! #[derive(Resource, Default)]
+//! struct Deaths(u32);
+//!
+//! let mut world = World::new();
+//! world.init_resource::<Deaths>();
+//! let mut player = world.spawn(Health(10));
+//! player.observe::<Health>(ComponentEvent::Remove, |world, _entity, _component| {
+//!     world.resource_mut::<Deaths>().0 += 1;
+//! });
+//! let player = player.id();
+//!
+//! world.entity_mut(player).remove::<Health>();
+//! assert_eq!(world.resource::<Deaths>().0, 1);
+//! ```
+//!
+//! [`EntityMut::observe`]: crate::world::EntityMut::observe
+//! [`Commands`]: crate::system::Commands
+
+use crate::{
+    archetype::ArchetypeId,
+    component::{ComponentEvent, ComponentId},
+    entity::Entity,
+    world::World,
+};
+use bevy_utils::HashMap;
+
+/// Identifies an observer registered with [`EntityMut::observe`](crate::world::EntityMut::observe),
+/// so it can be removed with [`World::remove_observer`].
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ObserverId {
+    entity: Entity,
+    index: u32,
+}
+
+impl ObserverId {
+    /// Returns the entity this observer is registered on.
+    #[inline]
+    pub fn entity(self) -> Entity {
+        self.entity
+    }
+}
+
+/// The callback of an observer, called with the [`World`], the observed entity and the
+/// [`ComponentId`] of the component that changed.
+pub type ObserverCallback = Box<dyn FnMut(&mut World, Entity, ComponentId) + Send + Sync>;
+
+struct Observer {
+    index: u32,
+    event: ComponentEvent,
+    component_id: ComponentId,
+    callback: ObserverCallback,
+}
+
+#[derive(Default)]
+struct EntityObservers {
+    next_index: u32,
+    observers: Vec<Observer>,
+    /// Observers removed while they were taken out to run
+    removed: Vec<u32>,
+    running: bool,
+}
+
+/// The observers registered on the entities of a [`World`].
+#[derive(Default)]
+pub struct Observers {
+    entities: HashMap<Entity, EntityObservers>,
+}
+
+impl Observers {
+    /// Returns `true` if `entity` has any observers.
+    #[inline]
+    pub fn has_observers(&self, entity: Entity) -> bool {
+        self.entities.contains_key(&entity)
+    }
+
+    /// Returns `true` if no entity has observers.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Returns the number of observers registered on `entity`.
+    pub fn len(&self, entity: Entity) -> usize {
+        self.entities
+            .get(&entity)
+            .map_or(0, |observers| observers.observers.len())
+    }
+
+    fn add(
+        &mut self,
+        entity: Entity,
+        event: ComponentEvent,
+        component_id: ComponentId,
+        callback: ObserverCallback,
+    ) -> ObserverId {
+        let observers = self.entities.entry(entity).or_default();
+        let index = observers.next_index;
+        observers.next_index += 1;
+        observers.observers.push(Observer {
+            index,
+            event,
+            component_id,
+            callback,
+        });
+        ObserverId { entity, index }
+    }
+
+    fn remove(&mut self, id: ObserverId) -> bool {
+        let observers = match self.entities.get_mut(&id.entity) {
+            Some(observers) => observers,
+            None => return false,
+        };
+        if let Some(position) = observers
+            .observers
+            .iter()
+            .position(|observer| observer.index == id.index)
+        {
+            observers.observers.remove(position);
+        } else if observers.running && id.index < observers.next_index {
+            observers.removed.push(id.index);
+        } else {
+            return false;
+        }
+        if observers.observers.is_empty() && !observers.running {
+            self.entities.remove(&id.entity);
+        }
+        true
+    }
+}
+
+impl World {
+    /// Returns the [`Observers`] registered on the entities of this [`World`].
+    #[inline]
+    pub fn observers(&self) -> &Observers {
+        &self.observers
+    }
+
+    /// Registers an observer on `entity`, run when `event` happens to the component with the
+    /// given [`ComponentId`].
+    ///
+    /// See [`EntityMut::observe`](crate::world::EntityMut::observe) for the typed version.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` does not exist.
+    pub fn observe_by_id(
+        &mut self,
+        entity: Entity,
+        event: ComponentEvent,
+        component_id: ComponentId,
+        observer: impl FnMut(&mut World, Entity, ComponentId) + Send + Sync + 'static,
+    ) -> ObserverId {
+        assert!(
+            self.entities.get(entity).is_some(),
+            "Entity {:?} does not exist",
+            entity
+        );
+        self.observers
+            .add(entity, event, component_id, Box::new(observer))
+    }
+
+    /// Removes an observer. Returns `false` if it did not exist.
+    pub fn remove_observer(&mut self, id: ObserverId) -> bool {
+        self.observers.remove(id)
+    }
+
+    /// Returns `true` if inserting or removing any of `component_ids` on `entity` may run
+    /// hooks or observers.
+    #[inline]
+    pub(crate) fn has_component_listeners(
+        &self,
+        entity: Entity,
+        component_ids: &[ComponentId],
+    ) -> bool {
+        self.observers.has_observers(entity) || self.has_component_hooks(component_ids)
+    }
+
+    /// Returns `true` if any of `component_ids` has hooks.
+    #[inline]
+    pub(crate) fn has_component_hooks(&self, component_ids: &[ComponentId]) -> bool {
+        component_ids.iter().any(|&id| {
+            self.components
+                .get_info(id)
+                .map_or(false, |info| !info.hooks().is_empty())
+        })
+    }
+
+    /// Returns the components of `component_ids` that are not in the archetype `archetype_id`,
+    /// which inserting them would add.
+    pub(crate) fn components_added_to(
+        &self,
+        archetype_id: ArchetypeId,
+        component_ids: &[ComponentId],
+    ) -> Vec<ComponentId> {
+        let archetype = &self.archetypes[archetype_id];
+        component_ids
+            .iter()
+            .copied()
+            .filter(|&id| !archetype.contains(id))
+            .collect()
+    }
+
+    /// Runs the hook and the observers of `entity` for `event`, for each of `component_ids` in
+    /// order. Stops early if `entity` is despawned.
+    pub(crate) fn trigger_component_event(
+        &mut self,
+        event: ComponentEvent,
+        entity: Entity,
+        component_ids: &[ComponentId],
+    ) {
+        for &component_id in component_ids {
+            if self.entities.get(entity).is_none() {
+                return;
+            }
+            if let Some(hook) = self
+                .components
+                .get_info(component_id)
+                .and_then(|info| info.hooks().get(event))
+            {
+                hook(self, entity, component_id);
+            }
+            self.run_observers(event, entity, component_id);
+        }
+    }
+
+    fn run_observers(&mut self, event: ComponentEvent, entity: Entity, component_id: ComponentId) {
+        let entity_observers = match self.observers.entities.get_mut(&entity) {
+            Some(entity_observers) => entity_observers,
+            None => return,
+        };
+        // Observers may be reentrant, so take the ones that match out while they run.
+        let mut running = Vec::new();
+        let mut index = 0;
+        while index < entity_observers.observers.len() {
+            let observer = &entity_observers.observers[index];
+            if observer.event == event && observer.component_id == component_id {
+                running.push(entity_observers.observers.remove(index));
+            } else {
+                index += 1;
+            }
+        }
+        if running.is_empty() {
+            return;
+        }
+        let was_running = std::mem::replace(&mut entity_observers.running, true);
+
+        for observer in &mut running {
+            let removed = self
+                .observers
+                .entities
+                .get(&entity)
+                .map_or(true, |observers| {
+                    observers.removed.contains(&observer.index)
+                });
+            if removed || self.entities.get(entity).is_none() {
+                continue;
+            }
+            (observer.callback)(self, entity, component_id);
+        }
+
+        if self.entities.get(entity).is_none() {
+            // The entity was despawned by an observer, which already dropped its other observers.
+            self.observers.entities.remove(&entity);
+            return;
+        }
+        let entity_observers = self.observers.entities.entry(entity).or_default();
+        entity_observers.running = was_running;
+        let removed = std::mem::take(&mut entity_observers.removed);
+        running.retain(|observer| !removed.contains(&observer.index));
+        if was_running {
+            entity_observers.removed = removed;
+        }
+        // Put the observers back in registration order.
+        entity_observers.observers.extend(running);
+        entity_observers
+            .observers
+            .sort_unstable_by_key(|observer| observer.index);
+        if entity_observers.observers.is_empty() && !entity_observers.running {
+            self.observers.entities.remove(&entity);
+        }
+    }
+
+    /// Drops the observers of a despawned entity.
+    pub(crate) fn despawn_observers(&mut self, entity: Entity) {
+        self.observers.entities.remove(&entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        component::{ComponentEvent, ComponentId},
+        prelude::*,
+        system::CommandQueue,
+    };
+
+    #[derive(Component)]
+    struct A(u32);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct B;
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<&'static str>);
+
+    fn log(world: &mut World, message: &'static str) {
+        world.resource_mut::<Log>().0.push(message);
+    }
+
+    fn take_log(world: &mut World) -> Vec<&'static str> {
+        std::mem::take(&mut world.resource_mut::<Log>().0)
+    }
+
+    #[test]
+    fn component_hooks() {
+        let mut world = World::new();
+        world.init_resource::<Log>();
+        world
+            .register_component_hooks::<A>()
+            .on_add(|world, _, _| log(world, "add A"))
+            .on_insert(|world, _, _| log(world, "insert A"))
+            .on_remove(|world, entity, _| {
+                // the component is still there when the hook runs
+                assert!(world.get::<A>(entity).is_some());
+                log(world, "remove A");
+            });
+        world
+            .register_component_hooks::<B>()
+            .on_add(|world, _, _| log(world, "add B"))
+            .on_remove(|world, _, _| log(world, "remove B"));
+
+        let entity = world.spawn(A(0)).id();
+        assert_eq!(take_log(&mut world), vec!["add A", "insert A"]);
+
+        world.entity_mut(entity).insert((A(1), B));
+        assert_eq!(take_log(&mut world), vec!["add B", "insert A"]);
+
+        world.entity_mut(entity).remove::<A>();
+        assert_eq!(take_log(&mut world), vec!["remove A"]);
+
+        world.entity_mut(entity).remove_intersection::<(A, B)>();
+        assert_eq!(take_log(&mut world), vec!["remove B"]);
+
+        world.entity_mut(entity).insert(A(2));
+        world.despawn(entity);
+        assert_eq!(take_log(&mut world), vec!["add A", "insert A", "remove A"]);
+
+        world.spawn_batch([A(3), A(4)]).for_each(drop);
+        assert_eq!(
+            take_log(&mut world),
+            vec!["add A", "insert A", "add A", "insert A"]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn component_hook_registered_twice() {
+        let mut world = World::new();
+        world
+            .register_component_hooks::<A>()
+            .on_add(|_, _, _| {})
+            .on_add(|_, _, _| {});
+    }
+
+    #[test]
+    fn hooks_run_on_command_application() {
+        let mut world = World::new();
+        world.init_resource::<Log>();
+        world
+            .register_component_hooks::<A>()
+            .on_add(|world, _, _| log(world, "add A"))
+            .on_remove(|world, _, _| log(world, "remove A"));
+
+        let mut queue = CommandQueue::default();
+        let entity = {
+            let mut commands = Commands::new(&mut queue, &world);
+            let entity = commands.spawn(A(0)).id();
+            commands.entity(entity).remove::<A>();
+            commands.entity(entity).insert(A(1));
+            commands.entity(entity).despawn();
+            entity
+        };
+        queue.apply(&mut world);
+        assert!(world.get_entity(entity).is_none());
+        assert_eq!(
+            take_log(&mut world),
+            vec!["add A", "remove A", "add A", "remove A"]
+        );
+    }
+
+    #[test]
+    fn entity_observers() {
+        let mut world = World::new();
+        world.init_resource::<Log>();
+        let observed = world.spawn(A(0)).id();
+        let other = world.spawn(A(0)).id();
+
+        world
+            .register_component_hooks::<A>()
+            .on_insert(|world, _, _| log(world, "hook"));
+        world
+            .entity_mut(observed)
+            .observe::<A>(ComponentEvent::Insert, |world, _, _| log(world, "first"));
+        let second = world
+            .entity_mut(observed)
+            .observe::<A>(ComponentEvent::Insert, |world, _, _| log(world, "second"));
+        world
+            .entity_mut(observed)
+            .observe::<A>(ComponentEvent::Remove, |world, entity, _| {
+                assert_eq!(world.get::<A>(entity).unwrap().0, 2);
+                log(world, "removed");
+            });
+
+        world.entity_mut(other).insert(A(1));
+        assert_eq!(take_log(&mut world), vec!["hook"]);
+
+        world.entity_mut(observed).insert(A(1));
+        assert_eq!(take_log(&mut world), vec!["hook", "first", "second"]);
+
+        assert!(world.remove_observer(second));
+        assert!(!world.remove_observer(second));
+        world.entity_mut(observed).insert(A(2));
+        assert_eq!(take_log(&mut world), vec!["hook", "first"]);
+
+        world.despawn(observed);
+        assert_eq!(take_log(&mut world), vec!["removed"]);
+        assert!(!world.observers().has_observers(observed));
+    }
+
+    #[test]
+    fn observers_can_modify_the_world() {
+        let mut world = World::new();
+        world.init_resource::<Log>();
+        let entity = world.spawn_empty().id();
+        world
+            .entity_mut(entity)
+            .observe::<A>(ComponentEvent::Add, |world, entity, _| {
+                // structural changes to the observed entity are allowed
+                world.entity_mut(entity).insert(B);
+                log(world, "add A");
+            });
+        world
+            .entity_mut(entity)
+            .observe::<B>(ComponentEvent::Add, |world, entity, _| {
+                world.despawn(entity);
+                log(world, "add B");
+            });
+
+        let mut entity_mut = world.entity_mut(entity);
+        entity_mut.insert(A(0));
+        assert!(entity_mut.is_despawned());
+        assert_eq!(take_log(&mut world), vec!["add B", "add A"]);
+        assert!(world.get_entity(entity).is_none());
+        assert!(!world.observers().has_observers(entity));
+    }
+
+    #[test]
+    fn observe_dynamic_component() {
+        let mut world = World::new();
+        world.init_resource::<Log>();
+        let a = world.init_component::<A>();
+        let entity = world.spawn(A(0)).id();
+        world.observe_by_id(
+            entity,
+            ComponentEvent::Remove,
+            a,
+            |world, _, component_id: ComponentId| {
+                assert_eq!(Some(component_id), world.components().component_id::<A>());
+                log(world, "removed");
+            },
+        );
+        world.entity_mut(entity).remove::<A>();
+        assert_eq!(take_log(&mut world), vec!["removed"]);
+    }
+}
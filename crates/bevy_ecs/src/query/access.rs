@@ -227,6 +227,15 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
         self.without.insert(index.sparse_set_index());
     }
 
+    /// Returns `true` if the element given by `index` is accessed, or is required to be present
+    /// or not present.
+    pub fn refers_to(&self, index: T) -> bool {
+        let index = index.sparse_set_index();
+        self.access.reads_and_writes.contains(index)
+            || self.with.contains(index)
+            || self.without.contains(index)
+    }
+
     pub fn extend_intersect_filter(&mut self, other: &FilteredAccess<T>) {
         self.without.intersect_with(&other.without);
         self.with.intersect_with(&other.with);
@@ -386,8 +395,8 @@ mod tests {
         let mut access_d = Access::<usize>::default();
         access_d.add_read(0);
 
-        assert_eq!(access_d.get_conflicts(&access_a), vec![]);
-        assert_eq!(access_d.get_conflicts(&access_b), vec![]);
+        assert_eq!(access_d.get_conflicts(&access_a), Vec::<usize>::new());
+        assert_eq!(access_d.get_conflicts(&access_b), Vec::<usize>::new());
         assert_eq!(access_d.get_conflicts(&access_c), vec![0]);
     }
 
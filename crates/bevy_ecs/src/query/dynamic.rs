@@ -0,0 +1,565 @@
+use crate::{
+    archetype::{Archetype, ArchetypeComponentId},
+    change_detection::{MutUntyped, Ticks},
+    component::{ComponentId, StorageType},
+    entity::Entity,
+    query::{Access, DebugCheckedUnwrap, FilteredAccess, ReadOnlyWorldQuery, WorldQuery},
+    storage::{Column, ComponentSparseSet, Table},
+    world::World,
+};
+use bevy_ptr::{Ptr, UnsafeCellDeref};
+
+/// How a [`DynamicQuery`] accesses one of its components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DynamicAccess {
+    /// Fetches the component immutably, as a [`DynamicItem::Ref`].
+    Read,
+    /// Fetches the component mutably, as a [`DynamicItem::Mut`].
+    Write,
+    /// Only matches entities that have the component, without fetching it.
+    With,
+    /// Only matches entities that do not have the component.
+    Without,
+}
+
+/// A component fetched by a [`DynamicQuery`].
+pub enum DynamicItem<'w> {
+    /// A component fetched with [`DynamicAccess::Read`], or with [`DynamicAccess::Write`] through
+    /// a read-only query.
+    Ref(Ptr<'w>),
+    /// A component fetched with [`DynamicAccess::Write`].
+    Mut(MutUntyped<'w>),
+}
+
+impl std::fmt::Debug for DynamicItem<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DynamicItem::Ref(ptr) => f.debug_tuple("Ref").field(&ptr.as_ptr()).finish(),
+            DynamicItem::Mut(component) => f.debug_tuple("Mut").field(component).finish(),
+        }
+    }
+}
+
+impl<'w> DynamicItem<'w> {
+    /// Returns the mutable access to the component, if it was fetched with
+    /// [`DynamicAccess::Write`].
+    pub fn into_mut(self) -> Option<MutUntyped<'w>> {
+        match self {
+            DynamicItem::Ref(_) => None,
+            DynamicItem::Mut(component) => Some(component),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct DynamicTerm {
+    component_id: ComponentId,
+    access: DynamicAccess,
+    storage_type: StorageType,
+}
+
+/// The [`WorldQuery::State`] of a [`DynamicQuery`]: the components it accesses, and how.
+#[derive(Clone, Debug, Default)]
+pub struct DynamicQueryState {
+    terms: Vec<DynamicTerm>,
+}
+
+impl DynamicQueryState {
+    /// # Panics
+    ///
+    /// Panics if any of the [`ComponentId`]s is not registered in `world`.
+    pub(crate) fn new(
+        world: &World,
+        terms: impl IntoIterator<Item = (ComponentId, DynamicAccess)>,
+    ) -> Self {
+        let terms = terms
+            .into_iter()
+            .map(|(component_id, access)| {
+                let info = world
+                    .components()
+                    .get_info(component_id)
+                    .unwrap_or_else(|| {
+                        panic!(
+                            "Component {:?} is not registered in this World",
+                            component_id
+                        )
+                    });
+                DynamicTerm {
+                    component_id,
+                    access,
+                    storage_type: info.storage_type(),
+                }
+            })
+            .collect();
+        Self { terms }
+    }
+
+    /// Returns the [`ComponentId`]s accessed by the query, and how.
+    pub fn terms(&self) -> impl Iterator<Item = (ComponentId, DynamicAccess)> + '_ {
+        self.terms
+            .iter()
+            .map(|term| (term.component_id, term.access))
+    }
+
+    fn fetched_terms(&self) -> impl Iterator<Item = &DynamicTerm> {
+        self.terms
+            .iter()
+            .filter(|term| matches!(term.access, DynamicAccess::Read | DynamicAccess::Write))
+    }
+
+    fn update_component_access(&self, access: &mut FilteredAccess<ComponentId>, readonly: bool) {
+        for term in &self.terms {
+            match term.access {
+                DynamicAccess::Write if !readonly => {
+                    assert!(
+                        !access.access().has_read(term.component_id),
+                        "Write access to {:?} conflicts with a previous access in this query. Mutable component access must be unique.",
+                        term.component_id,
+                    );
+                    access.add_write(term.component_id);
+                }
+                DynamicAccess::Read | DynamicAccess::Write => {
+                    assert!(
+                        !access.access().has_write(term.component_id),
+                        "Read access to {:?} conflicts with a previous write access in this query. Shared access cannot coincide with exclusive access.",
+                        term.component_id,
+                    );
+                    access.add_read(term.component_id);
+                }
+                DynamicAccess::With => access.add_with(term.component_id),
+                DynamicAccess::Without => access.add_without(term.component_id),
+            }
+        }
+    }
+
+    fn update_archetype_component_access(
+        &self,
+        archetype: &Archetype,
+        access: &mut Access<ArchetypeComponentId>,
+        readonly: bool,
+    ) {
+        for term in self.fetched_terms() {
+            if let Some(archetype_component_id) =
+                archetype.get_archetype_component_id(term.component_id)
+            {
+                if term.access == DynamicAccess::Write && !readonly {
+                    access.add_write(archetype_component_id);
+                } else {
+                    access.add_read(archetype_component_id);
+                }
+            }
+        }
+    }
+
+    fn matches_component_set(&self, set_contains_id: &impl Fn(ComponentId) -> bool) -> bool {
+        self.terms.iter().all(|term| match term.access {
+            DynamicAccess::Without => !set_contains_id(term.component_id),
+            _ => set_contains_id(term.component_id),
+        })
+    }
+}
+
+#[doc(hidden)]
+pub struct DynamicFetch<'w> {
+    components: Vec<DynamicComponentFetch<'w>>,
+    last_change_tick: u32,
+    change_tick: u32,
+}
+
+#[derive(Clone, Copy)]
+struct DynamicComponentFetch<'w> {
+    component_id: ComponentId,
+    write: bool,
+    // StorageType::Table
+    table_column: Option<&'w Column>,
+    // StorageType::SparseSet
+    sparse_set: Option<&'w ComponentSparseSet>,
+}
+
+impl<'w> DynamicFetch<'w> {
+    /// # Safety
+    ///
+    /// `state` must have been created from `world`.
+    unsafe fn new(
+        world: &'w World,
+        state: &DynamicQueryState,
+        readonly: bool,
+        last_change_tick: u32,
+        change_tick: u32,
+    ) -> Self {
+        let components = state
+            .fetched_terms()
+            .map(|term| DynamicComponentFetch {
+                component_id: term.component_id,
+                write: term.access == DynamicAccess::Write && !readonly,
+                table_column: None,
+                sparse_set: (term.storage_type == StorageType::SparseSet).then(|| {
+                    world
+                        .storages()
+                        .sparse_sets
+                        .get(term.component_id)
+                        .debug_checked_unwrap()
+                }),
+            })
+            .collect();
+        DynamicFetch {
+            components,
+            last_change_tick,
+            change_tick,
+        }
+    }
+
+    fn clone_fetch(&self) -> Self {
+        DynamicFetch {
+            components: self.components.clone(),
+            last_change_tick: self.last_change_tick,
+            change_tick: self.change_tick,
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `table` must contain every table-stored component of this fetch.
+    unsafe fn set_table(&mut self, table: &'w Table) {
+        for component in &mut self.components {
+            if component.sparse_set.is_none() {
+                component.table_column = Some(
+                    table
+                        .get_column(component.component_id)
+                        .debug_checked_unwrap(),
+                );
+            }
+        }
+    }
+
+    /// # Safety
+    ///
+    /// `entity` and `table_row` must be in the table or archetype set last, and the access
+    /// described by this fetch must not conflict with any other live access.
+    unsafe fn fetch(&mut self, entity: Entity, table_row: usize) -> Vec<DynamicItem<'w>> {
+        self.components
+            .iter()
+            .map(|component| {
+                let (value, ticks) = match component.sparse_set {
+                    Some(sparse_set) => sparse_set.get_with_ticks(entity).debug_checked_unwrap(),
+                    None => {
+                        let column = component.table_column.debug_checked_unwrap();
+                        (
+                            column.get_data_unchecked(table_row),
+                            column.get_ticks_unchecked(table_row),
+                        )
+                    }
+                };
+                if component.write {
+                    DynamicItem::Mut(MutUntyped {
+                        value: value.assert_unique(),
+                        ticks: Ticks {
+                            component_ticks: ticks.deref_mut(),
+                            last_change_tick: self.last_change_tick,
+                            change_tick: self.change_tick,
+                        },
+                    })
+                } else {
+                    DynamicItem::Ref(value)
+                }
+            })
+            .collect()
+    }
+}
+
+/// A [`WorldQuery`] over components chosen at runtime, such as components registered with
+/// [`World::init_component_with_descriptor`].
+///
+/// Each fetched item contains one [`DynamicItem`] for every component accessed with
+/// [`DynamicAccess::Read`] or [`DynamicAccess::Write`], in the order they were given.
+/// The query's access is recorded like that of any other query, so it takes part in
+/// conflict checks and parallel scheduling.
+///
+/// A [`QueryState`](crate::query::QueryState) for this query is created with
+/// [`World::query_dynamic`] or [`World::query_dynamic_filtered`]. Its read-only version is
+/// [`ReadOnlyDynamicQuery`], which fetches every component as a [`DynamicItem::Ref`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::{DynamicAccess, DynamicItem};
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// let mut world = World::new();
+/// let health = world.init_component::<Health>();
+/// world.spawn(Health(10));
+///
+/// let mut query = world.query_dynamic([(health, DynamicAccess::Write)]);
+/// for components in query.iter_mut(&mut world) {
+///     for component in components {
+///         let component = component.into_mut().unwrap();
+///         // SAFETY: `health` is the id of the `Health` component
+///         unsafe { component.into_inner().deref_mut::<Health>().0 += 1 };
+///     }
+/// }
+/// ```
+pub struct DynamicQuery;
+
+/// The read-only version of a [`DynamicQuery`].
+pub struct ReadOnlyDynamicQuery;
+
+macro_rules! impl_dynamic_query {
+    ($query: ident, $readonly: literal) => {
+        // SAFETY: access is recorded for every fetched component, and matches the fetch
+        unsafe impl WorldQuery for $query {
+            type Fetch<'w> = DynamicFetch<'w>;
+            type Item<'w> = Vec<DynamicItem<'w>>;
+            type ReadOnly = ReadOnlyDynamicQuery;
+            type State = DynamicQueryState;
+
+            fn shrink<'wlong: 'wshort, 'wshort>(
+                item: Vec<DynamicItem<'wlong>>,
+            ) -> Vec<DynamicItem<'wshort>> {
+                item
+            }
+
+            const IS_DENSE: bool = false;
+
+            const IS_ARCHETYPAL: bool = true;
+
+            unsafe fn init_fetch<'w>(
+                world: &'w World,
+                state: &DynamicQueryState,
+                last_change_tick: u32,
+                change_tick: u32,
+            ) -> DynamicFetch<'w> {
+                DynamicFetch::new(world, state, $readonly, last_change_tick, change_tick)
+            }
+
+            unsafe fn clone_fetch<'w>(fetch: &Self::Fetch<'w>) -> Self::Fetch<'w> {
+                fetch.clone_fetch()
+            }
+
+            #[inline]
+            unsafe fn set_archetype<'w>(
+                fetch: &mut DynamicFetch<'w>,
+                _state: &DynamicQueryState,
+                _archetype: &'w Archetype,
+                table: &'w Table,
+            ) {
+                fetch.set_table(table);
+            }
+
+            #[inline]
+            unsafe fn set_table<'w>(
+                fetch: &mut DynamicFetch<'w>,
+                _state: &DynamicQueryState,
+                table: &'w Table,
+            ) {
+                fetch.set_table(table);
+            }
+
+            #[inline]
+            unsafe fn fetch<'w>(
+                fetch: &mut Self::Fetch<'w>,
+                entity: Entity,
+                table_row: usize,
+            ) -> Self::Item<'w> {
+                fetch.fetch(entity, table_row)
+            }
+
+            fn update_component_access(
+                state: &DynamicQueryState,
+                access: &mut FilteredAccess<ComponentId>,
+            ) {
+                state.update_component_access(access, $readonly);
+            }
+
+            fn update_archetype_component_access(
+                state: &DynamicQueryState,
+                archetype: &Archetype,
+                access: &mut Access<ArchetypeComponentId>,
+            ) {
+                state.update_archetype_component_access(archetype, access, $readonly);
+            }
+
+            fn init_state(_world: &mut World) -> DynamicQueryState {
+                DynamicQueryState::default()
+            }
+
+            fn matches_component_set(
+                state: &DynamicQueryState,
+                set_contains_id: &impl Fn(ComponentId) -> bool,
+            ) -> bool {
+                state.matches_component_set(set_contains_id)
+            }
+        }
+    };
+}
+
+impl_dynamic_query!(DynamicQuery, false);
+impl_dynamic_query!(ReadOnlyDynamicQuery, true);
+
+/// SAFETY: every component is fetched immutably
+unsafe impl ReadOnlyWorldQuery for ReadOnlyDynamicQuery {}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynamicAccess, DynamicItem};
+    use crate as bevy_ecs;
+    use crate::{
+        component::{ComponentDescriptor, StorageType},
+        prelude::*,
+        query::FilteredAccess,
+    };
+    use bevy_ptr::OwningPtr;
+    use std::alloc::Layout;
+
+    #[derive(Component)]
+    struct A(u32);
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct B(u32);
+
+    #[derive(Component)]
+    struct C;
+
+    fn descriptor(name: &str, storage_type: StorageType) -> ComponentDescriptor {
+        // SAFETY: a `u64` is `Send + Sync` and does not need to be dropped
+        unsafe {
+            ComponentDescriptor::new_with_layout(
+                name.to_string(),
+                storage_type,
+                Layout::new::<u64>(),
+                None,
+            )
+        }
+    }
+
+    #[test]
+    fn dynamic_components() {
+        let mut world = World::new();
+        let table = world.init_component_with_descriptor(descriptor("table", StorageType::Table));
+        let sparse =
+            world.init_component_with_descriptor(descriptor("sparse", StorageType::SparseSet));
+
+        let both = world.spawn_empty().id();
+        OwningPtr::make(1u64, |table_value| {
+            OwningPtr::make(2u64, |sparse_value| {
+                // SAFETY: both values are `u64`s, matching the layout of their components
+                unsafe {
+                    world
+                        .entity_mut(both)
+                        .insert_by_ids(&[table, sparse], [table_value, sparse_value]);
+                }
+            });
+        });
+        let table_only = world.spawn_empty().id();
+        OwningPtr::make(3u64, |value| {
+            // SAFETY: the value is a `u64`, matching the layout of the component
+            unsafe { world.entity_mut(table_only).insert_by_id(table, value) };
+        });
+
+        // SAFETY: the components hold `u64`s
+        let read = |ptr: bevy_ptr::Ptr<'_>| unsafe { *ptr.deref::<u64>() };
+        assert_eq!(read(world.get_by_id(both, table).unwrap()), 1);
+        assert_eq!(read(world.get_by_id(both, sparse).unwrap()), 2);
+        assert_eq!(read(world.get_by_id(table_only, table).unwrap()), 3);
+
+        let mut query =
+            world.query_dynamic([(table, DynamicAccess::Write), (sparse, DynamicAccess::Read)]);
+        for components in query.iter_mut(&mut world) {
+            let mut components = components.into_iter();
+            let table_value = components.next().unwrap().into_mut().unwrap();
+            let sparse_value = match components.next().unwrap() {
+                DynamicItem::Ref(ptr) => read(ptr),
+                DynamicItem::Mut(_) => panic!("expected read access"),
+            };
+            // SAFETY: the component holds a `u64`
+            unsafe { *table_value.into_inner().deref_mut::<u64>() += sparse_value };
+        }
+        assert_eq!(read(world.get_by_id(both, table).unwrap()), 3);
+
+        let mut query = world.query_dynamic([
+            (table, DynamicAccess::Read),
+            (sparse, DynamicAccess::Without),
+        ]);
+        let values = query
+            .iter(&world)
+            .map(|components| match components[0] {
+                DynamicItem::Ref(ptr) => read(ptr),
+                DynamicItem::Mut(_) => panic!("expected read access"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, vec![3]);
+    }
+
+    #[test]
+    fn dynamic_query_with_rust_components() {
+        let mut world = World::new();
+        world.spawn((A(1), B(2)));
+        world.spawn((A(3), B(4), C));
+        let a = world.init_component::<A>();
+        let b = world.init_component::<B>();
+        let c = world.init_component::<C>();
+
+        let mut query = world.query_dynamic_filtered::<With<C>>([
+            (a, DynamicAccess::Write),
+            (b, DynamicAccess::Write),
+        ]);
+        for components in query.iter_mut(&mut world) {
+            let mut components = components.into_iter();
+            let a = components.next().unwrap().into_mut().unwrap();
+            let b = components.next().unwrap().into_mut().unwrap();
+            // SAFETY: the components were requested in the order `A`, `B`
+            unsafe {
+                a.into_inner().deref_mut::<A>().0 += 10;
+                b.into_inner().deref_mut::<B>().0 += 10;
+            }
+        }
+        let mut values = world
+            .query::<(&A, &B)>()
+            .iter(&world)
+            .map(|(a, b)| (a.0, b.0))
+            .collect::<Vec<_>>();
+        values.sort_unstable();
+        assert_eq!(values, vec![(1, 2), (13, 14)]);
+
+        let mut query = world.query_dynamic([(b, DynamicAccess::Read), (c, DynamicAccess::With)]);
+        assert_eq!(query.iter(&world).count(), 1);
+        let mut query = world.query_dynamic([(a, DynamicAccess::With)]);
+        assert_eq!(query.iter(&world).count(), 2);
+        assert!(query.iter(&world).all(|components| components.is_empty()));
+    }
+
+    #[test]
+    fn dynamic_query_access() {
+        let mut world = World::new();
+        let a = world.init_component::<A>();
+        let b = world.init_component::<B>();
+        world.spawn((A(1), B(2)));
+
+        let query = world.query_dynamic([(a, DynamicAccess::Write), (b, DynamicAccess::Read)]);
+        let mut expected = FilteredAccess::default();
+        expected.add_write(a);
+        expected.add_read(b);
+        assert_eq!(query.component_access(), &expected);
+        assert_eq!(
+            query.component_access(),
+            world.query::<(&mut A, &B)>().component_access(),
+            "a dynamic query has the same access as the equivalent typed query"
+        );
+        assert_eq!(
+            query.archetype_component_access(),
+            world.query::<(&mut A, &B)>().archetype_component_access()
+        );
+        assert_eq!(query.archetype_component_access().writes().count(), 1);
+        assert_eq!(query.archetype_component_access().reads().count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn dynamic_query_conflicting_access() {
+        let mut world = World::new();
+        let a = world.init_component::<A>();
+        world.query_dynamic([(a, DynamicAccess::Read), (a, DynamicAccess::Write)]);
+    }
+}
@@ -1,13 +1,17 @@
 mod access;
+mod dynamic;
 mod fetch;
 mod filter;
 mod iter;
+mod order;
 mod state;
 
 pub use access::*;
+pub use dynamic::*;
 pub use fetch::*;
 pub use filter::*;
 pub use iter::*;
+pub use order::*;
 pub use state::*;
 
 /// A debug checked version of [`Option::unwrap_unchecked`]. Will panic in
@@ -820,4 +824,76 @@ mod tests {
         let _: [&Foo; 1] = q.many([e]);
         let _: &Foo = q.single();
     }
+
+    #[test]
+    fn sorted_iteration() {
+        let mut world = World::new();
+        world.spawn((A(3), B(0)));
+        world.spawn((A(1), B(1)));
+        world.spawn((A(2), B(2), Sparse(0)));
+        world.spawn((A(1), B(3), Sparse(1)));
+
+        let mut state = SystemState::<Query<(&A, &mut B)>>::new(&mut world);
+        let mut query = state.get_mut(&mut world);
+        let sorted: Vec<_> = query
+            .iter_sorted_by_key(|(a, _)| a.0)
+            .map(|(a, b)| (a.0, b.0))
+            .collect();
+        assert_eq!(sorted, [(1, 1), (1, 3), (2, 2), (3, 0)]);
+
+        let sorted: Vec<_> = query
+            .iter_sorted_by(|(_, b1), (_, b2)| b2.0.cmp(&b1.0))
+            .map(|(_, b)| b.0)
+            .collect();
+        assert_eq!(sorted, [3, 2, 1, 0]);
+
+        for (i, (_, mut b)) in query
+            .iter_mut_sorted_by_key(|(a, _)| std::cmp::Reverse(a.0))
+            .enumerate()
+        {
+            b.0 = i;
+        }
+        let mut query = world.query::<(&A, &B)>();
+        let mut values: Vec<_> = query.iter(&world).map(|(a, b)| (a.0, b.0)).collect();
+        values.sort_unstable();
+        assert_eq!(values, [(1, 2), (1, 3), (2, 1), (3, 0)]);
+
+        let sorted: Vec<_> = SystemState::<Query<&Sparse>>::new(&mut world)
+            .get(&world)
+            .iter_sorted_by_key(|sparse| std::cmp::Reverse(sparse.0))
+            .map(|sparse| sparse.0)
+            .collect();
+        assert_eq!(sorted, [1, 0]);
+    }
+
+    #[test]
+    fn query_order() {
+        use super::QueryOrder;
+
+        #[derive(Component, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Key(i32);
+
+        let mut world = World::new();
+        let a = world.spawn((Key(3), A(0))).id();
+        let b = world.spawn((Key(1), A(0))).id();
+        let c = world.spawn(Key(2)).id();
+
+        let mut state = SystemState::<QueryOrder<Key, With<A>>>::new(&mut world);
+        assert_eq!(state.get_mut(&mut world).entities(), [b, a]);
+
+        let d = world.spawn((Key(2), A(0))).id();
+        world.entity_mut(c).insert(A(0));
+        assert_eq!(state.get_mut(&mut world).entities(), [b, d, c, a]);
+
+        world.get_mut::<Key>(a).unwrap().0 = 0;
+        world.entity_mut(d).remove::<A>();
+        world.despawn(b);
+        assert_eq!(state.get_mut(&mut world).entities(), [a, c]);
+
+        // Unchanged order, and entities with equal keys keep their previous order.
+        world.get_mut::<Key>(c).unwrap().0 = 0;
+        let mut order = state.get_mut(&mut world);
+        assert_eq!(order.entities(), [a, c]);
+        assert_eq!(order.entities(), [a, c]);
+    }
 }
@@ -0,0 +1,110 @@
+use crate as bevy_ecs;
+use crate::{
+    change_detection::MAX_CHANGE_AGE,
+    component::Component,
+    entity::Entity,
+    query::{ChangeTrackers, ReadOnlyWorldQuery},
+    system::{Local, Query, SystemParam},
+};
+use bevy_utils::HashMap;
+
+/// [`SystemParam`] keeping the entities matching `With<C>` and the filter `F` sorted by their
+/// component `C`, across the runs of the system.
+///
+/// Unlike [`Query::iter_sorted_by_key`], the order is cached: each call to
+/// [`entities`](Self::entities) still visits every matching entity to check its change ticks,
+/// but only clones the components `C` that were added or changed since the last call, and sorts
+/// again only if one of them moved. The sort is stable, so entities with equal components keep
+/// their previous order.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::query::QueryOrder;
+/// #[derive(Component, Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// struct ZIndex(i32);
+///
+/// #[derive(Component)]
+/// struct Node;
+///
+/// fn draw_nodes(mut order: QueryOrder<ZIndex, With<Node>>, nodes: Query<(Entity, &Node)>) {
+///     for (entity, node) in nodes.iter_many(order.entities()) {
+///         // Draw back to front.
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(draw_nodes);
+/// ```
+#[derive(SystemParam)]
+pub struct QueryOrder<'w, 's, C, F = ()>
+where
+    C: Component + Ord + Clone,
+    F: ReadOnlyWorldQuery + 'static,
+{
+    query: Query<'w, 's, (Entity, &'static C, ChangeTrackers<C>), F>,
+    cache: Local<'s, QueryOrderCache<C>>,
+}
+
+/// The state of a [`QueryOrder`].
+#[doc(hidden)]
+pub struct QueryOrderCache<C> {
+    entities: Vec<Entity>,
+    keys: HashMap<Entity, C>,
+    /// The change tick up to which changes of `C` were applied.
+    last_change_tick: u32,
+}
+
+impl<C> Default for QueryOrderCache<C> {
+    fn default() -> Self {
+        Self {
+            entities: Vec::new(),
+            keys: HashMap::default(),
+            last_change_tick: 0,
+        }
+    }
+}
+
+impl<'w, 's, C, F> QueryOrder<'w, 's, C, F>
+where
+    C: Component + Ord + Clone,
+    F: ReadOnlyWorldQuery + 'static,
+{
+    /// Returns the matching entities, sorted by their component `C`.
+    pub fn entities(&mut self) -> &[Entity] {
+        let change_tick = self.query.change_tick;
+        let cache = &mut *self.cache;
+        // Ticks older than `MAX_CHANGE_AGE` can not be compared, so every value is checked.
+        let check_all = change_tick.wrapping_sub(cache.last_change_tick) > MAX_CHANGE_AGE;
+        let mut moved = false;
+        let mut len = 0;
+        for (entity, key, trackers) in &self.query {
+            len += 1;
+            if !check_all
+                && !trackers
+                    .component_ticks
+                    .is_changed(cache.last_change_tick, change_tick)
+                && cache.keys.contains_key(&entity)
+            {
+                continue;
+            }
+            match cache.keys.insert(entity, key.clone()) {
+                Some(previous) => moved |= previous != *key,
+                None => {
+                    cache.entities.push(entity);
+                    moved = true;
+                }
+            }
+        }
+        if len != cache.keys.len() {
+            // Some entities stopped matching the query.
+            let query = &self.query;
+            cache.keys.retain(|&entity, _| query.contains(entity));
+            cache.entities.retain(|&entity| query.contains(entity));
+        }
+        if moved {
+            let keys = &cache.keys;
+            cache.entities.sort_by(|a, b| keys[a].cmp(&keys[b]));
+        }
+        cache.last_change_tick = change_tick;
+        &cache.entities
+    }
+}
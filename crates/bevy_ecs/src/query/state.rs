@@ -1,7 +1,7 @@
 use crate::{
     archetype::{Archetype, ArchetypeComponentId, ArchetypeGeneration, ArchetypeId},
     component::ComponentId,
-    entity::Entity,
+    entity::{Disabled, Entity},
     prelude::FromWorld,
     query::{
         Access, DebugCheckedUnwrap, FilteredAccess, QueryCombinationIter, QueryIter, WorldQuery,
@@ -29,6 +29,8 @@ pub struct QueryState<Q: WorldQuery, F: ReadOnlyWorldQuery = ()> {
     pub(crate) matched_archetypes: FixedBitSet,
     pub(crate) archetype_component_access: Access<ArchetypeComponentId>,
     pub(crate) component_access: FilteredAccess<ComponentId>,
+    /// The id of [`Disabled`] if archetypes containing it are not matched.
+    disabled: Option<ComponentId>,
     // NOTE: we maintain both a TableId bitset and a vec because iterating the vec is faster
     pub(crate) matched_table_ids: Vec<TableId>,
     // NOTE: we maintain both a ArchetypeId bitset and a vec because iterating the vec is faster
@@ -97,7 +99,18 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
     pub fn new(world: &mut World) -> Self {
         let fetch_state = Q::init_state(world);
         let filter_state = F::init_state(world);
+        Self::new_with_state(world, fetch_state, filter_state)
+    }
 
+    /// Creates a new [`QueryState`] from already initialized fetch and filter states.
+    ///
+    /// `fetch_state` and `filter_state` must have been initialized from `world`, as in
+    /// [`WorldQuery::init_state`].
+    pub(crate) fn new_with_state(
+        world: &World,
+        fetch_state: Q::State,
+        filter_state: F::State,
+    ) -> Self {
         let mut component_access = FilteredAccess::default();
         Q::update_component_access(&fetch_state, &mut component_access);
 
@@ -111,6 +124,12 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         // properly considered in a global "cross-query" context (both within systems and across systems).
         component_access.extend(&filter_component_access);
 
+        // Disabled entities are only matched by queries referring to `Disabled`.
+        let disabled = world
+            .components
+            .component_id::<Disabled>()
+            .filter(|&id| !component_access.refers_to(id));
+
         let mut state = Self {
             world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
@@ -119,6 +138,7 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
             fetch_state,
             filter_state,
             component_access,
+            disabled,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
             archetype_component_access: Default::default(),
@@ -127,6 +147,22 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
         state
     }
 
+    /// Returns the components accessed by this query, and the filters on them.
+    ///
+    /// This can be used to check a query built at runtime, such as a
+    /// [`DynamicQuery`](crate::query::DynamicQuery), for conflicts with other queries and systems.
+    #[inline]
+    pub fn component_access(&self) -> &FilteredAccess<ComponentId> {
+        &self.component_access
+    }
+
+    /// Returns the archetype components accessed by this query in the archetypes it matched
+    /// the last time they were updated.
+    #[inline]
+    pub fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        &self.archetype_component_access
+    }
+
     /// Checks if the query is empty for the given [`World`], where the last change and current tick are given.
     #[inline]
     pub fn is_empty(&self, world: &World, last_change_tick: u32, change_tick: u32) -> bool {
@@ -168,6 +204,11 @@ impl<Q: WorldQuery, F: ReadOnlyWorldQuery> QueryState<Q, F> {
 
     /// Creates a new [`Archetype`].
     pub fn new_archetype(&mut self, archetype: &Archetype) {
+        if let Some(disabled) = self.disabled {
+            if archetype.contains(disabled) {
+                return;
+            }
+        }
         if Q::matches_component_set(&self.fetch_state, &|id| archetype.contains(id))
             && F::matches_component_set(&self.filter_state, &|id| archetype.contains(id))
         {
@@ -1280,6 +1321,7 @@ impl fmt::Display for QueryEntityError {
 
 #[cfg(test)]
 mod tests {
+    use crate as bevy_ecs;
     use crate::{prelude::*, query::QueryEntityError};
 
     #[test]
@@ -1385,6 +1427,41 @@ mod tests {
         let mut query_state = world_1.query::<Entity>();
         let _panics = query_state.get_many_mut(&mut world_2, []);
     }
+
+    #[test]
+    fn disabled_entities() {
+        #[derive(Component)]
+        struct A(u32);
+
+        #[derive(Component)]
+        #[component(storage = "SparseSet")]
+        struct S;
+
+        let mut world = World::new();
+        let enabled = world.spawn((A(0), S)).id();
+        let disabled = world.spawn((A(1), S, Disabled)).id();
+
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).map(|a| a.0).collect::<Vec<_>>(), [0]);
+        let mut query = world.query_filtered::<Entity, With<S>>();
+        assert_eq!(query.iter(&world).collect::<Vec<_>>(), [enabled]);
+        assert_eq!(
+            query.get(&world, disabled),
+            Err(QueryEntityError::QueryDoesNotMatch(disabled))
+        );
+
+        let mut query = world.query::<(&A, Option<&Disabled>)>();
+        assert_eq!(query.iter(&world).count(), 2);
+        let mut query = world.query_filtered::<&A, Without<Disabled>>();
+        assert_eq!(query.iter(&world).count(), 1);
+
+        // Enabling an entity again makes it match queries.
+        world.entity_mut(disabled).remove::<Disabled>();
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).count(), 2);
+        world.entity_mut(enabled).insert(Disabled);
+        assert_eq!(query.iter(&world).map(|a| a.0).collect::<Vec<_>>(), [1]);
+    }
 }
 
 /// An error that occurs when evaluating a [`QueryState`] as a single expected resulted via
@@ -9,7 +9,7 @@ use crate::{
 };
 use bevy_reflect::{
     impl_from_reflect_value, impl_reflect_value, FromType, Reflect, ReflectDeserialize,
-    ReflectSerialize,
+    ReflectSerialize, TypePath,
 };
 
 /// A struct used to operate on reflected [`Component`] of a type.
@@ -404,6 +404,16 @@ impl<C: Resource + Reflect + FromWorld> FromType<C> for ReflectResource {
 impl_reflect_value!(Entity(Hash, PartialEq, Serialize, Deserialize));
 impl_from_reflect_value!(Entity);
 
+impl TypePath for Entity {
+    fn type_path() -> &'static str {
+        "bevy_ecs::entity::Entity"
+    }
+
+    fn short_type_path() -> &'static str {
+        "Entity"
+    }
+}
+
 #[derive(Clone)]
 pub struct ReflectMapEntities {
     map_entities: fn(&mut World, &EntityMap) -> Result<(), MapEntitiesError>,
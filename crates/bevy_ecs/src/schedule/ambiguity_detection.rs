@@ -1,5 +1,6 @@
 use bevy_utils::tracing::info;
 use fixedbitset::FixedBitSet;
+use serde::Serialize;
 
 use crate::component::ComponentId;
 use crate::schedule::{AmbiguityDetection, GraphNode, SystemContainer, SystemStage};
@@ -16,9 +17,10 @@ struct SystemOrderAmbiguity {
     conflicts: Vec<String>,
 }
 
-/// Which part of a [`SystemStage`] was a [`SystemOrderAmbiguity`] detected in?
-#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash)]
-enum SystemStageSegment {
+/// A part of a [`SystemStage`], in which systems are ordered relative to each other.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemStageSegment {
     Parallel,
     ExclusiveAtStart,
     ExclusiveBeforeCommands,
@@ -8,8 +8,13 @@ use async_channel::{Receiver, Sender};
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool};
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::Instrument;
+use bevy_utils::{Duration, Instant};
 use event_listener::Event;
 use fixedbitset::FixedBitSet;
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
 
 #[cfg(test)]
 use scheduling_event::*;
@@ -31,6 +36,9 @@ struct SystemSchedulingMetadata {
 }
 
 pub struct ParallelExecutor {
+    /// Whether conflicting systems are ordered by their index, see
+    /// [`set_deterministic`](Self::set_deterministic).
+    deterministic: bool,
     /// Cached metadata of every system.
     system_metadata: Vec<SystemSchedulingMetadata>,
     /// Used by systems to notify the executor that they have finished.
@@ -49,6 +57,9 @@ pub struct ParallelExecutor {
     active_archetype_component_access: Access<ArchetypeComponentId>,
     /// Scratch space to avoid reallocating a vector when updating dependency counters.
     dependants_scratch: Vec<usize>,
+    /// Duration of the last run of every system in nanoseconds, or `u64::MAX` if it did not run.
+    /// `None` unless timings are recorded.
+    system_timings: Option<Arc<[AtomicU64]>>,
     #[cfg(test)]
     events_sender: Option<Sender<SchedulingEvent>>,
 }
@@ -61,6 +72,7 @@ impl Default for ParallelExecutor {
         // all systems will just suspend until the parallel executor runs.
         let (finish_sender, finish_receiver) = async_channel::bounded(128);
         Self {
+            deterministic: false,
             system_metadata: Default::default(),
             finish_sender,
             finish_receiver,
@@ -70,6 +82,7 @@ impl Default for ParallelExecutor {
             should_run: Default::default(),
             active_archetype_component_access: Default::default(),
             dependants_scratch: Default::default(),
+            system_timings: None,
             #[cfg(test)]
             events_sender: None,
         }
@@ -96,12 +109,18 @@ impl ParallelSystemExecutor for ParallelExecutor {
                 archetype_component_access: Default::default(),
             });
         }
+        if self.system_timings.is_some() {
+            self.system_timings = Some(Self::new_timings(systems.len()));
+        }
         // Populate the dependants lists in the scheduling metadata.
         for (dependant, container) in systems.iter().enumerate() {
             for dependency in container.dependencies() {
                 self.system_metadata[*dependency].dependants.push(dependant);
             }
         }
+        if self.deterministic {
+            self.order_conflicting_systems(systems);
+        }
     }
 
     fn run_systems(&mut self, systems: &mut [SystemContainer], world: &mut World) {
@@ -162,6 +181,83 @@ impl ParallelSystemExecutor for ParallelExecutor {
 }
 
 impl ParallelExecutor {
+    /// Creates an executor in deterministic mode, see [`set_deterministic`](Self::set_deterministic).
+    pub fn deterministic() -> Self {
+        Self {
+            deterministic: true,
+            ..Default::default()
+        }
+    }
+
+    /// Sets whether systems run in a deterministic order. This is disabled by default.
+    ///
+    /// By default, the executor starts any system whose dependencies have finished as soon as it
+    /// does not conflict with the running systems, so systems without an explicit order may run
+    /// in a different order every time. In deterministic mode, systems that conflict are instead
+    /// always run in the order of [`SystemStage::parallel_systems`](super::SystemStage::parallel_systems),
+    /// as if each was labeled to run after the previous ones. Two systems conflict if their
+    /// component or resource access is incompatible, or if both have deferred changes such as
+    /// [`Commands`](crate::system::Commands), since those allocate entity ids while running.
+    ///
+    /// Given the same inputs, this guarantees the same order of commands application, events
+    /// and entity ids, while still running non-conflicting systems in parallel.
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+
+    /// Returns `true` if systems run in a deterministic order, see
+    /// [`set_deterministic`](Self::set_deterministic).
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Makes every system depend on the previous systems it conflicts with.
+    fn order_conflicting_systems(&mut self, systems: &[SystemContainer]) {
+        for (dependant, container) in systems.iter().enumerate() {
+            let system = container.system();
+            for (dependency, previous) in systems[..dependant].iter().enumerate() {
+                if container.dependencies().contains(&dependency) {
+                    continue;
+                }
+                let previous = previous.system();
+                let conflicts = (system.has_deferred() && previous.has_deferred())
+                    || !system
+                        .component_access()
+                        .is_compatible(previous.component_access());
+                if conflicts {
+                    self.system_metadata[dependency].dependants.push(dependant);
+                    self.system_metadata[dependant].dependencies_total += 1;
+                }
+            }
+        }
+    }
+
+    /// Sets whether the duration of each system run is recorded, see
+    /// [`system_timing`](Self::system_timing). This is disabled by default.
+    pub fn set_record_timings(&mut self, record_timings: bool) {
+        self.system_timings = record_timings.then(|| Self::new_timings(self.system_metadata.len()));
+    }
+
+    /// Returns how long the parallel system at `index` took during the last run of the stage,
+    /// or `None` if it did not run or timings are not recorded.
+    ///
+    /// See [`SystemStage::parallel_systems`](super::SystemStage::parallel_systems) for the order
+    /// of the systems.
+    pub fn system_timing(&self, index: usize) -> Option<Duration> {
+        let nanos = self
+            .system_timings
+            .as_ref()?
+            .get(index)?
+            .load(Ordering::Relaxed);
+        (nanos != u64::MAX).then(|| Duration::from_nanos(nanos))
+    }
+
+    fn new_timings(system_count: usize) -> Arc<[AtomicU64]> {
+        (0..system_count)
+            .map(|_| AtomicU64::new(u64::MAX))
+            .collect()
+    }
+
     /// Populates `should_run` bitset, spawns tasks for systems that should run this iteration,
     /// queues systems with no dependencies to run (or skip) at next opportunity.
     fn prepare_systems<'scope>(
@@ -180,6 +276,9 @@ impl ParallelExecutor {
             self.system_metadata.iter_mut().zip(systems).enumerate()
         {
             let should_run = system.should_run();
+            if let Some(timings) = &self.system_timings {
+                timings[index].store(u64::MAX, Ordering::Relaxed);
+            }
             let can_start = should_run
                 && system_data.dependencies_total == 0
                 && Self::can_start_now(
@@ -211,11 +310,18 @@ impl ParallelExecutor {
             let overhead_span =
                 bevy_utils::tracing::info_span!("system overhead", name = &*system.name());
 
+            let timings = self.system_timings.clone();
+
             let mut run = move || {
                 #[cfg(feature = "trace")]
                 let _system_guard = system_span.enter();
+                let start = timings.as_ref().map(|_| Instant::now());
                 // SAFETY: the executor prevents two systems with conflicting access from running simultaneously.
                 unsafe { system.run_unsafe((), world) };
+                if let (Some(timings), Some(start)) = (&timings, start) {
+                    let nanos = start.elapsed().as_nanos().min(u64::MAX as u128 - 1) as u64;
+                    timings[index].store(nanos, Ordering::Relaxed);
+                }
             };
 
             if can_start {
@@ -401,10 +507,12 @@ mod tests {
         schedule::{
             executor_parallel::scheduling_event::*, SingleThreadedExecutor, Stage, SystemStage,
         },
-        system::{NonSend, Query, Res, ResMut, Resource},
+        system::{Commands, NonSend, Query, Res, ResMut, Resource},
         world::World,
     };
 
+    use super::ParallelExecutor;
+
     use SchedulingEvent::StartedSystems;
 
     #[derive(Component)]
@@ -561,4 +669,52 @@ mod tests {
         stage.set_executor(Box::<SingleThreadedExecutor>::default());
         stage.run(&mut world);
     }
+
+    #[test]
+    fn deterministic() {
+        #[derive(Resource, Default)]
+        struct Order(Vec<usize>);
+
+        fn stage() -> SystemStage {
+            let mut stage = SystemStage::new(Box::new(ParallelExecutor::deterministic()));
+            for i in 0..8 {
+                stage.add_system(move |mut order: ResMut<Order>| order.0.push(i));
+                stage.add_system(move |mut commands: Commands| {
+                    commands.spawn(W(i));
+                });
+                stage.add_system(|_: Query<&W<u32>>| {});
+            }
+            stage
+        }
+
+        for _ in 0..10 {
+            let mut world = World::new();
+            world.init_resource::<Order>();
+            let mut stage = stage();
+            stage.run(&mut world);
+            receive_events(&world);
+            assert_eq!(world.resource::<Order>().0, (0..8).collect::<Vec<_>>());
+            let mut spawned = world
+                .query::<(bevy_ecs::entity::Entity, &W<usize>)>()
+                .iter(&world)
+                .map(|(entity, w)| (entity.index(), w.0))
+                .collect::<Vec<_>>();
+            spawned.sort();
+            assert_eq!(spawned, (0..8).map(|i| (i as u32, i)).collect::<Vec<_>>());
+        }
+
+        // Non-conflicting systems still run in parallel.
+        let mut world = World::new();
+        let mut stage = SystemStage::new(Box::new(ParallelExecutor::deterministic()))
+            .with_system(|_: Query<&W<u32>>| {})
+            .with_system(|_: Query<&W<u32>>| {})
+            .with_system(|_: Res<Counter>| {})
+            .with_system(|_: ResMut<Counter>| {});
+        world.init_resource::<Counter>();
+        stage.run(&mut world);
+        assert_eq!(
+            receive_events(&world),
+            vec![StartedSystems(3), StartedSystems(1)]
+        );
+    }
 }
@@ -0,0 +1,674 @@
+use std::fmt::Write;
+
+use bevy_utils::Duration;
+use serde::{Serialize, Serializer};
+
+use crate::{
+    schedule::{
+        GraphNode, ParallelExecutor, RunCriteriaContainer, Schedule, SystemContainer, SystemStage,
+        SystemStageSegment,
+    },
+    schedule_v3::{self, NodeId},
+};
+
+/// A description of the stages and systems of a [`Schedule`], which can be exported to
+/// Graphviz DOT with [`to_dot`](Self::to_dot), or serialized with any `serde` format, such as
+/// JSON.
+///
+/// Systems are only sorted, and their dependencies resolved, once their stage has run. Dumping a
+/// schedule that was never run lists its systems in insertion order, without dependencies.
+/// [`schedule_v3::Schedule`]s inserted as stages are described by their [`ScheduleGraphDump`].
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # fn physics() {}
+/// # fn render() {}
+/// #[derive(StageLabel)]
+/// struct Update;
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_stage(
+///     Update,
+///     SystemStage::parallel()
+///         .with_system(physics.label("physics"))
+///         .with_system(render.after("physics")),
+/// );
+/// let mut world = World::new();
+/// schedule.run_once(&mut world);
+///
+/// let dot = schedule.dump_graph().to_dot();
+/// assert!(dot.starts_with("digraph schedule {"));
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleDump {
+    /// The name of the run criteria of the schedule, if any.
+    pub run_criteria: Option<String>,
+    /// The stages, in execution order.
+    pub stages: Vec<StageDump>,
+}
+
+/// A stage in a [`ScheduleDump`].
+#[derive(Debug, Clone, Serialize)]
+pub struct StageDump {
+    pub label: String,
+    #[serde(flatten)]
+    pub contents: StageContents,
+}
+
+/// The contents of a [`StageDump`], depending on the type of the stage.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StageContents {
+    Systems(SystemStageDump),
+    Schedule(ScheduleDump),
+    /// A [`schedule_v3::Schedule`], ordered by its graph of systems and sets.
+    Graph(ScheduleGraphDump),
+    /// A custom [`Stage`](super::Stage), which can not be inspected.
+    Unknown,
+}
+
+/// A description of the systems of a [`SystemStage`].
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemStageDump {
+    /// The name of the run criteria of the stage, if any.
+    pub run_criteria: Option<String>,
+    /// The run criteria shared by the systems of the stage.
+    pub system_run_criteria: Vec<RunCriteriaDump>,
+    /// The systems, grouped by segment in execution order, and sorted within each segment.
+    pub systems: Vec<SystemDump>,
+}
+
+/// A run criteria in a [`SystemStageDump`].
+#[derive(Debug, Clone, Serialize)]
+pub struct RunCriteriaDump {
+    pub name: String,
+    pub label: Option<String>,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+}
+
+/// A system in a [`SystemStageDump`].
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemDump {
+    pub name: String,
+    pub segment: SystemStageSegment,
+    pub labels: Vec<String>,
+    pub before: Vec<String>,
+    pub after: Vec<String>,
+    /// The name or label of the run criteria of the system, if any.
+    pub run_criteria: Option<String>,
+    /// Indices in [`SystemStageDump::systems`] of the systems this one waits for.
+    pub dependencies: Vec<usize>,
+    /// How long the system took during the last run of the stage, if it ran and its
+    /// [`ParallelExecutor`] records timings. Serialized in microseconds.
+    #[serde(rename = "last_run_us", serialize_with = "serialize_micros")]
+    pub last_run: Option<Duration>,
+}
+
+/// A description of the systems and sets of a [`schedule_v3::Schedule`].
+///
+/// Like stages, schedules are only built once they have run or been initialized: systems added
+/// since then are not listed.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleGraphDump {
+    /// The sets, indexed like [`NodeId::Set`].
+    pub sets: Vec<SetDump>,
+    /// The systems, in the order they were added.
+    pub systems: Vec<GraphSystemDump>,
+    /// The indices of the systems in the order they run with a single thread.
+    pub order: Vec<usize>,
+}
+
+/// A set in a [`ScheduleGraphDump`].
+#[derive(Debug, Clone, Serialize)]
+pub struct SetDump {
+    pub name: String,
+    /// Indices in [`ScheduleGraphDump::sets`] of the sets this one is directly in.
+    pub sets: Vec<usize>,
+}
+
+/// A system in a [`ScheduleGraphDump`].
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphSystemDump {
+    pub name: String,
+    /// Indices in [`ScheduleGraphDump::sets`] of the sets this system is directly in.
+    pub sets: Vec<usize>,
+    /// Indices in [`ScheduleGraphDump::systems`] of the systems this one waits for.
+    pub dependencies: Vec<usize>,
+}
+
+fn serialize_micros<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    duration
+        .map(|duration| duration.as_secs_f64() * 1_000_000.0)
+        .serialize(serializer)
+}
+
+fn label_names<L: std::fmt::Debug>(labels: &[L]) -> Vec<String> {
+    labels.iter().map(|label| format!("{label:?}")).collect()
+}
+
+impl Schedule {
+    /// Returns a description of the stages and systems of this schedule, see [`ScheduleDump`].
+    pub fn dump_graph(&self) -> ScheduleDump {
+        ScheduleDump {
+            run_criteria: self.run_criteria.name().map(|name| name.into_owned()),
+            stages: self
+                .iter_stages()
+                .map(|(label, stage)| StageDump {
+                    label: format!("{label:?}"),
+                    contents: if let Some(stage) = stage.downcast_ref::<SystemStage>() {
+                        StageContents::Systems(stage.dump_graph())
+                    } else if let Some(schedule) = stage.downcast_ref::<Schedule>() {
+                        StageContents::Schedule(schedule.dump_graph())
+                    } else if let Some(schedule) = stage.downcast_ref::<schedule_v3::Schedule>() {
+                        StageContents::Graph(schedule.dump_graph())
+                    } else {
+                        StageContents::Unknown
+                    },
+                })
+                .collect(),
+        }
+    }
+}
+
+impl SystemStage {
+    /// Returns a description of the systems of this stage, see [`ScheduleDump`].
+    ///
+    /// If the stage uses a [`ParallelExecutor`] recording timings, the duration of the last run
+    /// of its parallel systems is included.
+    pub fn dump_graph(&self) -> SystemStageDump {
+        let executor = self.get_executor::<ParallelExecutor>();
+        let segments = [
+            (
+                SystemStageSegment::ExclusiveAtStart,
+                &self.exclusive_at_start,
+            ),
+            (SystemStageSegment::Parallel, &self.parallel),
+            (
+                SystemStageSegment::ExclusiveBeforeCommands,
+                &self.exclusive_before_commands,
+            ),
+            (SystemStageSegment::ExclusiveAtEnd, &self.exclusive_at_end),
+        ];
+
+        let mut systems = Vec::new();
+        for (segment, containers) in segments {
+            // Dependencies are indices in the segment.
+            let offset = systems.len();
+            for (index, container) in containers.iter().enumerate() {
+                let last_run = match (segment, executor) {
+                    (SystemStageSegment::Parallel, Some(executor)) => executor.system_timing(index),
+                    _ => None,
+                };
+                systems.push(self.dump_system(container, segment, offset, last_run));
+            }
+        }
+
+        SystemStageDump {
+            run_criteria: self.stage_run_criteria.name().map(|name| name.into_owned()),
+            system_run_criteria: self.run_criteria.iter().map(dump_run_criteria).collect(),
+            systems,
+        }
+    }
+
+    fn dump_system(
+        &self,
+        container: &SystemContainer,
+        segment: SystemStageSegment,
+        offset: usize,
+        last_run: Option<Duration>,
+    ) -> SystemDump {
+        let run_criteria = match (container.run_criteria_label(), container.run_criteria()) {
+            (Some(label), _) => Some(format!("{label:?}")),
+            (None, Some(index)) => self
+                .run_criteria
+                .get(index)
+                .map(|criteria| criteria.name().into_owned()),
+            (None, None) => None,
+        };
+        SystemDump {
+            name: container.name().into_owned(),
+            segment,
+            labels: label_names(container.labels()),
+            before: label_names(GraphNode::before(container)),
+            after: label_names(GraphNode::after(container)),
+            run_criteria,
+            dependencies: container
+                .dependencies()
+                .iter()
+                .map(|dependency| offset + dependency)
+                .collect(),
+            last_run,
+        }
+    }
+}
+
+impl schedule_v3::Schedule {
+    /// Returns a description of the systems and sets of this schedule, see [`ScheduleGraphDump`].
+    pub fn dump_graph(&self) -> ScheduleGraphDump {
+        let graph = self.graph();
+        let executable = self.executable();
+        let set_index = |node: NodeId| match node {
+            NodeId::Set(index) => index,
+            NodeId::System(_) => unreachable!("systems can not contain nodes"),
+        };
+
+        let mut systems: Vec<_> = executable
+            .systems()
+            .iter()
+            .enumerate()
+            .map(|(index, system)| GraphSystemDump {
+                name: system.name().into_owned(),
+                sets: graph
+                    .parents(NodeId::System(index))
+                    .map(set_index)
+                    .collect(),
+                dependencies: Vec::new(),
+            })
+            .collect();
+        for &(before, after) in graph.dependencies() {
+            if let Some(system) = systems.get_mut(after) {
+                system.dependencies.push(before);
+            }
+        }
+
+        ScheduleGraphDump {
+            sets: graph
+                .sets()
+                .iter()
+                .enumerate()
+                .map(|(index, set)| SetDump {
+                    name: format!("{set:?}"),
+                    sets: graph.parents(NodeId::Set(index)).map(set_index).collect(),
+                })
+                .collect(),
+            systems,
+            order: executable.order().to_vec(),
+        }
+    }
+}
+
+fn dump_run_criteria(criteria: &RunCriteriaContainer) -> RunCriteriaDump {
+    RunCriteriaDump {
+        name: criteria.name().into_owned(),
+        label: criteria.label.map(|label| format!("{label:?}")),
+        before: label_names(&criteria.before),
+        after: label_names(&criteria.after),
+    }
+}
+
+impl ScheduleDump {
+    /// Exports this description to the Graphviz DOT format.
+    ///
+    /// Every stage is a cluster of nodes, grouped by segment, with an edge from each system to
+    /// the systems waiting for it. Stages are connected by dashed edges in execution order. If
+    /// timings were recorded, systems are shaded from white to red according to their duration
+    /// relative to the slowest system.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let slowest = self.slowest_system();
+        writeln!(dot, "digraph schedule {{").unwrap();
+        writeln!(dot, "    compound=true;").unwrap();
+        writeln!(dot, "    node [shape=box, style=filled, fillcolor=white];").unwrap();
+        if let Some(run_criteria) = &self.run_criteria {
+            writeln!(
+                dot,
+                "    label={};",
+                quote(&format!("run criteria: {run_criteria}"))
+            )
+            .unwrap();
+        }
+        self.write_dot(&mut dot, "s", 1, slowest);
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+
+    fn slowest_system(&self) -> Option<Duration> {
+        let mut slowest: Option<Duration> = None;
+        for stage in &self.stages {
+            let timing = match &stage.contents {
+                StageContents::Systems(stage) => stage
+                    .systems
+                    .iter()
+                    .filter_map(|system| system.last_run)
+                    .max(),
+                StageContents::Schedule(schedule) => schedule.slowest_system(),
+                StageContents::Graph(_) | StageContents::Unknown => None,
+            };
+            slowest = slowest.max(timing);
+        }
+        slowest
+    }
+
+    /// Writes the stages of this schedule, returning the ids of the anchor nodes of its first and
+    /// last stages, used to connect nested schedules to their neighbours.
+    fn write_dot(
+        &self,
+        dot: &mut String,
+        prefix: &str,
+        depth: usize,
+        slowest: Option<Duration>,
+    ) -> Option<(String, String)> {
+        let indent = "    ".repeat(depth);
+        let mut anchors: Vec<(String, String)> = Vec::new();
+        for (stage_index, stage) in self.stages.iter().enumerate() {
+            let id = format!("{prefix}{stage_index}");
+            writeln!(dot, "{indent}subgraph cluster_{id} {{").unwrap();
+            let mut title = format!("stage: {}", stage.label);
+            let anchor = match &stage.contents {
+                StageContents::Systems(stage) => {
+                    if let Some(run_criteria) = &stage.run_criteria {
+                        write!(title, "\nrun criteria: {run_criteria}").unwrap();
+                    }
+                    writeln!(dot, "{indent}    label={};", quote(&title)).unwrap();
+                    stage.write_dot(dot, &id, depth + 1, slowest);
+                    Some((format!("{id}_anchor"), format!("{id}_anchor")))
+                }
+                StageContents::Schedule(schedule) => {
+                    if let Some(run_criteria) = &schedule.run_criteria {
+                        write!(title, "\nrun criteria: {run_criteria}").unwrap();
+                    }
+                    writeln!(dot, "{indent}    label={};", quote(&title)).unwrap();
+                    schedule.write_dot(dot, &format!("{id}_"), depth + 1, slowest)
+                }
+                StageContents::Graph(graph) => {
+                    writeln!(dot, "{indent}    label={};", quote(&title)).unwrap();
+                    graph.write_dot(dot, &id, depth + 1);
+                    Some((format!("{id}_anchor"), format!("{id}_anchor")))
+                }
+                StageContents::Unknown => {
+                    writeln!(dot, "{indent}    label={};", quote(&title)).unwrap();
+                    writeln!(dot, "{indent}    {id}_anchor [shape=point, style=invis];").unwrap();
+                    Some((format!("{id}_anchor"), format!("{id}_anchor")))
+                }
+            };
+            writeln!(dot, "{indent}}}").unwrap();
+            anchors.extend(anchor);
+        }
+
+        for pair in anchors.windows(2) {
+            writeln!(
+                dot,
+                "{indent}{} -> {} [style=dashed, ltail=cluster_{}, lhead=cluster_{}];",
+                pair[0].1,
+                pair[1].0,
+                pair[0].1.trim_end_matches("_anchor"),
+                pair[1].0.trim_end_matches("_anchor"),
+            )
+            .unwrap();
+        }
+
+        Some((anchors.first()?.0.clone(), anchors.last()?.1.clone()))
+    }
+}
+
+impl SystemStageDump {
+    fn write_dot(&self, dot: &mut String, id: &str, depth: usize, slowest: Option<Duration>) {
+        let indent = "    ".repeat(depth);
+        writeln!(dot, "{indent}{id}_anchor [shape=point, style=invis];").unwrap();
+        let mut segment = None;
+        for (index, system) in self.systems.iter().enumerate() {
+            if segment != Some(system.segment) {
+                if segment.is_some() {
+                    writeln!(dot, "{indent}}}").unwrap();
+                }
+                segment = Some(system.segment);
+                writeln!(dot, "{indent}subgraph cluster_{id}_{:?} {{", system.segment).unwrap();
+                writeln!(dot, "{indent}    label={};", quote(system.segment.desc())).unwrap();
+            }
+            writeln!(
+                dot,
+                "{indent}    {id}_{index} [label={}{}];",
+                quote(&system.dot_label()),
+                system.dot_fill(slowest)
+            )
+            .unwrap();
+        }
+        if segment.is_some() {
+            writeln!(dot, "{indent}}}").unwrap();
+        }
+
+        for (index, system) in self.systems.iter().enumerate() {
+            for dependency in &system.dependencies {
+                writeln!(dot, "{indent}{id}_{dependency} -> {id}_{index};").unwrap();
+            }
+        }
+    }
+}
+
+impl ScheduleGraphDump {
+    fn write_dot(&self, dot: &mut String, id: &str, depth: usize) {
+        let indent = "    ".repeat(depth);
+        writeln!(dot, "{indent}{id}_anchor [shape=point, style=invis];").unwrap();
+        for &index in &self.order {
+            let system = &self.systems[index];
+            let mut label = system.name.clone();
+            if !system.sets.is_empty() {
+                let sets: Vec<_> = system
+                    .sets
+                    .iter()
+                    .map(|&set| self.sets[set].name.as_str())
+                    .collect();
+                write!(label, "\nsets: {}", sets.join(", ")).unwrap();
+            }
+            writeln!(dot, "{indent}{id}_{index} [label={}];", quote(&label)).unwrap();
+        }
+        for (index, system) in self.systems.iter().enumerate() {
+            for dependency in &system.dependencies {
+                writeln!(dot, "{indent}{id}_{dependency} -> {id}_{index};").unwrap();
+            }
+        }
+    }
+}
+
+impl SystemDump {
+    fn dot_label(&self) -> String {
+        let mut label = self.name.clone();
+        if !self.labels.is_empty() {
+            write!(label, "\nlabels: {}", self.labels.join(", ")).unwrap();
+        }
+        if !self.before.is_empty() {
+            write!(label, "\nbefore: {}", self.before.join(", ")).unwrap();
+        }
+        if !self.after.is_empty() {
+            write!(label, "\nafter: {}", self.after.join(", ")).unwrap();
+        }
+        if let Some(run_criteria) = &self.run_criteria {
+            write!(label, "\nrun criteria: {run_criteria}").unwrap();
+        }
+        if let Some(last_run) = self.last_run {
+            write!(label, "\n{:.3} ms", last_run.as_secs_f64() * 1000.0).unwrap();
+        }
+        label
+    }
+
+    fn dot_fill(&self, slowest: Option<Duration>) -> String {
+        match (self.last_run, slowest) {
+            (Some(last_run), Some(slowest)) if !slowest.is_zero() => {
+                let saturation = last_run.as_secs_f64() / slowest.as_secs_f64();
+                format!(", fillcolor=\"0.000 {saturation:.3} 1.000\"")
+            }
+            _ => String::new(),
+        }
+    }
+}
+
+/// Quotes and escapes `text` as a DOT string.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        schedule::{
+            IntoSystemDescriptor, ParallelExecutor, Schedule, ShouldRun, StageContents, StageLabel,
+            SystemStage, SystemStageSegment,
+        },
+        schedule_v3,
+        world::World,
+    };
+
+    #[derive(StageLabel)]
+    enum TestStage {
+        First,
+        Second,
+    }
+
+    fn a() {}
+    fn b() {}
+    fn c(_world: &mut World) {}
+
+    fn schedule() -> Schedule {
+        let mut schedule = Schedule::default();
+        schedule
+            .add_stage(
+                TestStage::First,
+                SystemStage::parallel()
+                    .with_system(b.after("a"))
+                    .with_system(a.label("a"))
+                    .with_system(c.at_start()),
+            )
+            .add_stage(
+                TestStage::Second,
+                Schedule::default().with_stage("nested", SystemStage::single(a)),
+            );
+        schedule
+    }
+
+    #[test]
+    fn dump_resolves_dependencies() {
+        let mut schedule = schedule();
+        schedule.run_once(&mut World::new());
+        let dump = schedule.dump_graph();
+
+        assert_eq!(dump.stages.len(), 2);
+        let stage = match &dump.stages[0].contents {
+            StageContents::Systems(stage) => stage,
+            contents => panic!("unexpected stage: {contents:?}"),
+        };
+        let names: Vec<_> = stage
+            .systems
+            .iter()
+            .map(|system| (system.name.rsplit("::").next().unwrap(), system.segment))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("c", SystemStageSegment::ExclusiveAtStart),
+                ("a", SystemStageSegment::Parallel),
+                ("b", SystemStageSegment::Parallel),
+            ]
+        );
+        // Systems are also labelled by their own type.
+        assert!(stage.systems[1].labels.contains(&"a".to_string()));
+        assert_eq!(stage.systems[2].dependencies, vec![1]);
+        assert!(matches!(
+            &dump.stages[1].contents,
+            StageContents::Schedule(nested) if nested.stages.len() == 1
+        ));
+    }
+
+    #[test]
+    fn export_formats() {
+        let mut schedule = schedule();
+        schedule.run_once(&mut World::new());
+        let dump = schedule.dump_graph();
+
+        let dot = dump.to_dot();
+        assert!(dot.contains("subgraph cluster_s0 {"));
+        assert!(dot.contains("s0_1 -> s0_2;"));
+        assert!(dot.contains("subgraph cluster_s1_0 {"));
+        assert!(dot.contains("s0_anchor -> s1_0_anchor"));
+
+        let json = serde_json::to_string(&dump).unwrap();
+        assert!(json.starts_with(r#"{"run_criteria":null,"stages":[{"label":"#));
+        assert!(json.contains(r#""kind":"systems","run_criteria":null,"system_run_criteria":[]"#));
+        assert!(json.contains(r#""kind":"schedule","run_criteria":null,"stages":["#));
+        assert!(json.contains(r#""segment":"exclusive_at_start""#));
+        assert!(json.contains(
+            r#""after":["a"],"run_criteria":null,"dependencies":[1],"last_run_us":null}"#
+        ));
+    }
+
+    #[test]
+    fn graph_schedules() {
+        use schedule_v3::{IntoSystemConfig, IntoSystemSetConfig, SystemSet};
+
+        #[derive(SystemSet)]
+        struct Set;
+
+        let mut graph = schedule_v3::Schedule::new();
+        graph
+            .configure_set(Set.before(a))
+            .add_system(a)
+            .add_system(b.in_set(Set));
+        let mut schedule = Schedule::default();
+        schedule.add_stage(TestStage::First, graph);
+        schedule.run_once(&mut World::new());
+
+        let dump = schedule.dump_graph();
+        let graph = match &dump.stages[0].contents {
+            StageContents::Graph(graph) => graph,
+            contents => panic!("unexpected stage: {contents:?}"),
+        };
+        assert_eq!(graph.order, vec![1, 0]);
+        assert_eq!(graph.systems[0].dependencies, vec![1]);
+        assert!(graph.systems[1]
+            .sets
+            .iter()
+            .any(|&set| graph.sets[set].name == "Set"));
+
+        assert!(dump.to_dot().contains("s0_1 -> s0_0;"));
+        assert!(serde_json::to_string(&dump)
+            .unwrap()
+            .contains(r#""kind":"graph","sets":[{"name":"#));
+    }
+
+    #[test]
+    fn timings_are_recorded() {
+        let mut executor = ParallelExecutor::default();
+        executor.set_record_timings(true);
+        let mut stage = SystemStage::new(Box::new(executor));
+        stage
+            .add_system(a)
+            .add_system(b.with_run_criteria(|| ShouldRun::No));
+        let mut schedule = Schedule::default();
+        schedule.add_stage(TestStage::First, stage);
+        schedule.run_once(&mut World::new());
+
+        let dump = schedule.dump_graph();
+        let stage = match &dump.stages[0].contents {
+            StageContents::Systems(stage) => stage,
+            contents => panic!("unexpected stage: {contents:?}"),
+        };
+        let last_run = |name: &str| {
+            stage
+                .systems
+                .iter()
+                .find(|system| system.name.ends_with(name))
+                .unwrap()
+                .last_run
+        };
+        assert!(last_run("::a").is_some());
+        // Skipped systems have no timing.
+        assert!(last_run("::b").is_none());
+        assert!(dump.to_dot().contains(" ms\""));
+    }
+}
@@ -96,7 +96,9 @@ pub fn topological_order<Labels: Clone>(
             return false;
         }
         current.push(*node);
-        for dependency in graph.get(node).unwrap().keys() {
+        let mut dependencies: Vec<_> = graph.get(node).unwrap().keys().collect();
+        dependencies.sort_unstable();
+        for dependency in dependencies {
             if check_if_cycles_and_visit(dependency, graph, sorted, unvisited, current) {
                 return true;
             }
@@ -109,7 +111,10 @@ pub fn topological_order<Labels: Clone>(
     let mut current = Vec::with_capacity(graph.len());
     let mut unvisited = HashSet::with_capacity_and_hasher(graph.len(), Default::default());
     unvisited.extend(graph.keys().cloned());
-    while let Some(node) = unvisited.iter().next().cloned() {
+    // Nodes are visited in ascending order, so that the result does not depend on hashing.
+    let mut nodes: Vec<_> = graph.keys().cloned().collect();
+    nodes.sort_unstable();
+    for node in nodes {
         if check_if_cycles_and_visit(&node, graph, &mut sorted, &mut unvisited, &mut current) {
             let mut cycle = Vec::new();
             let last_window = [*current.last().unwrap(), current[0]];
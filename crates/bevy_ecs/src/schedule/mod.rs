@@ -6,17 +6,21 @@
 mod ambiguity_detection;
 mod executor;
 mod executor_parallel;
+mod graph_export;
 pub mod graph_utils;
 mod label;
 mod run_criteria;
 mod stage;
 mod state;
+mod stepping;
 mod system_container;
 mod system_descriptor;
 mod system_set;
 
+pub use ambiguity_detection::SystemStageSegment;
 pub use executor::*;
 pub use executor_parallel::*;
+pub use graph_export::*;
 pub use graph_utils::GraphNode;
 pub use label::*;
 pub use run_criteria::*;
@@ -10,6 +10,7 @@ use crate::{
         RunCriteriaDescriptorOrLabel, RunCriteriaInner, RunCriteriaLabelId, ShouldRun,
         SingleThreadedExecutor, SystemContainer, SystemDescriptor, SystemLabelId, SystemSet,
     },
+    schedule_v3::StepPosition,
     world::{World, WorldId},
 };
 use bevy_ecs_macros::Resource;
@@ -70,9 +71,9 @@ pub struct SystemStage {
     /// Instance of a scheduling algorithm for running the systems.
     executor: Box<dyn ParallelSystemExecutor>,
     /// Determines whether the stage should run.
-    stage_run_criteria: BoxedRunCriteria,
+    pub(super) stage_run_criteria: BoxedRunCriteria,
     /// Topologically sorted run criteria of systems.
-    run_criteria: Vec<RunCriteriaContainer>,
+    pub(super) run_criteria: Vec<RunCriteriaContainer>,
     /// Topologically sorted exclusive systems that want to be run at the start of the stage.
     pub(super) exclusive_at_start: Vec<SystemContainer>,
     /// Topologically sorted exclusive systems that want to be run after parallel systems but
@@ -99,8 +100,10 @@ pub struct SystemStage {
     /// Saves the value of the World change_tick during the last tick check
     last_tick_check: u32,
     /// If true, buffers will be automatically applied at the end of the stage. If false, buffers must be manually applied.
-    apply_buffers: bool,
+    pub(super) apply_buffers: bool,
     must_read_resource: Option<ComponentId>,
+    /// The position of the stage in its frame, if it can be stepped.
+    pub(super) stepping: Option<StepPosition>,
 }
 
 impl SystemStage {
@@ -124,6 +127,7 @@ impl SystemStage {
             last_tick_check: Default::default(),
             apply_buffers: true,
             must_read_resource: None,
+            stepping: None,
         }
     }
 
@@ -517,7 +521,7 @@ impl SystemStage {
     ///
     /// During each scan, any change ticks older than [`MAX_CHANGE_AGE`](crate::change_detection::MAX_CHANGE_AGE)
     /// are clamped to that age. This prevents false positives from appearing due to overflow.
-    fn check_change_ticks(&mut self, world: &mut World) {
+    pub(super) fn check_change_ticks(&mut self, world: &mut World) {
         let change_tick = world.change_tick();
         let ticks_since_last_check = change_tick.wrapping_sub(self.last_tick_check);
 
@@ -690,6 +694,45 @@ fn process_systems(
     Ok(())
 }
 
+impl SystemStage {
+    /// Evaluates the run criteria of the systems.
+    pub(super) fn evaluate_run_criteria(&mut self, world: &mut World) {
+        for index in 0..self.run_criteria.len() {
+            let (run_criteria, tail) = self.run_criteria.split_at_mut(index);
+            let criteria = &mut tail[0];
+
+            #[cfg(feature = "trace")]
+            let _span =
+                bevy_utils::tracing::info_span!("run criteria", name = &*criteria.name()).entered();
+
+            match &mut criteria.inner {
+                RunCriteriaInner::Single(system) => criteria.should_run = system.run((), world),
+                RunCriteriaInner::Piped {
+                    input: parent,
+                    system,
+                    ..
+                } => criteria.should_run = system.run(run_criteria[*parent].should_run, world),
+            }
+        }
+    }
+}
+
+/// Returns `true` if the run criteria of `container` allow it to run, using `default` if it has
+/// none.
+pub(super) fn should_run(
+    container: &SystemContainer,
+    run_criteria: &[RunCriteriaContainer],
+    default: ShouldRun,
+) -> bool {
+    matches!(
+        container
+            .run_criteria()
+            .map(|index| run_criteria[index].should_run)
+            .unwrap_or(default),
+        ShouldRun::Yes | ShouldRun::YesAndCheckAgain
+    )
+}
+
 impl Stage for SystemStage {
     fn run(&mut self, world: &mut World) {
         if let Some(world_id) = self.world_id {
@@ -705,6 +748,10 @@ impl Stage for SystemStage {
             self.initialize_systems(world);
             self.rebuild_orders_and_dependencies();
             self.systems_modified = false;
+            // The systems moved, so a stepping stage starts its frame again.
+            if let Some(position) = &mut self.stepping {
+                position.next = 0;
+            }
             self.executor.rebuild_cached_data(&self.parallel);
             self.executor_modified = false;
             if world.contains_resource::<ReportExecutionOrderAmbiguities>() {
@@ -718,10 +765,13 @@ impl Stage for SystemStage {
             self.executor_modified = false;
         }
 
+        if self.stepping.is_some() && self.run_stepping(world) {
+            return;
+        }
+
         let mut run_stage_loop = true;
         while run_stage_loop {
-            let should_run = self.stage_run_criteria.should_run(world);
-            match should_run {
+            match self.stage_run_criteria.should_run(world) {
                 ShouldRun::No => return,
                 ShouldRun::NoAndCheckAgain => continue,
                 ShouldRun::YesAndCheckAgain => (),
@@ -730,45 +780,13 @@ impl Stage for SystemStage {
                 }
             };
 
-            // Evaluate system run criteria.
-            for index in 0..self.run_criteria.len() {
-                let (run_criteria, tail) = self.run_criteria.split_at_mut(index);
-                let mut criteria = &mut tail[0];
-
-                #[cfg(feature = "trace")]
-                let _span =
-                    bevy_utils::tracing::info_span!("run criteria", name = &*criteria.name())
-                        .entered();
-
-                match &mut criteria.inner {
-                    RunCriteriaInner::Single(system) => criteria.should_run = system.run((), world),
-                    RunCriteriaInner::Piped {
-                        input: parent,
-                        system,
-                        ..
-                    } => criteria.should_run = system.run(run_criteria[*parent].should_run, world),
-                }
-            }
+            self.evaluate_run_criteria(world);
 
             let mut run_system_loop = true;
             let mut default_should_run = ShouldRun::Yes;
             while run_system_loop {
                 run_system_loop = false;
 
-                fn should_run(
-                    container: &SystemContainer,
-                    run_criteria: &[RunCriteriaContainer],
-                    default: ShouldRun,
-                ) -> bool {
-                    matches!(
-                        container
-                            .run_criteria()
-                            .map(|index| run_criteria[index].should_run)
-                            .unwrap_or(default),
-                        ShouldRun::Yes | ShouldRun::YesAndCheckAgain
-                    )
-                }
-
                 // Run systems that want to be at the start of stage.
                 for container in &mut self.exclusive_at_start {
                     if should_run(container, &self.run_criteria, default_should_run) {
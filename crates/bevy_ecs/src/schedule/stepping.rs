@@ -0,0 +1,331 @@
+use crate::{
+    schedule::{should_run, ShouldRun, SystemContainer, SystemStage},
+    schedule_v3::{Budget, StepPosition, Stepping},
+    world::World,
+};
+
+impl SystemStage {
+    /// Sets whether the stage can be paused between its systems and stepped through with the
+    /// [`Stepping`] resource, like a schedule using a
+    /// [`SteppingExecutor`](crate::schedule_v3::SteppingExecutor).
+    ///
+    /// While stepping, the systems of the stage run one at a time on the current thread, in the
+    /// order the stage would run them, and run criteria are evaluated once per frame of the stage,
+    /// even if they ask to be checked again.
+    pub fn set_steppable(&mut self, steppable: bool) -> &mut Self {
+        self.stepping = steppable.then(StepPosition::default);
+        self
+    }
+
+    /// Returns `true` if the stage can be stepped through with the [`Stepping`] resource.
+    pub fn is_steppable(&self) -> bool {
+        self.stepping.is_some()
+    }
+
+    /// Runs the stage as requested by the [`Stepping`] resource, returning `false` if it should
+    /// run normally instead.
+    pub(super) fn run_stepping(&mut self, world: &mut World) -> bool {
+        let mut position = self.stepping.take().unwrap_or_default();
+        let stepped = self.step(&mut position, world);
+        self.stepping = Some(position);
+        stepped
+    }
+
+    /// The systems of the stage are stepped through in the order
+    /// `exclusive_at_start`, `parallel`, `exclusive_before_commands`, `exclusive_at_end`, with
+    /// one more step applying the buffers of the parallel systems before `exclusive_at_end`.
+    fn step(&mut self, position: &mut StepPosition, world: &mut World) -> bool {
+        let apply_parallel_buffers = self.exclusive_at_start.len()
+            + self.parallel.len()
+            + self.exclusive_before_commands.len();
+        let len = apply_parallel_buffers + 1 + self.exclusive_at_end.len();
+
+        let enabled =
+            matches!(world.get_resource::<Stepping>(), Some(stepping) if stepping.is_enabled());
+        if !enabled && (position.next == 0 || position.next >= len) {
+            // Stepping was disabled and the current frame is over.
+            position.next = 0;
+            return false;
+        }
+
+        let budget = position.budget(len, world);
+        let mut runs = true;
+        if position.next == 0 && budget != Budget::Nothing {
+            runs = self.begin_stepping_frame(world);
+            if !runs {
+                position.next = len;
+            }
+        }
+
+        if runs && budget != Budget::Nothing {
+            while position.next < len {
+                let step = position.next;
+                position.next += 1;
+                if step == apply_parallel_buffers {
+                    self.apply_parallel_buffers(world);
+                    continue;
+                }
+
+                let (container, exclusive) = self.step_container(step);
+                if !container.should_run {
+                    continue;
+                }
+                {
+                    #[cfg(feature = "trace")]
+                    let _system_span =
+                        bevy_utils::tracing::info_span!("system", name = &*container.name())
+                            .entered();
+                    container.system_mut().run((), world);
+                }
+                if exclusive {
+                    #[cfg(feature = "trace")]
+                    let _system_span = bevy_utils::tracing::info_span!(
+                        "system_commands",
+                        name = &*container.name()
+                    )
+                    .entered();
+                    container.system_mut().apply_buffers(world);
+                }
+
+                if budget == Budget::OneSystem {
+                    break;
+                }
+            }
+
+            // Applying buffers is not a system, so it never waits for a step of its own.
+            if position.next == apply_parallel_buffers {
+                self.apply_parallel_buffers(world);
+                position.next += 1;
+            }
+            if position.next >= len {
+                self.check_change_ticks(world);
+            }
+        }
+
+        let mut next = position.next;
+        if next == apply_parallel_buffers {
+            next += 1;
+        }
+        let next_system = (next < len).then(|| self.step_container(next).0.name());
+        position.update_cursor(len, next_system, world);
+        true
+    }
+
+    /// Evaluates the run criteria of the stage and its systems at the start of a frame, returning
+    /// `false` if the stage does not run.
+    fn begin_stepping_frame(&mut self, world: &mut World) -> bool {
+        loop {
+            match self.stage_run_criteria.should_run(world) {
+                ShouldRun::No => return false,
+                ShouldRun::NoAndCheckAgain => continue,
+                ShouldRun::Yes | ShouldRun::YesAndCheckAgain => break,
+            }
+        }
+
+        self.evaluate_run_criteria(world);
+        for container in self
+            .exclusive_at_start
+            .iter_mut()
+            .chain(&mut self.parallel)
+            .chain(&mut self.exclusive_before_commands)
+            .chain(&mut self.exclusive_at_end)
+        {
+            container.should_run = should_run(container, &self.run_criteria, ShouldRun::Yes);
+        }
+        true
+    }
+
+    fn apply_parallel_buffers(&mut self, world: &mut World) {
+        if !self.apply_buffers {
+            return;
+        }
+        for container in &mut self.parallel {
+            if container.should_run {
+                #[cfg(feature = "trace")]
+                let _span =
+                    bevy_utils::tracing::info_span!("system_commands", name = &*container.name())
+                        .entered();
+                container.system_mut().apply_buffers(world);
+            }
+        }
+    }
+
+    /// Returns the system run at `step`, and whether it is exclusive.
+    fn step_container(&mut self, mut step: usize) -> (&mut SystemContainer, bool) {
+        if step < self.exclusive_at_start.len() {
+            return (&mut self.exclusive_at_start[step], true);
+        }
+        step -= self.exclusive_at_start.len();
+        if step < self.parallel.len() {
+            return (&mut self.parallel[step], false);
+        }
+        step -= self.parallel.len();
+        if step < self.exclusive_before_commands.len() {
+            return (&mut self.exclusive_before_commands[step], true);
+        }
+        step -= self.exclusive_before_commands.len();
+        // Skip the step applying the buffers of the parallel systems.
+        (&mut self.exclusive_at_end[step - 1], true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        schedule::{
+            IntoSystemDescriptor, Schedule, ShouldRun, SingleThreadedExecutor, Stage, StageLabel,
+            SystemLabel, SystemStage,
+        },
+        schedule_v3::Stepping,
+        system::{Commands, ResMut, Resource},
+        world::World,
+    };
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<u32>);
+
+    #[derive(Resource)]
+    struct Spawned;
+
+    #[derive(StageLabel)]
+    enum TestStage {
+        First,
+        Second,
+    }
+
+    #[derive(SystemLabel)]
+    enum TestLabels {
+        First,
+        Second,
+    }
+    use TestLabels::*;
+
+    fn push(value: u32) -> impl FnMut(ResMut<Order>) {
+        move |mut order: ResMut<Order>| order.0.push(value)
+    }
+
+    fn exclusive_push(value: u32) -> impl FnMut(&mut World) {
+        move |world: &mut World| world.resource_mut::<Order>().0.push(value)
+    }
+
+    fn world(enabled: bool) -> World {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let mut stepping = Stepping::default();
+        if enabled {
+            stepping.enable();
+        }
+        world.insert_resource(stepping);
+        world
+    }
+
+    fn steppable_stage(values: [u32; 3]) -> SystemStage {
+        let mut stage = SystemStage::single_threaded();
+        stage
+            .set_steppable(true)
+            .add_system(push(values[2]).after(Second))
+            .add_system(push(values[0]).label(First))
+            .add_system(push(values[1]).label(Second).after(First));
+        stage
+    }
+
+    fn take_order(world: &mut World) -> Vec<u32> {
+        std::mem::take(&mut world.resource_mut::<Order>().0)
+    }
+
+    fn step_system(world: &mut World, stage: &mut impl Stage) -> Vec<u32> {
+        world.resource_mut::<Stepping>().step_system();
+        stage.run(world);
+        take_order(world)
+    }
+
+    #[test]
+    fn step_through_segments() {
+        let mut world = world(true);
+        let mut stage = SystemStage::new(Box::<SingleThreadedExecutor>::default());
+        stage
+            .set_steppable(true)
+            .add_system(exclusive_push(2).at_end())
+            .add_system(exclusive_push(1).before_commands())
+            .add_system(push(0))
+            .add_system(exclusive_push(3).at_start())
+            .add_system(|mut commands: Commands| commands.insert_resource(Spawned));
+
+        stage.run(&mut world);
+        assert!(take_order(&mut world).is_empty());
+
+        assert_eq!(step_system(&mut world, &mut stage), vec![3]);
+        assert_eq!(step_system(&mut world, &mut stage), vec![0]);
+        // Runs the system inserting `Spawned`, whose buffer waits for the parallel systems.
+        assert!(step_system(&mut world, &mut stage).is_empty());
+        assert!(!world.contains_resource::<Spawned>());
+        assert_eq!(step_system(&mut world, &mut stage), vec![1]);
+        assert!(world.contains_resource::<Spawned>());
+        assert_eq!(step_system(&mut world, &mut stage), vec![2]);
+
+        // The next frame starts from the first system again.
+        assert_eq!(step_system(&mut world, &mut stage), vec![3]);
+    }
+
+    #[test]
+    fn step_over_and_into_stages() {
+        let mut world = world(true);
+        let mut schedule = Schedule::default()
+            .with_stage(TestStage::First, steppable_stage([0, 1, 2]))
+            .with_stage(TestStage::Second, steppable_stage([3, 4, 5]));
+
+        // Steps into the first stage.
+        assert_eq!(step_system(&mut world, &mut schedule), vec![0]);
+        // Steps over the rest of the first stage.
+        world.resource_mut::<Stepping>().step_schedule();
+        schedule.run(&mut world);
+        assert_eq!(take_order(&mut world), vec![1, 2]);
+        // Steps into the second stage.
+        assert_eq!(step_system(&mut world, &mut schedule), vec![3]);
+        assert!(world.resource::<Stepping>().next_systems().next().is_some());
+        // Finishes the frame.
+        world.resource_mut::<Stepping>().step_frame();
+        schedule.run(&mut world);
+        assert_eq!(take_order(&mut world), vec![4, 5]);
+        assert_eq!(step_system(&mut world, &mut schedule), vec![0]);
+    }
+
+    #[test]
+    fn runs_normally_without_stepping() {
+        let mut world = world(false);
+        let mut steppable = steppable_stage([0, 1, 2]);
+        steppable.run(&mut world);
+        assert_eq!(take_order(&mut world), vec![0, 1, 2]);
+
+        // Stages that are not steppable ignore the stepping resource.
+        world.resource_mut::<Stepping>().enable();
+        let mut stage = steppable_stage([0, 1, 2]);
+        stage.set_steppable(false);
+        stage.run(&mut world);
+        assert_eq!(take_order(&mut world), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn finishes_frame_after_disabling() {
+        let mut world = world(true);
+        let mut stage = steppable_stage([0, 1, 2]);
+        assert_eq!(step_system(&mut world, &mut stage), vec![0]);
+
+        world.resource_mut::<Stepping>().disable();
+        stage.run(&mut world);
+        assert_eq!(take_order(&mut world), vec![1, 2]);
+        stage.run(&mut world);
+        assert_eq!(take_order(&mut world), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn skips_systems_whose_run_criteria_fail() {
+        let mut world = world(true);
+        let mut stage = steppable_stage([0, 1, 2]);
+        stage.add_system(push(3).with_run_criteria(|| ShouldRun::No));
+        world.resource_mut::<Stepping>().step_frame();
+        stage.run(&mut world);
+        assert_eq!(take_order(&mut world), vec![0, 1, 2]);
+    }
+}
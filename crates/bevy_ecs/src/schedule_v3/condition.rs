@@ -0,0 +1,261 @@
+use std::{borrow::Cow, marker::PhantomData};
+
+use crate::{
+    archetype::ArchetypeComponentId,
+    component::ComponentId,
+    query::Access,
+    system::{BoxedSystem, IntoSystem, System},
+    world::World,
+};
+
+/// A type-erased run condition stored in a [`Schedule`](super::Schedule).
+pub type BoxedCondition = BoxedSystem<(), bool>;
+
+/// A system that determines if one or more scheduled systems should run.
+///
+/// Any system taking no input and returning a `bool` is a condition. Conditions compose with
+/// [`and_then`](Condition::and_then), [`or_else`](Condition::or_else) and
+/// [`not`](common_conditions::not), and multiple conditions on the same system or set must all
+/// be `true` for it to run.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule_v3::{common_conditions::*, Condition, IntoSystemConfig, Schedule};
+/// #[derive(Resource, PartialEq)]
+/// struct Paused(bool);
+///
+/// fn simulate() {}
+///
+/// let mut schedule = Schedule::new();
+/// schedule.add_system(
+///     simulate.run_if(resource_exists::<Paused>().and_then(not(resource_equals(Paused(true))))),
+/// );
+/// ```
+pub trait Condition<Params>: IntoSystem<(), bool, Params> + Sized {
+    /// Returns a condition that is `true` if both this condition and `and` are.
+    ///
+    /// `and` is only evaluated if this condition is `true`.
+    fn and_then<P, C: Condition<P>>(self, and: C) -> AndThen<Self::System, C::System> {
+        CombinatorSystem::new(
+            IntoSystem::into_system(self),
+            IntoSystem::into_system(and),
+            "&&",
+        )
+    }
+
+    /// Returns a condition that is `true` if either this condition or `or` is.
+    ///
+    /// `or` is only evaluated if this condition is `false`.
+    fn or_else<P, C: Condition<P>>(self, or: C) -> OrElse<Self::System, C::System> {
+        CombinatorSystem::new(
+            IntoSystem::into_system(self),
+            IntoSystem::into_system(or),
+            "||",
+        )
+    }
+}
+
+impl<Params, F> Condition<Params> for F where F: IntoSystem<(), bool, Params> {}
+
+/// Combines the outputs of the two conditions of a [`CombinatorSystem`].
+pub trait Combine {
+    /// Combines the results of `a` and `b`, each of which runs the matching condition with
+    /// access to `world`.
+    fn combine<W>(
+        world: &mut W,
+        a: impl FnOnce(&mut W) -> bool,
+        b: impl FnOnce(&mut W) -> bool,
+    ) -> bool;
+}
+
+/// [`Combine`] implementation of [`Condition::and_then`].
+pub struct AndThenMarker;
+
+impl Combine for AndThenMarker {
+    fn combine<W>(
+        world: &mut W,
+        a: impl FnOnce(&mut W) -> bool,
+        b: impl FnOnce(&mut W) -> bool,
+    ) -> bool {
+        a(world) && b(world)
+    }
+}
+
+/// [`Combine`] implementation of [`Condition::or_else`].
+pub struct OrElseMarker;
+
+impl Combine for OrElseMarker {
+    fn combine<W>(
+        world: &mut W,
+        a: impl FnOnce(&mut W) -> bool,
+        b: impl FnOnce(&mut W) -> bool,
+    ) -> bool {
+        a(world) || b(world)
+    }
+}
+
+/// A condition combining two conditions with a short-circuiting `&&`.
+pub type AndThen<A, B> = CombinatorSystem<AndThenMarker, A, B>;
+
+/// A condition combining two conditions with a short-circuiting `||`.
+pub type OrElse<A, B> = CombinatorSystem<OrElseMarker, A, B>;
+
+/// A [`System`] combining the outputs of two conditions, as specified by `Func`.
+pub struct CombinatorSystem<Func, A, B> {
+    a: A,
+    b: B,
+    name: Cow<'static, str>,
+    component_access: Access<ComponentId>,
+    archetype_component_access: Access<ArchetypeComponentId>,
+    marker: PhantomData<fn() -> Func>,
+}
+
+impl<Func, A: System, B: System> CombinatorSystem<Func, A, B> {
+    fn new(a: A, b: B, operator: &str) -> Self {
+        Self {
+            name: Cow::Owned(format!("{} {operator} {}", a.name(), b.name())),
+            a,
+            b,
+            component_access: Default::default(),
+            archetype_component_access: Default::default(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Func, A, B> System for CombinatorSystem<Func, A, B>
+where
+    Func: Combine + 'static,
+    A: System<In = (), Out = bool>,
+    B: System<In = (), Out = bool>,
+{
+    type In = ();
+    type Out = bool;
+
+    fn name(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+
+    fn component_access(&self) -> &Access<ComponentId> {
+        &self.component_access
+    }
+
+    fn archetype_component_access(&self) -> &Access<ArchetypeComponentId> {
+        &self.archetype_component_access
+    }
+
+    fn is_send(&self) -> bool {
+        self.a.is_send() && self.b.is_send()
+    }
+
+    fn is_exclusive(&self) -> bool {
+        self.a.is_exclusive() || self.b.is_exclusive()
+    }
+
+    unsafe fn run_unsafe(&mut self, _input: (), world: &World) -> bool {
+        let (a, b) = (&mut self.a, &mut self.b);
+        Func::combine(
+            &mut &*world,
+            |world| a.run_unsafe((), world),
+            |world| b.run_unsafe((), world),
+        )
+    }
+
+    fn run(&mut self, _input: (), world: &mut World) -> bool {
+        // Each condition may be exclusive, so they are run one after the other with full access.
+        let (a, b) = (&mut self.a, &mut self.b);
+        Func::combine(world, |world| a.run((), world), |world| b.run((), world))
+    }
+
+    fn apply_buffers(&mut self, world: &mut World) {
+        self.a.apply_buffers(world);
+        self.b.apply_buffers(world);
+    }
+
+    fn initialize(&mut self, world: &mut World) {
+        self.a.initialize(world);
+        self.b.initialize(world);
+        self.component_access.extend(self.a.component_access());
+        self.component_access.extend(self.b.component_access());
+    }
+
+    fn update_archetype_component_access(&mut self, world: &World) {
+        self.a.update_archetype_component_access(world);
+        self.b.update_archetype_component_access(world);
+
+        self.archetype_component_access
+            .extend(self.a.archetype_component_access());
+        self.archetype_component_access
+            .extend(self.b.archetype_component_access());
+    }
+
+    fn check_change_tick(&mut self, change_tick: u32) {
+        self.a.check_change_tick(change_tick);
+        self.b.check_change_tick(change_tick);
+    }
+
+    fn get_last_change_tick(&self) -> u32 {
+        self.a.get_last_change_tick()
+    }
+
+    fn set_last_change_tick(&mut self, last_change_tick: u32) {
+        self.a.set_last_change_tick(last_change_tick);
+        self.b.set_last_change_tick(last_change_tick);
+    }
+}
+
+/// Common run conditions.
+pub mod common_conditions {
+    use super::Condition;
+    use crate::{
+        schedule::{State, StateData},
+        system::{In, IntoPipeSystem, Res, Resource, System},
+    };
+
+    /// Returns a condition that is `true` the first time it is evaluated and `false` after.
+    pub fn run_once() -> impl FnMut() -> bool {
+        let mut has_run = false;
+        move || {
+            if has_run {
+                false
+            } else {
+                has_run = true;
+                true
+            }
+        }
+    }
+
+    /// Returns a condition that is `true` if the resource `T` exists.
+    pub fn resource_exists<T: Resource>() -> impl FnMut(Option<Res<T>>) -> bool {
+        move |res: Option<Res<T>>| res.is_some()
+    }
+
+    /// Returns a condition that is `true` if the resource `T` exists and is equal to `value`.
+    pub fn resource_equals<T: Resource + PartialEq>(
+        value: T,
+    ) -> impl FnMut(Option<Res<T>>) -> bool {
+        move |res: Option<Res<T>>| res.map_or(false, |res| *res == value)
+    }
+
+    /// Returns a condition that is `true` if the resource `T` exists and was added or changed
+    /// since the condition was last evaluated.
+    pub fn resource_changed<T: Resource>() -> impl FnMut(Option<Res<T>>) -> bool {
+        move |res: Option<Res<T>>| res.map_or(false, |res| res.is_changed())
+    }
+
+    /// Returns a condition that is `true` if the current [`State`] of type `T` is `state`.
+    pub fn state_equals<T: StateData>(state: T) -> impl FnMut(Option<Res<State<T>>>) -> bool {
+        move |current: Option<Res<State<T>>>| {
+            current.map_or(false, |current| *current.current() == state)
+        }
+    }
+
+    /// Returns a condition that inverts the output of `condition`.
+    pub fn not<Params>(condition: impl Condition<Params>) -> impl System<In = (), Out = bool> {
+        fn invert(In(value): In<bool>) -> bool {
+            !value
+        }
+        condition.pipe(invert)
+    }
+}
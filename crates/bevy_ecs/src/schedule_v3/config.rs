@@ -0,0 +1,389 @@
+use bevy_ecs_macros::all_tuples;
+
+use crate::{
+    schedule_v3::{AsSystemSet, BoxedCondition, Condition, SystemSet, SystemSetId, SystemTypeSet},
+    system::{BoxedSystem, IntoSystem},
+};
+
+/// Whether a node must run before or after the [`SystemSet`] of a [`Dependency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DependencyKind {
+    Before,
+    After,
+}
+
+/// An ordering constraint between a node and every system of a [`SystemSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Dependency {
+    pub(crate) kind: DependencyKind,
+    pub(crate) set: SystemSetId,
+}
+
+/// The sets a system or set is part of, and the ordering constraints it has.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct GraphInfo {
+    pub(crate) sets: Vec<SystemSetId>,
+    pub(crate) dependencies: Vec<Dependency>,
+    /// Sets whose systems are not reported as ambiguous with this node.
+    pub(crate) ambiguous_with: Vec<SystemSetId>,
+}
+
+impl GraphInfo {
+    fn before(&mut self, set: SystemSetId) {
+        self.dependencies.push(Dependency {
+            kind: DependencyKind::Before,
+            set,
+        });
+    }
+
+    fn after(&mut self, set: SystemSetId) {
+        self.dependencies.push(Dependency {
+            kind: DependencyKind::After,
+            set,
+        });
+    }
+}
+
+/// Encapsulates a system and information on when it runs in a [`Schedule`](super::Schedule).
+///
+/// Systems can be part of any number of [`SystemSet`]s, can be ordered relative to sets (including
+/// the [`SystemTypeSet`] of a system function) and can have [run conditions](Condition).
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::schedule_v3::{IntoSystemConfig, Schedule, SystemSet};
+/// # fn physics() {}
+/// # fn movement() {}
+/// #[derive(SystemSet)]
+/// struct Physics;
+///
+/// let mut schedule = Schedule::new();
+/// schedule
+///     .add_system(physics.in_set(Physics))
+///     .add_system(movement.before(Physics));
+/// ```
+pub struct SystemConfig {
+    pub(crate) system: BoxedSystem,
+    pub(crate) graph_info: GraphInfo,
+    pub(crate) conditions: Vec<BoxedCondition>,
+}
+
+impl SystemConfig {
+    fn new(system: BoxedSystem, type_set: Option<SystemSetId>) -> Self {
+        SystemConfig {
+            system,
+            graph_info: GraphInfo {
+                sets: type_set.into_iter().collect(),
+                dependencies: Vec::new(),
+                ambiguous_with: Vec::new(),
+            },
+            conditions: Vec::new(),
+        }
+    }
+}
+
+/// Types that can be converted into a [`SystemConfig`].
+pub trait IntoSystemConfig<Params> {
+    fn into_config(self) -> SystemConfig;
+
+    /// Adds the system to the given set. A system can be in any number of sets.
+    fn in_set(self, set: impl SystemSet) -> SystemConfig;
+
+    /// Specifies that the system should run before all the systems in the given set.
+    fn before<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig;
+
+    /// Specifies that the system should run after all the systems in the given set.
+    fn after<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig;
+
+    /// Runs the system only if `condition` is `true`.
+    ///
+    /// The system is skipped if any of its conditions, or of the conditions of the sets it is
+    /// in, is `false`.
+    fn run_if<P>(self, condition: impl Condition<P>) -> SystemConfig;
+
+    /// Suppresses the ambiguities reported between the system and the systems in the given
+    /// set, see [`Schedule::set_ambiguity_detection`](super::Schedule::set_ambiguity_detection).
+    fn ambiguous_with<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig;
+}
+
+impl IntoSystemConfig<()> for SystemConfig {
+    fn into_config(self) -> SystemConfig {
+        self
+    }
+
+    fn in_set(mut self, set: impl SystemSet) -> SystemConfig {
+        self.graph_info.sets.push(set.as_label());
+        self
+    }
+
+    fn before<Marker>(mut self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.graph_info.before(set.as_system_set());
+        self
+    }
+
+    fn after<Marker>(mut self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.graph_info.after(set.as_system_set());
+        self
+    }
+
+    fn run_if<P>(mut self, condition: impl Condition<P>) -> SystemConfig {
+        self.conditions
+            .push(Box::new(IntoSystem::into_system(condition)));
+        self
+    }
+
+    fn ambiguous_with<Marker>(mut self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.graph_info.ambiguous_with.push(set.as_system_set());
+        self
+    }
+}
+
+impl<S, Params> IntoSystemConfig<Params> for S
+where
+    S: IntoSystem<(), (), Params> + 'static,
+{
+    fn into_config(self) -> SystemConfig {
+        let type_set = SystemTypeSet::<S>::new().as_label();
+        SystemConfig::new(Box::new(IntoSystem::into_system(self)), Some(type_set))
+    }
+
+    fn in_set(self, set: impl SystemSet) -> SystemConfig {
+        self.into_config().in_set(set)
+    }
+
+    fn before<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.into_config().before(set)
+    }
+
+    fn after<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.into_config().after(set)
+    }
+
+    fn run_if<P>(self, condition: impl Condition<P>) -> SystemConfig {
+        self.into_config().run_if(condition)
+    }
+
+    fn ambiguous_with<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.into_config().ambiguous_with(set)
+    }
+}
+
+impl IntoSystemConfig<()> for BoxedSystem<(), ()> {
+    fn into_config(self) -> SystemConfig {
+        SystemConfig::new(self, None)
+    }
+
+    fn in_set(self, set: impl SystemSet) -> SystemConfig {
+        self.into_config().in_set(set)
+    }
+
+    fn before<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.into_config().before(set)
+    }
+
+    fn after<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.into_config().after(set)
+    }
+
+    fn run_if<P>(self, condition: impl Condition<P>) -> SystemConfig {
+        self.into_config().run_if(condition)
+    }
+
+    fn ambiguous_with<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfig {
+        self.into_config().ambiguous_with(set)
+    }
+}
+
+/// A collection of [`SystemConfig`]s, added to a [`Schedule`](super::Schedule) together.
+pub struct SystemConfigs {
+    pub(crate) systems: Vec<SystemConfig>,
+    /// If `true`, the systems run one after the other, in the order they were given.
+    pub(crate) chained: bool,
+}
+
+/// Types that can be converted into a [`SystemConfigs`], such as tuples of systems.
+pub trait IntoSystemConfigs<Params>
+where
+    Self: Sized,
+{
+    fn into_configs(self) -> SystemConfigs;
+
+    /// Adds the systems to the given set.
+    fn in_set(self, set: impl SystemSet) -> SystemConfigs {
+        self.into_configs().in_set(set)
+    }
+
+    /// Specifies that the systems should run before all the systems in the given set.
+    fn before<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfigs {
+        self.into_configs().before(set)
+    }
+
+    /// Specifies that the systems should run after all the systems in the given set.
+    fn after<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfigs {
+        self.into_configs().after(set)
+    }
+
+    /// Suppresses the ambiguities reported between the systems and the systems in the given
+    /// set.
+    fn ambiguous_with<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemConfigs {
+        self.into_configs().ambiguous_with(set)
+    }
+
+    /// Orders the systems to run one after the other, in the order they were given.
+    fn chain(self) -> SystemConfigs {
+        self.into_configs().chain()
+    }
+}
+
+impl IntoSystemConfigs<()> for SystemConfigs {
+    fn into_configs(self) -> SystemConfigs {
+        self
+    }
+
+    fn in_set(mut self, set: impl SystemSet) -> SystemConfigs {
+        let set = set.as_label();
+        for config in &mut self.systems {
+            config.graph_info.sets.push(set);
+        }
+        self
+    }
+
+    fn before<Marker>(mut self, set: impl AsSystemSet<Marker>) -> SystemConfigs {
+        let set = set.as_system_set();
+        for config in &mut self.systems {
+            config.graph_info.before(set);
+        }
+        self
+    }
+
+    fn after<Marker>(mut self, set: impl AsSystemSet<Marker>) -> SystemConfigs {
+        let set = set.as_system_set();
+        for config in &mut self.systems {
+            config.graph_info.after(set);
+        }
+        self
+    }
+
+    fn ambiguous_with<Marker>(mut self, set: impl AsSystemSet<Marker>) -> SystemConfigs {
+        let set = set.as_system_set();
+        for config in &mut self.systems {
+            config.graph_info.ambiguous_with.push(set);
+        }
+        self
+    }
+
+    fn chain(mut self) -> SystemConfigs {
+        self.chained = true;
+        self
+    }
+}
+
+macro_rules! impl_system_collection {
+    ($(($param: ident, $sys: ident)),*) => {
+        impl<$($param, $sys),*> IntoSystemConfigs<($($param,)*)> for ($($sys,)*)
+        where
+            $($sys: IntoSystemConfig<$param>),*
+        {
+            #[allow(non_snake_case)]
+            fn into_configs(self) -> SystemConfigs {
+                let ($($sys,)*) = self;
+                SystemConfigs {
+                    systems: vec![$($sys.into_config(),)*],
+                    chained: false,
+                }
+            }
+        }
+    }
+}
+
+all_tuples!(impl_system_collection, 0, 15, P, S);
+
+/// Encapsulates a [`SystemSet`] and information on when its systems run in a
+/// [`Schedule`](super::Schedule).
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::schedule_v3::{common_conditions::run_once, IntoSystemSetConfig, Schedule, SystemSet};
+/// #[derive(SystemSet)]
+/// struct Startup;
+/// #[derive(SystemSet)]
+/// struct Update;
+///
+/// let mut schedule = Schedule::new();
+/// schedule
+///     .configure_set(Startup.before(Update).run_if(run_once()))
+///     .configure_set(Update);
+/// ```
+pub struct SystemSetConfig {
+    pub(crate) set: SystemSetId,
+    pub(crate) graph_info: GraphInfo,
+    pub(crate) conditions: Vec<BoxedCondition>,
+}
+
+/// Types that can be converted into a [`SystemSetConfig`].
+pub trait IntoSystemSetConfig
+where
+    Self: Sized,
+{
+    fn into_config(self) -> SystemSetConfig;
+
+    /// Adds the set to the given parent set. Sets can be nested to any depth, but not cyclically.
+    fn in_set(self, set: impl SystemSet) -> SystemSetConfig {
+        self.into_config().in_set(set)
+    }
+
+    /// Specifies that the systems of this set should run before all the systems in the given set.
+    fn before<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemSetConfig {
+        self.into_config().before(set)
+    }
+
+    /// Specifies that the systems of this set should run after all the systems in the given set.
+    fn after<Marker>(self, set: impl AsSystemSet<Marker>) -> SystemSetConfig {
+        self.into_config().after(set)
+    }
+
+    /// Runs the systems of this set only if `condition` is `true`.
+    ///
+    /// The conditions of a set are evaluated at most once per run of the schedule, right before
+    /// the first system of the set would run.
+    fn run_if<P>(self, condition: impl Condition<P>) -> SystemSetConfig {
+        self.into_config().run_if(condition)
+    }
+}
+
+impl<S: SystemSet> IntoSystemSetConfig for S {
+    fn into_config(self) -> SystemSetConfig {
+        SystemSetConfig {
+            set: self.as_label(),
+            graph_info: GraphInfo::default(),
+            conditions: Vec::new(),
+        }
+    }
+}
+
+impl IntoSystemSetConfig for SystemSetConfig {
+    fn into_config(self) -> SystemSetConfig {
+        self
+    }
+
+    fn in_set(mut self, set: impl SystemSet) -> SystemSetConfig {
+        self.graph_info.sets.push(set.as_label());
+        self
+    }
+
+    fn before<Marker>(mut self, set: impl AsSystemSet<Marker>) -> SystemSetConfig {
+        self.graph_info.before(set.as_system_set());
+        self
+    }
+
+    fn after<Marker>(mut self, set: impl AsSystemSet<Marker>) -> SystemSetConfig {
+        self.graph_info.after(set.as_system_set());
+        self
+    }
+
+    fn run_if<P>(mut self, condition: impl Condition<P>) -> SystemSetConfig {
+        self.conditions
+            .push(Box::new(IntoSystem::into_system(condition)));
+        self
+    }
+}
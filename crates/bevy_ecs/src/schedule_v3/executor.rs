@@ -0,0 +1,176 @@
+use downcast_rs::{impl_downcast, Downcast};
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    schedule_v3::{BoxedCondition, SystemSchedule},
+    world::World,
+};
+
+/// Runs the systems of a [`SystemSchedule`] in a [`World`].
+pub trait SystemExecutor: Downcast + Send + Sync {
+    /// Called whenever the [`SystemSchedule`] was rebuilt, before it is next run.
+    fn init(&mut self, schedule: &SystemSchedule);
+
+    fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World);
+}
+
+impl std::fmt::Debug for dyn SystemExecutor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "dyn SystemExecutor")
+    }
+}
+
+impl_downcast!(SystemExecutor);
+
+/// A no-op exclusive system applying the command buffers of the systems that ran before it.
+///
+/// When added to a [`Schedule`](super::Schedule), the buffers of every system that ran since
+/// the previous `apply_system_buffers` (or since the start of the schedule) are applied in the
+/// order the systems ran. It can be ordered like any other system, which makes command
+/// application an explicit, movable sync point.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule_v3::{apply_system_buffers, IntoSystemConfigs, Schedule};
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn_empty();
+/// }
+///
+/// fn count(query: Query<Entity>) {
+///     assert_eq!(query.iter().count(), 1);
+/// }
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::new();
+/// schedule.add_systems((spawn, apply_system_buffers, count).chain());
+/// schedule.run(&mut world);
+/// ```
+pub fn apply_system_buffers(_world: &mut World) {}
+
+/// Runs the systems of a [`SystemSchedule`] one at a time, on the current thread, in their
+/// topological order.
+///
+/// Run conditions of sets are evaluated at most once per run, right before the first system of
+/// the set would run.
+#[derive(Debug)]
+pub struct SingleThreadedExecutor {
+    /// Sets whose conditions were already evaluated during this run.
+    evaluated_sets: FixedBitSet,
+    /// Sets whose conditions were all `true` during this run.
+    running_sets: FixedBitSet,
+    /// Systems that ran since buffers were last applied.
+    unapplied_systems: Vec<usize>,
+    /// If `true`, buffers remaining at the end of the schedule are applied.
+    apply_final_buffers: bool,
+}
+
+impl Default for SingleThreadedExecutor {
+    fn default() -> Self {
+        Self {
+            evaluated_sets: FixedBitSet::new(),
+            running_sets: FixedBitSet::new(),
+            unapplied_systems: Vec::new(),
+            apply_final_buffers: true,
+        }
+    }
+}
+
+impl SingleThreadedExecutor {
+    /// Sets whether the buffers of systems that ran after the last [`apply_system_buffers`] are
+    /// applied at the end of each run. This is the default.
+    pub fn set_apply_final_buffers(&mut self, apply_final_buffers: bool) {
+        self.apply_final_buffers = apply_final_buffers;
+    }
+}
+
+impl SystemExecutor for SingleThreadedExecutor {
+    fn init(&mut self, schedule: &SystemSchedule) {
+        let set_count = schedule.set_conditions.len();
+        self.evaluated_sets = FixedBitSet::with_capacity(set_count);
+        self.running_sets = FixedBitSet::with_capacity(set_count);
+        self.unapplied_systems = Vec::with_capacity(schedule.systems.len());
+    }
+
+    fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
+        for order_index in 0..schedule.order.len() {
+            let system_index = schedule.order[order_index];
+            if !should_run(
+                schedule,
+                system_index,
+                &mut self.evaluated_sets,
+                &mut self.running_sets,
+                world,
+            ) {
+                continue;
+            }
+
+            if schedule.apply_buffers[system_index] {
+                apply_buffers(&mut self.unapplied_systems, schedule, world);
+                continue;
+            }
+
+            let system = &mut schedule.systems[system_index];
+            #[cfg(feature = "trace")]
+            let _system_span =
+                bevy_utils::tracing::info_span!("system", name = &*system.name()).entered();
+            system.run((), world);
+            self.unapplied_systems.push(system_index);
+        }
+
+        if self.apply_final_buffers {
+            apply_buffers(&mut self.unapplied_systems, schedule, world);
+        }
+        self.evaluated_sets.clear();
+        self.running_sets.clear();
+    }
+}
+
+/// Applies the buffers of the `unapplied_systems`, in the order they ran.
+pub(crate) fn apply_buffers(
+    unapplied_systems: &mut Vec<usize>,
+    schedule: &mut SystemSchedule,
+    world: &mut World,
+) {
+    for system_index in unapplied_systems.drain(..) {
+        #[cfg(feature = "trace")]
+        let _apply_buffers_span = bevy_utils::tracing::info_span!(
+            "system_commands",
+            name = &*schedule.systems[system_index].name()
+        )
+        .entered();
+        schedule.systems[system_index].apply_buffers(world);
+    }
+}
+
+/// Evaluates the conditions of the system at `system_index`, and of the sets it is in that were
+/// not evaluated yet during this run, returning `true` if they all are.
+pub(crate) fn should_run(
+    schedule: &mut SystemSchedule,
+    system_index: usize,
+    evaluated_sets: &mut FixedBitSet,
+    running_sets: &mut FixedBitSet,
+    world: &mut World,
+) -> bool {
+    let mut should_run = true;
+    for &set_index in &schedule.sets_of_systems[system_index] {
+        if !evaluated_sets.put(set_index) {
+            let set_runs = evaluate_conditions(&mut schedule.set_conditions[set_index], world);
+            running_sets.set(set_index, set_runs);
+        }
+        should_run &= running_sets.contains(set_index);
+    }
+    // The conditions of the system are only evaluated if it can still run, but those of its
+    // sets always are, so that their evaluation does not depend on the order of the systems.
+    should_run && evaluate_conditions(&mut schedule.system_conditions[system_index], world)
+}
+
+/// Evaluates every condition, without short-circuiting so that all of them see the same
+/// changes, and returns `true` if they all are.
+fn evaluate_conditions(conditions: &mut [BoxedCondition], world: &mut World) -> bool {
+    let mut all = true;
+    for condition in conditions {
+        all &= condition.run((), world);
+    }
+    all
+}
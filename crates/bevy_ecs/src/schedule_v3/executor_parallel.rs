@@ -0,0 +1,409 @@
+use async_channel::{Receiver, Sender};
+use bevy_tasks::{ComputeTaskPool, Scope, TaskPool};
+#[cfg(feature = "trace")]
+use bevy_utils::tracing::Instrument;
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    archetype::ArchetypeComponentId,
+    query::Access,
+    schedule_v3::{
+        executor::{apply_buffers, should_run},
+        BoxedCondition, SystemExecutor, SystemSchedule,
+    },
+    system::BoxedSystem,
+    world::World,
+};
+
+/// Runs the systems of a [`SystemSchedule`] on the [`ComputeTaskPool`], starting every system
+/// whose dependencies have finished as soon as its access does not conflict with the systems
+/// already running.
+///
+/// Exclusive systems, [`apply_system_buffers`](super::apply_system_buffers) and systems with
+/// exclusive run conditions wait for every running system to finish, then run on the thread
+/// running the schedule. The other run conditions are evaluated on that thread too, right
+/// before the system they gate would start, and only once no running system conflicts with
+/// them. Non-send systems also run on that thread, one at a time.
+///
+/// Systems that are not ordered relative to each other can run in any order, and in a different
+/// one every time. See [`Schedule::set_ambiguity_detection`](super::Schedule::set_ambiguity_detection)
+/// to find the ones that conflict.
+///
+/// With this executor, [`apply_system_buffers`](super::apply_system_buffers) applies the buffers
+/// of the systems that finished before it, which always include the systems ordered before it.
+#[derive(Debug)]
+pub struct MultiThreadedExecutor {
+    /// Archetype-component access of every system, updated before the systems are started.
+    system_access: Vec<Access<ArchetypeComponentId>>,
+    /// Systems that must run with exclusive access to the world.
+    exclusive_systems: FixedBitSet,
+    /// Systems that must run on the thread running the schedule.
+    non_send_systems: FixedBitSet,
+    /// Number of dependencies of every system that have not finished or been skipped yet.
+    dependencies_remaining: Vec<usize>,
+    /// Systems whose dependencies are done, waiting to be started or skipped.
+    ready: FixedBitSet,
+    /// Systems that are currently running.
+    running: FixedBitSet,
+    /// Whether a non-send system is currently running.
+    non_send_running: bool,
+    /// Compound archetype-component access of the running systems.
+    active_access: Access<ArchetypeComponentId>,
+    /// Sets whose conditions were already evaluated during this run.
+    evaluated_sets: FixedBitSet,
+    /// Sets whose conditions were all `true` during this run.
+    running_sets: FixedBitSet,
+    /// Systems that ran since buffers were last applied.
+    unapplied_systems: Vec<usize>,
+    /// Scratch space to avoid reallocating while iterating the ready systems.
+    ready_scratch: Vec<usize>,
+    /// If `true`, buffers remaining at the end of the schedule are applied.
+    apply_final_buffers: bool,
+    /// Used by system tasks to notify the executor that they have finished.
+    finish_sender: Sender<usize>,
+    /// Receives the indices of the systems that have finished.
+    finish_receiver: Receiver<usize>,
+}
+
+impl Default for MultiThreadedExecutor {
+    fn default() -> Self {
+        let (finish_sender, finish_receiver) = async_channel::unbounded();
+        Self {
+            system_access: Vec::new(),
+            exclusive_systems: FixedBitSet::new(),
+            non_send_systems: FixedBitSet::new(),
+            dependencies_remaining: Vec::new(),
+            ready: FixedBitSet::new(),
+            running: FixedBitSet::new(),
+            non_send_running: false,
+            active_access: Access::default(),
+            evaluated_sets: FixedBitSet::new(),
+            running_sets: FixedBitSet::new(),
+            unapplied_systems: Vec::new(),
+            ready_scratch: Vec::new(),
+            apply_final_buffers: true,
+            finish_sender,
+            finish_receiver,
+        }
+    }
+}
+
+impl MultiThreadedExecutor {
+    /// Sets whether the buffers of systems that ran after the last
+    /// [`apply_system_buffers`](super::apply_system_buffers) are applied at the end of each run.
+    /// This is the default.
+    pub fn set_apply_final_buffers(&mut self, apply_final_buffers: bool) {
+        self.apply_final_buffers = apply_final_buffers;
+    }
+}
+
+impl SystemExecutor for MultiThreadedExecutor {
+    fn init(&mut self, schedule: &SystemSchedule) {
+        let system_count = schedule.systems.len();
+        let set_count = schedule.set_conditions.len();
+        self.system_access = vec![Access::default(); system_count];
+        self.exclusive_systems = FixedBitSet::with_capacity(system_count);
+        self.non_send_systems = FixedBitSet::with_capacity(system_count);
+        for (index, system) in schedule.systems.iter().enumerate() {
+            self.exclusive_systems.set(
+                index,
+                system.is_exclusive() || schedule.apply_buffers[index],
+            );
+            self.non_send_systems.set(index, !system.is_send());
+        }
+        self.ready = FixedBitSet::with_capacity(system_count);
+        self.running = FixedBitSet::with_capacity(system_count);
+        self.evaluated_sets = FixedBitSet::with_capacity(set_count);
+        self.running_sets = FixedBitSet::with_capacity(set_count);
+        self.unapplied_systems = Vec::with_capacity(system_count);
+    }
+
+    fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
+        self.dependencies_remaining
+            .clone_from(&schedule.dependency_counts);
+        for (index, &count) in schedule.dependency_counts.iter().enumerate() {
+            self.ready.set(index, count == 0);
+        }
+
+        // Run systems in parallel until one needs exclusive access to the world, run it, and
+        // repeat until every system has run or been skipped.
+        while let Some(system_index) = self.run_parallel(schedule, world) {
+            self.ready.set(system_index, false);
+            if should_run(
+                schedule,
+                system_index,
+                &mut self.evaluated_sets,
+                &mut self.running_sets,
+                world,
+            ) {
+                if schedule.apply_buffers[system_index] {
+                    apply_buffers(&mut self.unapplied_systems, schedule, world);
+                } else {
+                    let system = &mut schedule.systems[system_index];
+                    #[cfg(feature = "trace")]
+                    let _system_span =
+                        bevy_utils::tracing::info_span!("system", name = &*system.name()).entered();
+                    system.run((), world);
+                    self.unapplied_systems.push(system_index);
+                }
+            }
+            self.finish(&schedule.dependents, system_index);
+        }
+
+        if self.apply_final_buffers {
+            apply_buffers(&mut self.unapplied_systems, schedule, world);
+        }
+        self.evaluated_sets.clear();
+        self.running_sets.clear();
+    }
+}
+
+impl MultiThreadedExecutor {
+    /// Runs the ready systems that don't need exclusive access to the world on the task pool,
+    /// until no other system can be started.
+    ///
+    /// Returns the system that needs exclusive access and blocked the others, if any. No system
+    /// is running when this returns.
+    fn run_parallel(&mut self, schedule: &mut SystemSchedule, world: &mut World) -> Option<usize> {
+        {
+            #[cfg(feature = "trace")]
+            let _span = bevy_utils::tracing::info_span!("update_archetypes").entered();
+            for (system, access) in schedule.systems.iter_mut().zip(&mut self.system_access) {
+                system.update_archetype_component_access(world);
+                access.clear();
+                access.extend(system.archetype_component_access());
+            }
+            let conditions = schedule
+                .system_conditions
+                .iter_mut()
+                .chain(&mut schedule.set_conditions)
+                .flatten();
+            for condition in conditions {
+                condition.update_archetype_component_access(world);
+            }
+        }
+
+        let world: &World = world;
+        let mut systems: Vec<Option<&mut BoxedSystem>> =
+            schedule.systems.iter_mut().map(Some).collect();
+        let mut exclusive_system = None;
+        ComputeTaskPool::init(TaskPool::default).scope(|scope| {
+            let executor = &mut *self;
+            let systems = &mut systems;
+            let exclusive_system = &mut exclusive_system;
+            let system_conditions = &mut schedule.system_conditions;
+            let set_conditions = &mut schedule.set_conditions;
+            let sets_of_systems = &schedule.sets_of_systems;
+            let dependents = &schedule.dependents;
+            let executor_task = async move {
+                loop {
+                    let blocked = executor.start_ready_systems(
+                        scope,
+                        systems,
+                        system_conditions,
+                        set_conditions,
+                        sets_of_systems,
+                        dependents,
+                        world,
+                    );
+                    if executor.running.count_ones(..) == 0 {
+                        *exclusive_system = blocked;
+                        break;
+                    }
+
+                    // Wait until at least one system has finished.
+                    let index = executor
+                        .finish_receiver
+                        .recv()
+                        .await
+                        .unwrap_or_else(|error| unreachable!("{}", error));
+                    executor.finish_running(dependents, index);
+                    while let Ok(index) = executor.finish_receiver.try_recv() {
+                        executor.finish_running(dependents, index);
+                    }
+                    executor.active_access.clear();
+                    for index in executor.running.ones() {
+                        executor
+                            .active_access
+                            .extend(&executor.system_access[index]);
+                    }
+                }
+            };
+            #[cfg(feature = "trace")]
+            let executor_task =
+                executor_task.instrument(bevy_utils::tracing::info_span!("multithreaded executor"));
+            // Run conditions are evaluated by the executor itself, so keep it on this thread
+            // for non-send conditions.
+            scope.spawn_on_scope(executor_task);
+        });
+        exclusive_system
+    }
+
+    /// Starts or skips the ready systems until none of the remaining ones can be started.
+    ///
+    /// Returns the first ready system found that needs exclusive access to the world.
+    #[allow(clippy::too_many_arguments)]
+    fn start_ready_systems<'env>(
+        &mut self,
+        scope: &Scope<'_, 'env, ()>,
+        systems: &mut [Option<&'env mut BoxedSystem>],
+        system_conditions: &mut [Vec<BoxedCondition>],
+        set_conditions: &mut [Vec<BoxedCondition>],
+        sets_of_systems: &[Vec<usize>],
+        dependents: &[Vec<usize>],
+        world: &'env World,
+    ) -> Option<usize> {
+        let mut blocked = None;
+        // Skipped systems may make their dependents ready, so repeat until nothing changes.
+        let mut progressed = true;
+        while progressed {
+            progressed = false;
+            self.ready_scratch.clear();
+            self.ready_scratch.extend(self.ready.ones());
+            for index in 0..self.ready_scratch.len() {
+                let system_index = self.ready_scratch[index];
+                let pending_sets = sets_of_systems[system_index]
+                    .iter()
+                    .filter(|&&set| !self.evaluated_sets.contains(set));
+                let mut conditions = system_conditions[system_index]
+                    .iter()
+                    .chain(pending_sets.flat_map(|&set| &set_conditions[set]));
+
+                if self.exclusive_systems.contains(system_index)
+                    || conditions.clone().any(|condition| condition.is_exclusive())
+                {
+                    blocked = blocked.or(Some(system_index));
+                    continue;
+                }
+                let can_start = self.system_access[system_index].is_compatible(&self.active_access)
+                    && !(self.non_send_running && self.non_send_systems.contains(system_index))
+                    && conditions.all(|condition| {
+                        condition
+                            .archetype_component_access()
+                            .is_compatible(&self.active_access)
+                    });
+                if !can_start {
+                    continue;
+                }
+
+                self.ready.set(system_index, false);
+                // SAFETY: the conditions don't conflict with the running systems, and are
+                // evaluated one at a time on this thread.
+                let should_run = unsafe {
+                    should_run_unsafe(
+                        system_conditions,
+                        set_conditions,
+                        &sets_of_systems[system_index],
+                        system_index,
+                        &mut self.evaluated_sets,
+                        &mut self.running_sets,
+                        world,
+                    )
+                };
+                if !should_run {
+                    self.finish(dependents, system_index);
+                    progressed = true;
+                    continue;
+                }
+
+                let system = systems[system_index].take().unwrap();
+                self.spawn_system(scope, system, system_index, world);
+            }
+        }
+        blocked
+    }
+
+    fn spawn_system<'env>(
+        &mut self,
+        scope: &Scope<'_, 'env, ()>,
+        system: &'env mut BoxedSystem,
+        system_index: usize,
+        world: &'env World,
+    ) {
+        #[cfg(feature = "trace")] // NB: outside the task to get the TLS current span
+        let system_span = bevy_utils::tracing::info_span!("system", name = &*system.name());
+        let finish_sender = self.finish_sender.clone();
+        let task = async move {
+            {
+                #[cfg(feature = "trace")]
+                let _system_guard = system_span.enter();
+                // SAFETY: the executor prevents two systems with conflicting access from running
+                // simultaneously.
+                unsafe { system.run_unsafe((), world) };
+            }
+            // This will never panic: the channel is unbounded and never closed while a system
+            // is running.
+            finish_sender
+                .send(system_index)
+                .await
+                .unwrap_or_else(|error| unreachable!("{}", error));
+        };
+
+        self.running.insert(system_index);
+        self.active_access.extend(&self.system_access[system_index]);
+        if self.non_send_systems.contains(system_index) {
+            self.non_send_running = true;
+            scope.spawn_on_scope(task);
+        } else {
+            scope.spawn(task);
+        }
+    }
+
+    /// Records that a system started by [`Self::spawn_system`] has finished.
+    fn finish_running(&mut self, dependents: &[Vec<usize>], system_index: usize) {
+        self.running.set(system_index, false);
+        if self.non_send_systems.contains(system_index) {
+            self.non_send_running = false;
+        }
+        self.unapplied_systems.push(system_index);
+        self.finish(dependents, system_index);
+    }
+
+    /// Makes the dependents of a system that ran or was skipped ready once all their other
+    /// dependencies are done.
+    fn finish(&mut self, dependents: &[Vec<usize>], system_index: usize) {
+        for &dependent in &dependents[system_index] {
+            self.dependencies_remaining[dependent] -= 1;
+            if self.dependencies_remaining[dependent] == 0 {
+                self.ready.insert(dependent);
+            }
+        }
+    }
+}
+
+/// Like [`should_run`], but evaluates the conditions with shared access to the `world`.
+///
+/// # Safety
+///
+/// The conditions of the system and of the sets it is in that were not evaluated yet must not
+/// be exclusive, and must not conflict with any system running at the same time.
+unsafe fn should_run_unsafe(
+    system_conditions: &mut [Vec<BoxedCondition>],
+    set_conditions: &mut [Vec<BoxedCondition>],
+    sets_of_system: &[usize],
+    system_index: usize,
+    evaluated_sets: &mut FixedBitSet,
+    running_sets: &mut FixedBitSet,
+    world: &World,
+) -> bool {
+    let mut should_run = true;
+    for &set_index in sets_of_system {
+        if !evaluated_sets.put(set_index) {
+            let set_runs = evaluate_conditions_unsafe(&mut set_conditions[set_index], world);
+            running_sets.set(set_index, set_runs);
+        }
+        should_run &= running_sets.contains(set_index);
+    }
+    should_run && evaluate_conditions_unsafe(&mut system_conditions[system_index], world)
+}
+
+/// # Safety
+///
+/// See [`should_run_unsafe`].
+unsafe fn evaluate_conditions_unsafe(conditions: &mut [BoxedCondition], world: &World) -> bool {
+    let mut all = true;
+    for condition in conditions {
+        all &= condition.run_unsafe((), world);
+    }
+    all
+}
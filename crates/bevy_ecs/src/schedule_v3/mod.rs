@@ -0,0 +1,344 @@
+//! Stageless scheduling: systems ordered by an explicit dependency graph.
+//!
+//! Systems are added to a [`Schedule`] and can be grouped into nested [`SystemSet`]s. Systems
+//! and sets are ordered relative to each other with `before` and `after`, and gated by
+//! [run conditions](Condition), plain systems returning a `bool`. Command buffers are applied
+//! where [`apply_system_buffers`] is scheduled, rather than at the end of stages.
+//!
+//! # Example
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::schedule_v3::{
+//! #     apply_system_buffers, common_conditions::resource_exists, IntoSystemConfig,
+//! #     IntoSystemConfigs, IntoSystemSetConfig, Schedule, SystemSet,
+//! # };
+//! #[derive(SystemSet)]
+//! enum Gameplay {
+//!     Spawning,
+//!     Movement,
+//! }
+//!
+//! #[derive(Resource)]
+//! struct Running;
+//!
+//! # fn spawn_enemies() {}
+//! # fn move_player() {}
+//! # fn move_enemies() {}
+//! let mut schedule = Schedule::new();
+//! schedule
+//!     .configure_set(Gameplay::Spawning.before(Gameplay::Movement))
+//!     .configure_set(Gameplay::Movement.run_if(resource_exists::<Running>()))
+//!     .add_systems((spawn_enemies, apply_system_buffers).chain().in_set(Gameplay::Spawning))
+//!     .add_systems((move_player, move_enemies).in_set(Gameplay::Movement));
+//! ```
+
+mod condition;
+mod config;
+mod executor;
+mod executor_parallel;
+mod schedule;
+mod set;
+mod stepping;
+
+pub use condition::*;
+pub use config::*;
+pub use executor::*;
+pub use executor_parallel::*;
+pub use schedule::*;
+pub use set::*;
+pub use stepping::*;
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component,
+        schedule_v3::{
+            apply_system_buffers, common_conditions::*, Condition, IntoSystemConfig,
+            IntoSystemConfigs, IntoSystemSetConfig, LogLevel, MultiThreadedExecutor, Schedule,
+            ScheduleBuildError, SystemSet,
+        },
+        system::{Commands, Query, Res, ResMut, Resource},
+        world::World,
+    };
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<u32>);
+
+    fn push(value: u32) -> impl FnMut(ResMut<Order>) {
+        move |mut order: ResMut<Order>| order.0.push(value)
+    }
+
+    fn run(schedule: &mut Schedule) -> Vec<u32> {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        schedule.run(&mut world);
+        world.remove_resource::<Order>().unwrap().0
+    }
+
+    #[derive(SystemSet)]
+    enum TestSet {
+        A,
+        B,
+        C,
+    }
+
+    fn first(mut order: ResMut<Order>) {
+        order.0.push(0);
+    }
+
+    fn second(mut order: ResMut<Order>) {
+        order.0.push(1);
+    }
+
+    #[test]
+    fn systems_run_in_insertion_order_by_default() {
+        let mut schedule = Schedule::new();
+        schedule.add_systems((push(0), push(1), push(2)));
+        assert_eq!(run(&mut schedule), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn before_and_after_systems() {
+        let mut schedule = Schedule::new();
+        schedule
+            .add_system(second.after(first))
+            .add_system(first)
+            .add_system(push(2).before(second).after(first));
+        assert_eq!(run(&mut schedule), vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn nested_set_ordering() {
+        let mut schedule = Schedule::new();
+        schedule
+            .configure_set(TestSet::A.before(TestSet::B))
+            .configure_set(TestSet::C.in_set(TestSet::A))
+            .add_system(push(2).after(TestSet::B))
+            .add_system(push(1).in_set(TestSet::B))
+            .add_system(push(0).in_set(TestSet::C));
+        assert_eq!(run(&mut schedule), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn chain() {
+        let mut schedule = Schedule::new();
+        schedule
+            .add_systems((push(2), push(1), push(0)).chain())
+            .add_system(push(3).before(TestSet::A))
+            .add_systems((push(4), push(5)).chain().in_set(TestSet::A));
+        assert_eq!(run(&mut schedule), vec![2, 1, 0, 3, 4, 5]);
+    }
+
+    #[test]
+    fn dependency_cycle() {
+        let mut world = World::new();
+        let mut schedule = Schedule::new();
+        schedule
+            .add_system(first.after(second))
+            .add_system(second.after(TestSet::A))
+            .add_system(push(2).in_set(TestSet::A).after(first));
+        let error = schedule.initialize(&mut world).unwrap_err();
+        match error {
+            ScheduleBuildError::DependencyCycle(cycle) => assert_eq!(cycle.len(), 3),
+            error => panic!("unexpected error: {error}"),
+        }
+    }
+
+    #[test]
+    fn hierarchy_cycle() {
+        let mut world = World::new();
+        let mut schedule = Schedule::new();
+        schedule
+            .configure_set(TestSet::A.in_set(TestSet::B))
+            .configure_set(TestSet::B.in_set(TestSet::C))
+            .configure_set(TestSet::C.in_set(TestSet::A));
+        assert!(matches!(
+            schedule.initialize(&mut world),
+            Err(ScheduleBuildError::HierarchyCycle(sets)) if sets.len() == 3
+        ));
+    }
+
+    #[test]
+    fn run_conditions() {
+        #[derive(Resource)]
+        struct Flag;
+
+        let mut schedule = Schedule::new();
+        schedule
+            .configure_set(TestSet::A.run_if(resource_exists::<Flag>()))
+            .add_system(push(0).run_if(run_once()))
+            .add_system(push(1).in_set(TestSet::A))
+            .add_system(push(2).run_if(not(resource_exists::<Flag>())))
+            .add_system(
+                push(3).run_if(resource_exists::<Flag>().or_else(|| true).and_then(|| true)),
+            );
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Order>().0, vec![0, 2, 3]);
+
+        world.resource_mut::<Order>().0.clear();
+        world.insert_resource(Flag);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Order>().0, vec![1, 3]);
+    }
+
+    #[test]
+    fn set_conditions_are_evaluated_once() {
+        #[derive(Resource, Default)]
+        struct Evaluations(u32);
+
+        fn count(mut evaluations: ResMut<Evaluations>) -> bool {
+            evaluations.0 += 1;
+            true
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        world.init_resource::<Evaluations>();
+        let mut schedule = Schedule::new();
+        schedule
+            .configure_set(TestSet::B.in_set(TestSet::A))
+            .configure_set(TestSet::A.run_if(count))
+            .add_systems((push(0), push(1)).in_set(TestSet::A))
+            .add_system(push(2).in_set(TestSet::B));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Order>().0, vec![0, 1, 2]);
+        assert_eq!(world.resource::<Evaluations>().0, 1);
+    }
+
+    #[test]
+    fn apply_system_buffers_is_a_sync_point() {
+        #[derive(Component)]
+        struct Marker;
+
+        fn spawn(mut commands: Commands) {
+            commands.spawn(Marker);
+        }
+
+        fn count(query: Query<&Marker>, mut order: ResMut<Order>) {
+            order.0.push(query.iter().count() as u32);
+        }
+
+        fn count_again(query: Query<&Marker>, order: ResMut<Order>) {
+            count(query, order);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let mut schedule = Schedule::new();
+        schedule
+            .add_systems((spawn, count).chain())
+            .add_system(apply_system_buffers.after(count).before(TestSet::A))
+            .add_system(count_again.in_set(TestSet::A));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Order>().0, vec![0, 1]);
+
+        // Buffers are also applied at the end of the schedule.
+        let mut schedule = Schedule::new();
+        schedule.add_system(spawn);
+        schedule.run(&mut world);
+        assert_eq!(world.query::<&Marker>().iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn systems_added_later_are_initialized() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let mut schedule = Schedule::new();
+        schedule.add_system(push(1));
+        schedule.run(&mut world);
+        schedule.add_system(push(0).before(TestSet::A));
+        schedule.configure_set(TestSet::A.run_if(|| true));
+        schedule.add_system(push(2).in_set(TestSet::A));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Order>().0, vec![1, 1, 0, 2]);
+    }
+
+    #[test]
+    fn multi_threaded_executor() {
+        #[derive(Component)]
+        struct Marker;
+
+        #[derive(Resource, Default)]
+        struct Counts(Vec<usize>);
+
+        fn spawn(mut commands: Commands) {
+            commands.spawn(Marker);
+        }
+
+        fn count(query: Query<&Marker>, mut counts: ResMut<Counts>) {
+            counts.0.push(query.iter().count());
+        }
+
+        fn exclusive(world: &mut World) {
+            world.resource_mut::<Order>().0.push(3);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        world.init_resource::<Counts>();
+        let mut schedule = Schedule::with_executor(Box::<MultiThreadedExecutor>::default());
+        schedule
+            .configure_set(TestSet::A.run_if(|| false))
+            .configure_set(TestSet::B.after(TestSet::C))
+            .add_systems((push(0), push(1), push(2), exclusive, push(4)).chain())
+            .add_system(push(5).in_set(TestSet::A))
+            .add_systems(
+                (spawn, count, apply_system_buffers)
+                    .chain()
+                    .in_set(TestSet::C),
+            )
+            .add_system(count.in_set(TestSet::B));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Order>().0, vec![0, 1, 2, 3, 4]);
+        assert_eq!(world.resource::<Counts>().0, vec![0, 1]);
+
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<Order>().0,
+            vec![0, 1, 2, 3, 4, 0, 1, 2, 3, 4]
+        );
+        assert_eq!(world.resource::<Counts>().0, vec![0, 1, 1, 2]);
+    }
+
+    #[derive(Resource)]
+    struct Other;
+
+    fn read_order(_order: Res<Order>) {}
+
+    fn write_other(_other: ResMut<Other>) {}
+
+    #[test]
+    fn ambiguity_detection() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        world.insert_resource(Other);
+
+        let mut schedule = Schedule::new();
+        schedule
+            .set_ambiguity_detection(LogLevel::Error)
+            .add_systems((first, second, read_order, write_other));
+        let error = schedule.initialize(&mut world).unwrap_err();
+        assert!(matches!(&error, ScheduleBuildError::Ambiguity(pairs) if pairs.len() == 3));
+        assert_eq!(schedule.graph().ambiguities().len(), 3);
+
+        // Ordered systems and systems allowed to be ambiguous are not reported.
+        let mut schedule = Schedule::new();
+        schedule
+            .set_ambiguity_detection(LogLevel::Error)
+            .add_systems((first, second).chain())
+            .add_system(read_order.after(first).ambiguous_with(second))
+            .add_system(write_other);
+        schedule.initialize(&mut world).unwrap();
+        assert!(schedule.graph().ambiguities().is_empty());
+
+        // Ambiguities are not checked by default.
+        let mut schedule = Schedule::new();
+        schedule.add_systems((first, second));
+        schedule.initialize(&mut world).unwrap();
+        assert_eq!(schedule.graph().ambiguities().len(), 1);
+    }
+}
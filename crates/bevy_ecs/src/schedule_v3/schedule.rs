@@ -0,0 +1,829 @@
+use std::{
+    borrow::Cow,
+    cmp::Reverse,
+    collections::BinaryHeap,
+    fmt::{Debug, Display},
+};
+
+use bevy_utils::{tracing::warn, HashMap, HashSet};
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    change_detection::CHECK_TICK_THRESHOLD,
+    component::ComponentId,
+    schedule::Stage,
+    schedule_v3::{
+        apply_system_buffers, AsSystemSet, BoxedCondition, DependencyKind, GraphInfo,
+        IntoSystemConfig, IntoSystemConfigs, IntoSystemSetConfig, SingleThreadedExecutor,
+        SystemExecutor, SystemSetId,
+    },
+    system::BoxedSystem,
+    world::{World, WorldId},
+};
+
+/// A collection of systems, and the metadata and executor needed to run them in the right order.
+///
+/// Unlike a [`SystemStage`](crate::schedule::SystemStage), a schedule has no implicit sync
+/// points: systems are ordered by an explicit dependency graph built from their
+/// [`SystemSet`](super::SystemSet)s and `before`/`after` constraints, and command buffers are
+/// applied where [`apply_system_buffers`] is scheduled, and at the end of the schedule.
+///
+/// A schedule can be run directly with [`Schedule::run`] or inserted as a [`Stage`] into a
+/// [`Schedule`](crate::schedule::Schedule) of stages.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule_v3::{IntoSystemConfig, IntoSystemSetConfig, Schedule, SystemSet};
+/// #[derive(SystemSet)]
+/// enum GameSet {
+///     Input,
+///     Simulation,
+/// }
+///
+/// # fn read_input() {}
+/// # fn simulate() {}
+/// let mut world = World::new();
+/// let mut schedule = Schedule::new();
+/// schedule
+///     .configure_set(GameSet::Input.before(GameSet::Simulation))
+///     .add_system(simulate.in_set(GameSet::Simulation))
+///     .add_system(read_input.in_set(GameSet::Input));
+/// schedule.run(&mut world);
+/// ```
+#[derive(Debug)]
+pub struct Schedule {
+    graph: ScheduleGraph,
+    executable: SystemSchedule,
+    executor: Box<dyn SystemExecutor>,
+    world_id: Option<WorldId>,
+    /// Saves the value of the World change_tick during the last tick check
+    last_tick_check: u32,
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Schedule {
+    /// Creates an empty schedule run by a [`SingleThreadedExecutor`].
+    pub fn new() -> Self {
+        Self::with_executor(Box::<SingleThreadedExecutor>::default())
+    }
+
+    /// Creates an empty schedule run by the given [`SystemExecutor`].
+    pub fn with_executor(executor: Box<dyn SystemExecutor>) -> Self {
+        Self {
+            graph: ScheduleGraph::default(),
+            executable: SystemSchedule::default(),
+            executor,
+            world_id: None,
+            last_tick_check: Default::default(),
+        }
+    }
+
+    /// Replaces the [`SystemExecutor`] running this schedule.
+    pub fn set_executor(&mut self, executor: Box<dyn SystemExecutor>) -> &mut Self {
+        self.executor = executor;
+        self.graph.changed = true;
+        self
+    }
+
+    /// Returns the [`SystemExecutor`] running this schedule.
+    pub fn executor(&self) -> &dyn SystemExecutor {
+        &*self.executor
+    }
+
+    /// Returns the [`SystemExecutor`] running this schedule.
+    pub fn executor_mut(&mut self) -> &mut dyn SystemExecutor {
+        &mut *self.executor
+    }
+
+    /// Adds a system to the schedule.
+    pub fn add_system<Params>(&mut self, system: impl IntoSystemConfig<Params>) -> &mut Self {
+        self.graph.add_system(system);
+        self
+    }
+
+    /// Adds a collection of systems to the schedule, such as a tuple of systems.
+    pub fn add_systems<Params>(&mut self, systems: impl IntoSystemConfigs<Params>) -> &mut Self {
+        self.graph.add_systems(systems);
+        self
+    }
+
+    /// Configures a set: its parent sets, ordering and run conditions.
+    ///
+    /// Sets don't need to be configured to be used, and can be configured any number of times:
+    /// the configurations are merged.
+    pub fn configure_set(&mut self, set: impl IntoSystemSetConfig) -> &mut Self {
+        self.graph.configure_set(set);
+        self
+    }
+
+    /// Sets how systems with conflicting access and no order between them are reported when
+    /// the schedule is built. They are ignored by default.
+    ///
+    /// Such systems may run in any order, and in a different one every time with a
+    /// [`MultiThreadedExecutor`](super::MultiThreadedExecutor). Use
+    /// [`ambiguous_with`](IntoSystemConfig::ambiguous_with) to allow specific pairs.
+    pub fn set_ambiguity_detection(&mut self, level: LogLevel) -> &mut Self {
+        self.graph.ambiguity_detection = level;
+        self.graph.changed = true;
+        self
+    }
+
+    /// Returns the [`ScheduleGraph`] the systems are ordered from.
+    pub fn graph(&self) -> &ScheduleGraph {
+        &self.graph
+    }
+
+    /// Returns the [`SystemSchedule`] produced from the [`ScheduleGraph`] the last time the
+    /// schedule was initialized.
+    pub fn executable(&self) -> &SystemSchedule {
+        &self.executable
+    }
+
+    /// Initializes the new systems and conditions, and rebuilds the execution order if the
+    /// schedule changed since the last time it was initialized.
+    ///
+    /// This is done automatically by [`Schedule::run`], but can be done beforehand to find
+    /// errors in the schedule.
+    pub fn initialize(&mut self, world: &mut World) -> Result<(), ScheduleBuildError> {
+        if let Some(world_id) = self.world_id {
+            assert!(
+                world.id() == world_id,
+                "Schedule is being run on the wrong World: it was initialized for a different one"
+            );
+        } else {
+            self.world_id = Some(world.id());
+        }
+
+        self.graph.initialize(&mut self.executable, world);
+        if self.graph.changed {
+            self.graph.build_schedule(&mut self.executable, world)?;
+            self.executor.init(&self.executable);
+            self.graph.changed = false;
+        }
+        Ok(())
+    }
+
+    /// Runs all the systems of the schedule in the `world`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the schedule can not be built, see [`ScheduleBuildError`].
+    pub fn run(&mut self, world: &mut World) {
+        if let Err(error) = self.initialize(world) {
+            panic!("Failed to build the schedule: {error}");
+        }
+        self.executor.run(&mut self.executable, world);
+        self.check_change_ticks(world);
+    }
+
+    /// Checks for old component and system change ticks, like
+    /// [`SystemStage`](crate::schedule::SystemStage) does.
+    fn check_change_ticks(&mut self, world: &mut World) {
+        let change_tick = world.change_tick();
+        let ticks_since_last_check = change_tick.wrapping_sub(self.last_tick_check);
+
+        if ticks_since_last_check >= CHECK_TICK_THRESHOLD {
+            let executable = &mut self.executable;
+            for system in &mut executable.systems {
+                system.check_change_tick(change_tick);
+            }
+            let conditions = executable
+                .system_conditions
+                .iter_mut()
+                .chain(&mut executable.set_conditions)
+                .flatten();
+            for condition in conditions {
+                condition.check_change_tick(change_tick);
+            }
+
+            world.check_change_ticks();
+            self.last_tick_check = change_tick;
+        }
+    }
+}
+
+impl Stage for Schedule {
+    fn run(&mut self, world: &mut World) {
+        Schedule::run(self, world);
+    }
+}
+
+/// Identifies a system or a set in a [`ScheduleGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum NodeId {
+    /// The index of a system, in the order the systems were added.
+    System(usize),
+    /// The index of a set, in the order the sets were first used.
+    Set(usize),
+}
+
+/// How a problem found while building a [`Schedule`] is reported.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogLevel {
+    /// The problem is not reported.
+    #[default]
+    Ignore,
+    /// A warning is logged, and the schedule is built anyway.
+    Warn,
+    /// The schedule fails to build with a [`ScheduleBuildError`].
+    Error,
+}
+
+/// The systems and sets of a [`Schedule`], and the hierarchy and ordering constraints between
+/// them.
+#[derive(Default)]
+pub struct ScheduleGraph {
+    system_graph_info: Vec<GraphInfo>,
+    set_ids: Vec<SystemSetId>,
+    set_indices: HashMap<SystemSetId, usize>,
+    set_graph_info: Vec<GraphInfo>,
+    /// Conditions of the sets added since the schedule was last initialized.
+    uninitialized_set_conditions: Vec<(usize, BoxedCondition)>,
+    /// Systems and their conditions added since the schedule was last initialized.
+    uninitialized_systems: Vec<(BoxedSystem, Vec<BoxedCondition>)>,
+    /// Orderings between systems added by [`chain`](IntoSystemConfigs::chain).
+    chained: Vec<(usize, usize)>,
+    /// Direct dependency edges between systems, computed when the schedule was last built.
+    dependencies: Vec<(usize, usize)>,
+    /// Pairs of systems with conflicting access and no order, computed when the schedule was
+    /// last built.
+    ambiguities: Vec<(usize, usize, Vec<ComponentId>)>,
+    ambiguity_detection: LogLevel,
+    changed: bool,
+}
+
+impl Debug for ScheduleGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScheduleGraph")
+            .field("system_graph_info", &self.system_graph_info)
+            .field("set_ids", &self.set_ids)
+            .field("set_graph_info", &self.set_graph_info)
+            .field("dependencies", &self.dependencies)
+            .field("ambiguities", &self.ambiguities)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ScheduleGraph {
+    /// Returns the number of systems in the graph.
+    pub fn system_count(&self) -> usize {
+        self.system_graph_info.len()
+    }
+
+    /// Returns the [`SystemSetId`]s of the sets in the graph, indexed by [`NodeId::Set`].
+    pub fn sets(&self) -> &[SystemSetId] {
+        &self.set_ids
+    }
+
+    /// Returns the [`NodeId::Set`] of `set`, if it is used in the graph.
+    pub fn set_id<Marker>(&self, set: impl AsSystemSet<Marker>) -> Option<NodeId> {
+        self.set_indices
+            .get(&set.as_system_set())
+            .map(|&index| NodeId::Set(index))
+    }
+
+    /// Returns the sets `node` is directly in.
+    pub fn parents(&self, node: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.graph_info(node)
+            .sets
+            .iter()
+            .map(|set| NodeId::Set(self.set_indices[set]))
+    }
+
+    /// Returns the sets `node` must run before or after, as specified when it was added or
+    /// configured.
+    pub fn orderings(&self, node: NodeId) -> impl Iterator<Item = (NodeId, NodeId)> + '_ {
+        self.graph_info(node)
+            .dependencies
+            .iter()
+            .map(move |dependency| {
+                let set = NodeId::Set(self.set_indices[&dependency.set]);
+                match dependency.kind {
+                    DependencyKind::Before => (node, set),
+                    DependencyKind::After => (set, node),
+                }
+            })
+    }
+
+    /// Returns the `(before, after)` pairs of systems that must run in that order, as computed
+    /// when the schedule was last built.
+    ///
+    /// Only direct constraints are included, not the ones implied by transitivity.
+    pub fn dependencies(&self) -> &[(usize, usize)] {
+        &self.dependencies
+    }
+
+    /// Returns the pairs of systems that have conflicting access but no order between them, with
+    /// the components and resources they conflict on, as computed when the schedule was last
+    /// built.
+    ///
+    /// The list of conflicts is empty if one of the systems is exclusive. Pairs allowed with
+    /// [`ambiguous_with`](IntoSystemConfig::ambiguous_with) and [`apply_system_buffers`] are
+    /// not included.
+    pub fn ambiguities(&self) -> &[(usize, usize, Vec<ComponentId>)] {
+        &self.ambiguities
+    }
+
+    fn graph_info(&self, node: NodeId) -> &GraphInfo {
+        match node {
+            NodeId::System(index) => &self.system_graph_info[index],
+            NodeId::Set(index) => &self.set_graph_info[index],
+        }
+    }
+
+    fn set_index(&mut self, set: SystemSetId) -> usize {
+        if let Some(&index) = self.set_indices.get(&set) {
+            return index;
+        }
+        let index = self.set_ids.len();
+        self.set_ids.push(set);
+        self.set_indices.insert(set, index);
+        self.set_graph_info.push(GraphInfo::default());
+        index
+    }
+
+    /// Registers the sets referenced by `graph_info`.
+    fn add_sets_of(&mut self, graph_info: &GraphInfo) {
+        for &set in &graph_info.sets {
+            self.set_index(set);
+        }
+        for dependency in &graph_info.dependencies {
+            self.set_index(dependency.set);
+        }
+        for &set in &graph_info.ambiguous_with {
+            self.set_index(set);
+        }
+    }
+
+    fn add_system<Params>(&mut self, system: impl IntoSystemConfig<Params>) -> usize {
+        let config = system.into_config();
+        self.add_sets_of(&config.graph_info);
+        self.system_graph_info.push(config.graph_info);
+        self.changed = true;
+        self.uninitialized_systems
+            .push((config.system, config.conditions));
+        self.system_graph_info.len() - 1
+    }
+
+    fn add_systems<Params>(&mut self, systems: impl IntoSystemConfigs<Params>) {
+        let configs = systems.into_configs();
+        let mut previous = None;
+        for config in configs.systems {
+            let index = self.add_system(config);
+            if configs.chained {
+                if let Some(previous) = previous {
+                    self.chained.push((previous, index));
+                }
+                previous = Some(index);
+            }
+        }
+    }
+
+    fn configure_set(&mut self, set: impl IntoSystemSetConfig) {
+        let config = set.into_config();
+        let index = self.set_index(config.set);
+        self.add_sets_of(&config.graph_info);
+        let graph_info = &mut self.set_graph_info[index];
+        graph_info.sets.extend(config.graph_info.sets);
+        graph_info
+            .dependencies
+            .extend(config.graph_info.dependencies);
+        self.uninitialized_set_conditions
+            .extend(config.conditions.into_iter().map(|c| (index, c)));
+        self.changed = true;
+    }
+
+    /// Moves the systems and conditions added since the last call into `schedule`, and
+    /// initializes them.
+    fn initialize(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
+        for (mut system, mut conditions) in self.uninitialized_systems.drain(..) {
+            system.initialize(world);
+            for condition in &mut conditions {
+                condition.initialize(world);
+            }
+            schedule.systems.push(system);
+            schedule.system_conditions.push(conditions);
+        }
+        schedule
+            .set_conditions
+            .resize_with(self.set_ids.len(), Vec::new);
+        for (set_index, mut condition) in self.uninitialized_set_conditions.drain(..) {
+            condition.initialize(world);
+            schedule.set_conditions[set_index].push(condition);
+        }
+    }
+
+    /// Computes the execution order and condition metadata of `schedule` from the graph.
+    fn build_schedule(
+        &mut self,
+        schedule: &mut SystemSchedule,
+        world: &World,
+    ) -> Result<(), ScheduleBuildError> {
+        let set_count = self.set_ids.len();
+        let system_count = self.system_graph_info.len();
+
+        // Children of each set, to find the systems each set transitively contains.
+        let mut child_sets = vec![Vec::new(); set_count];
+        let mut child_systems = vec![Vec::new(); set_count];
+        for (set_index, graph_info) in self.set_graph_info.iter().enumerate() {
+            for parent in &graph_info.sets {
+                child_sets[self.set_indices[parent]].push(set_index);
+            }
+        }
+        for (system_index, graph_info) in self.system_graph_info.iter().enumerate() {
+            for parent in &graph_info.sets {
+                child_systems[self.set_indices[parent]].push(system_index);
+            }
+        }
+        self.check_hierarchy(&child_sets)?;
+
+        let mut set_systems: Vec<Option<Vec<usize>>> = vec![None; set_count];
+        for set_index in 0..set_count {
+            collect_systems(set_index, &child_sets, &child_systems, &mut set_systems);
+        }
+        let set_systems: Vec<Vec<usize>> = set_systems.into_iter().map(Option::unwrap).collect();
+
+        // Flatten the dependencies between sets and systems into dependencies between systems.
+        let mut edges = HashSet::new();
+        let nodes = (0..system_count)
+            .map(NodeId::System)
+            .chain((0..set_count).map(NodeId::Set));
+        for node in nodes {
+            let system;
+            let members: &[usize] = match node {
+                NodeId::System(index) => {
+                    system = [index];
+                    &system
+                }
+                NodeId::Set(index) => &set_systems[index],
+            };
+            for dependency in &self.graph_info(node).dependencies {
+                let others = &set_systems[self.set_indices[&dependency.set]];
+                for &member in members {
+                    for &other in others {
+                        edges.insert(match dependency.kind {
+                            DependencyKind::Before => (member, other),
+                            DependencyKind::After => (other, member),
+                        });
+                    }
+                }
+            }
+        }
+        edges.extend(self.chained.iter().copied());
+        let mut dependencies: Vec<_> = edges.into_iter().collect();
+        dependencies.sort_unstable();
+
+        schedule.order = topological_order(schedule, &dependencies)?;
+        schedule.dependents = vec![Vec::new(); system_count];
+        schedule.dependency_counts = vec![0; system_count];
+        for &(before, after) in &dependencies {
+            schedule.dependents[before].push(after);
+            schedule.dependency_counts[after] += 1;
+        }
+        self.dependencies = dependencies;
+
+        // Sets with conditions gating each system, from the outermost to the innermost.
+        let mut sets_of_systems = vec![Vec::new(); system_count];
+        for set_index in self.hierarchy_order(&child_sets) {
+            if schedule.set_conditions[set_index].is_empty() {
+                continue;
+            }
+            for &system_index in &set_systems[set_index] {
+                sets_of_systems[system_index].push(set_index);
+            }
+        }
+        schedule.sets_of_systems = sets_of_systems;
+
+        let apply_buffers_set = apply_system_buffers.as_system_set();
+        schedule.apply_buffers = self
+            .system_graph_info
+            .iter()
+            .map(|graph_info| graph_info.sets.contains(&apply_buffers_set))
+            .collect();
+
+        self.ambiguities = self.find_ambiguities(schedule, &set_systems);
+        if self.ambiguity_detection != LogLevel::Ignore && !self.ambiguities.is_empty() {
+            let ambiguities = self.describe_ambiguities(schedule, world);
+            if self.ambiguity_detection == LogLevel::Error {
+                return Err(ScheduleBuildError::Ambiguity(ambiguities));
+            }
+            warn!(
+                "Systems with conflicting access have no order between them:\n{}",
+                ambiguities.join("\n")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns the pairs of systems of `schedule` that conflict and are not ordered, directly or
+    /// transitively. `schedule.order` and `schedule.dependents` must be up to date.
+    fn find_ambiguities(
+        &self,
+        schedule: &SystemSchedule,
+        set_systems: &[Vec<usize>],
+    ) -> Vec<(usize, usize, Vec<ComponentId>)> {
+        let system_count = schedule.systems.len();
+
+        // The systems each system runs after, directly or transitively.
+        let mut ancestors = vec![FixedBitSet::with_capacity(system_count); system_count];
+        for &system in &schedule.order {
+            let system_ancestors = ancestors[system].clone();
+            for &dependent in &schedule.dependents[system] {
+                ancestors[dependent].union_with(&system_ancestors);
+                ancestors[dependent].insert(system);
+            }
+        }
+
+        let mut ignored = vec![FixedBitSet::with_capacity(system_count); system_count];
+        for (system, graph_info) in self.system_graph_info.iter().enumerate() {
+            for set in &graph_info.ambiguous_with {
+                for &other in &set_systems[self.set_indices[set]] {
+                    ignored[system].insert(other);
+                    ignored[other].insert(system);
+                }
+            }
+        }
+
+        let mut ambiguities = Vec::new();
+        for a in 0..system_count {
+            for b in a + 1..system_count {
+                if schedule.apply_buffers[a]
+                    || schedule.apply_buffers[b]
+                    || ancestors[a].contains(b)
+                    || ancestors[b].contains(a)
+                    || ignored[a].contains(b)
+                {
+                    continue;
+                }
+                let (system_a, system_b) = (&schedule.systems[a], &schedule.systems[b]);
+                if system_a.is_exclusive() || system_b.is_exclusive() {
+                    ambiguities.push((a, b, Vec::new()));
+                    continue;
+                }
+                let conflicts = system_a
+                    .component_access()
+                    .get_conflicts(system_b.component_access());
+                if !conflicts.is_empty() {
+                    ambiguities.push((a, b, conflicts));
+                }
+            }
+        }
+        ambiguities
+    }
+
+    fn describe_ambiguities(&self, schedule: &SystemSchedule, world: &World) -> Vec<String> {
+        self.ambiguities
+            .iter()
+            .map(|(a, b, conflicts)| {
+                let conflicts: Vec<_> = conflicts
+                    .iter()
+                    .map(|&id| world.components().get_info(id).unwrap().name())
+                    .collect();
+                format!(
+                    "{} and {} (conflicts: {:?})",
+                    schedule.system_name(*a),
+                    schedule.system_name(*b),
+                    conflicts
+                )
+            })
+            .collect()
+    }
+
+    /// Returns an error if a set contains itself, directly or through other sets.
+    fn check_hierarchy(&self, child_sets: &[Vec<usize>]) -> Result<(), ScheduleBuildError> {
+        // 0: unvisited, 1: being visited, 2: done
+        fn visit(
+            set: usize,
+            child_sets: &[Vec<usize>],
+            state: &mut [u8],
+            path: &mut Vec<usize>,
+        ) -> Result<(), Vec<usize>> {
+            match state[set] {
+                1 => {
+                    let start = path.iter().position(|&s| s == set).unwrap();
+                    return Err(path[start..].to_vec());
+                }
+                2 => return Ok(()),
+                _ => {}
+            }
+            state[set] = 1;
+            path.push(set);
+            for &child in &child_sets[set] {
+                visit(child, child_sets, state, path)?;
+            }
+            path.pop();
+            state[set] = 2;
+            Ok(())
+        }
+
+        let mut state = vec![0; child_sets.len()];
+        for set in 0..child_sets.len() {
+            visit(set, child_sets, &mut state, &mut Vec::new()).map_err(|cycle| {
+                ScheduleBuildError::HierarchyCycle(
+                    cycle
+                        .into_iter()
+                        .map(|set| format!("{:?}", self.set_ids[set]))
+                        .collect(),
+                )
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Returns the sets ordered so that parents come before their children.
+    fn hierarchy_order(&self, child_sets: &[Vec<usize>]) -> Vec<usize> {
+        let mut parent_count = vec![0; child_sets.len()];
+        for children in child_sets {
+            for &child in children {
+                parent_count[child] += 1;
+            }
+        }
+        let mut ready: Vec<_> = (0..child_sets.len())
+            .filter(|&set| parent_count[set] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(child_sets.len());
+        while let Some(set) = ready.pop() {
+            order.push(set);
+            for &child in &child_sets[set] {
+                parent_count[child] -= 1;
+                if parent_count[child] == 0 {
+                    ready.push(child);
+                }
+            }
+        }
+        order
+    }
+}
+
+/// Sorts the systems so that every system comes after its dependencies, keeping the order
+/// they were added in wherever they are unconstrained.
+fn topological_order(
+    schedule: &SystemSchedule,
+    dependencies: &[(usize, usize)],
+) -> Result<Vec<usize>, ScheduleBuildError> {
+    let system_count = schedule.systems.len();
+    let mut successors = vec![Vec::new(); system_count];
+    let mut predecessor_count = vec![0usize; system_count];
+    for &(before, after) in dependencies {
+        successors[before].push(after);
+        predecessor_count[after] += 1;
+    }
+
+    let mut ready: BinaryHeap<_> = (0..system_count)
+        .filter(|&system| predecessor_count[system] == 0)
+        .map(Reverse)
+        .collect();
+    let mut order = Vec::with_capacity(system_count);
+    while let Some(Reverse(system)) = ready.pop() {
+        order.push(system);
+        for &successor in &successors[system] {
+            predecessor_count[successor] -= 1;
+            if predecessor_count[successor] == 0 {
+                ready.push(Reverse(successor));
+            }
+        }
+    }
+
+    if order.len() == system_count {
+        return Ok(order);
+    }
+
+    // Every remaining system has a remaining predecessor, so walking predecessors from any
+    // of them must eventually loop.
+    let mut predecessors = vec![Vec::new(); system_count];
+    for &(before, after) in dependencies {
+        if predecessor_count[before] > 0 {
+            predecessors[after].push(before);
+        }
+    }
+    let mut visited = FixedBitSet::with_capacity(system_count);
+    let mut path = Vec::new();
+    let mut system = (0..system_count)
+        .find(|&system| predecessor_count[system] > 0)
+        .unwrap();
+    while !visited.put(system) {
+        path.push(system);
+        system = predecessors[system][0];
+    }
+    let start = path.iter().position(|&s| s == system).unwrap();
+    let cycle = path[start..]
+        .iter()
+        .rev()
+        .map(|&system| schedule.system_name(system).into_owned())
+        .collect();
+    Err(ScheduleBuildError::DependencyCycle(cycle))
+}
+
+/// Recursively collects the systems contained in `set` and its subsets.
+fn collect_systems(
+    set: usize,
+    child_sets: &[Vec<usize>],
+    child_systems: &[Vec<usize>],
+    set_systems: &mut [Option<Vec<usize>>],
+) {
+    if set_systems[set].is_some() {
+        return;
+    }
+    let mut systems = child_systems[set].clone();
+    for &child in &child_sets[set] {
+        collect_systems(child, child_sets, child_systems, set_systems);
+        systems.extend(set_systems[child].as_ref().unwrap());
+    }
+    systems.sort_unstable();
+    systems.dedup();
+    set_systems[set] = Some(systems);
+}
+
+/// The systems of a [`Schedule`], in the order and with the metadata its
+/// [`SystemExecutor`] needs to run them.
+#[derive(Default)]
+pub struct SystemSchedule {
+    /// The systems, in the order they were added.
+    pub(crate) systems: Vec<BoxedSystem>,
+    /// The conditions of each system.
+    pub(crate) system_conditions: Vec<Vec<BoxedCondition>>,
+    /// The conditions of each set.
+    pub(crate) set_conditions: Vec<Vec<BoxedCondition>>,
+    /// The indices of the systems, in an order satisfying their dependencies.
+    pub(crate) order: Vec<usize>,
+    /// For each system, the systems that directly depend on it.
+    pub(crate) dependents: Vec<Vec<usize>>,
+    /// For each system, the number of systems it directly depends on.
+    pub(crate) dependency_counts: Vec<usize>,
+    /// For each system, the sets with conditions it is in, outermost first.
+    pub(crate) sets_of_systems: Vec<Vec<usize>>,
+    /// For each system, whether it is [`apply_system_buffers`].
+    pub(crate) apply_buffers: Vec<bool>,
+}
+
+impl Debug for SystemSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SystemSchedule")
+            .field("systems", &self.systems)
+            .field("order", &self.order)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SystemSchedule {
+    /// Returns the systems, in the order they were added to the [`Schedule`].
+    pub fn systems(&self) -> &[BoxedSystem] {
+        &self.systems
+    }
+
+    /// Returns the indices of the systems in the order they run.
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Returns the name of the system at `index`.
+    pub fn system_name(&self, index: usize) -> Cow<'static, str> {
+        self.systems[index].name()
+    }
+}
+
+/// An error preventing a [`Schedule`] from being built.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScheduleBuildError {
+    /// A set contains itself, directly or through the listed sets.
+    HierarchyCycle(Vec<String>),
+    /// The listed systems must each run before the next one, and the last before the first.
+    DependencyCycle(Vec<String>),
+    /// The listed pairs of systems have conflicting access but no order between them, and
+    /// ambiguities are [errors](LogLevel::Error).
+    Ambiguity(Vec<String>),
+}
+
+impl Display for ScheduleBuildError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleBuildError::HierarchyCycle(sets) => {
+                write!(f, "sets contain themselves: {}", sets.join(" > "))
+            }
+            ScheduleBuildError::DependencyCycle(systems) => {
+                write!(
+                    f,
+                    "systems depend on themselves: {} -> {}",
+                    systems.join(" -> "),
+                    systems[0]
+                )
+            }
+            ScheduleBuildError::Ambiguity(ambiguities) => {
+                write!(
+                    f,
+                    "systems with conflicting access have no order between them: {}",
+                    ambiguities.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScheduleBuildError {}
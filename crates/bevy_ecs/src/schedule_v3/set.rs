@@ -0,0 +1,80 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+pub use bevy_ecs_macros::SystemSet;
+use bevy_utils::define_label;
+
+use crate::system::{
+    ExclusiveSystemParam, ExclusiveSystemParamFunction, IsExclusiveFunctionSystem, SystemParam,
+    SystemParamFunction,
+};
+
+define_label!(
+    /// A strongly-typed class of labels used to identify sets of systems in a
+    /// [`Schedule`](super::Schedule).
+    ///
+    /// Sets can contain systems and other sets, can be ordered relative to each other and can
+    /// have [run conditions](super::Condition) gating all the systems they contain.
+    SystemSet,
+    /// Strongly-typed identifier for a [`SystemSet`].
+    SystemSetId,
+);
+
+/// A [`SystemSet`] grouping all the instances of a system function, generated on the basis of
+/// its `TypeId`.
+///
+/// Every system function added to a [`Schedule`](super::Schedule) is part of its type set, which
+/// allows ordering other systems relative to it by passing the function itself to
+/// [`before`](super::IntoSystemConfig::before) or [`after`](super::IntoSystemConfig::after).
+pub struct SystemTypeSet<T: 'static>(PhantomData<fn() -> T>);
+
+impl<T: 'static> SystemTypeSet<T> {
+    pub(crate) fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: 'static> SystemSet for SystemTypeSet<T> {
+    #[inline]
+    fn as_str(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+}
+
+impl<T> Debug for SystemTypeSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SystemTypeSet")
+            .field(&std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+/// Used to implicitly convert system functions to their [`SystemTypeSet`], so that they can be
+/// used wherever a [`SystemSet`] is expected.
+pub trait AsSystemSet<Marker> {
+    fn as_system_set(&self) -> SystemSetId;
+}
+
+impl<In, Out, Param: SystemParam, Marker, T: SystemParamFunction<In, Out, Param, Marker>>
+    AsSystemSet<(In, Out, Param, Marker)> for T
+{
+    #[inline]
+    fn as_system_set(&self) -> SystemSetId {
+        SystemTypeSet::<T>::new().as_label()
+    }
+}
+
+impl<Param: ExclusiveSystemParam, Marker, T: ExclusiveSystemParamFunction<Param, Marker>>
+    AsSystemSet<(Param, Marker, IsExclusiveFunctionSystem)> for T
+{
+    #[inline]
+    fn as_system_set(&self) -> SystemSetId {
+        SystemTypeSet::<T>::new().as_label()
+    }
+}
+
+impl<T: SystemSet> AsSystemSet<()> for T {
+    #[inline]
+    fn as_system_set(&self) -> SystemSetId {
+        self.as_label()
+    }
+}
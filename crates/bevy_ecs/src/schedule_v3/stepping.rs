@@ -0,0 +1,441 @@
+use std::borrow::Cow;
+
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    schedule_v3::{
+        executor::{apply_buffers, should_run},
+        SystemExecutor, SystemSchedule,
+    },
+    system::Resource,
+    world::World,
+};
+
+use crate as bevy_ecs;
+
+/// What the schedules using a [`SteppingExecutor`] do the next time they run, while
+/// [`Stepping`] is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepAction {
+    /// Run no system.
+    Wait,
+    /// Run the next system, then wait.
+    System,
+    /// Run the remaining systems of the current schedule, then wait.
+    Schedule,
+    /// Run the remaining systems of every schedule until the end of the frame, then wait.
+    Frame,
+}
+
+/// The position of a schedule using a [`SteppingExecutor`] in the current frame.
+#[derive(Debug, Clone, Default)]
+struct StepCursor {
+    /// The name of the next system to run, if the schedule is not finished.
+    next_system: Option<Cow<'static, str>>,
+    /// Whether all the systems of the schedule ran during the current frame.
+    finished: bool,
+}
+
+/// Controls the schedules using a [`SteppingExecutor`] and the
+/// [steppable](crate::schedule::SystemStage::set_steppable) stages, to run them one system at a
+/// time.
+///
+/// While stepping is enabled, these schedules only run systems when an action is requested,
+/// starting where they stopped. The [`World`] can be inspected or modified between steps, since
+/// the schedules return after each step. A frame ends once every stepping schedule ran all its
+/// systems, and the next step then starts the first schedule from its first system again.
+///
+/// When stepping is disabled, the schedules finish their current frame and then run normally.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule_v3::{IntoSystemConfigs, Schedule, SteppingExecutor, Stepping};
+/// #[derive(Resource, Default)]
+/// struct Score(u32);
+///
+/// fn add_one(mut score: ResMut<Score>) {
+///     score.0 += 1;
+/// }
+///
+/// fn double(mut score: ResMut<Score>) {
+///     score.0 *= 2;
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Score>();
+/// let mut stepping = Stepping::default();
+/// stepping.enable();
+/// world.insert_resource(stepping);
+///
+/// let mut schedule = Schedule::with_executor(Box::new(SteppingExecutor::default()));
+/// schedule.add_systems((add_one, double).chain());
+///
+/// // Nothing runs until a step is requested.
+/// schedule.run(&mut world);
+/// assert_eq!(world.resource::<Score>().0, 0);
+///
+/// world.resource_mut::<Stepping>().step_system();
+/// schedule.run(&mut world);
+/// assert_eq!(world.resource::<Score>().0, 1);
+/// assert!(world.resource::<Stepping>().next_systems().any(|name| name.ends_with("double")));
+///
+/// world.resource_mut::<Stepping>().step_system();
+/// schedule.run(&mut world);
+/// assert_eq!(world.resource::<Score>().0, 2);
+/// ```
+#[derive(Resource, Debug)]
+pub struct Stepping {
+    enabled: bool,
+    action: StepAction,
+    /// The cursor of every schedule or stage that ran while stepping since this resource was
+    /// inserted, in the order they first ran.
+    cursors: Vec<StepCursor>,
+}
+
+impl Default for Stepping {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            action: StepAction::Wait,
+            cursors: Vec::new(),
+        }
+    }
+}
+
+impl Stepping {
+    /// Pauses the stepping schedules, which then only run when an action is requested.
+    pub fn enable(&mut self) {
+        self.enabled = true;
+        self.action = StepAction::Wait;
+    }
+
+    /// Resumes running the stepping schedules normally, after they finished their current frame.
+    pub fn disable(&mut self) {
+        self.enabled = false;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the action the stepping schedules will take the next time they run.
+    pub fn action(&self) -> StepAction {
+        self.action
+    }
+
+    /// Requests the given action, replacing any action that was not taken yet.
+    pub fn set_action(&mut self, action: StepAction) {
+        self.action = action;
+    }
+
+    /// Runs the next system, stepping into the current schedule.
+    pub fn step_system(&mut self) {
+        self.set_action(StepAction::System);
+    }
+
+    /// Runs the remaining systems of the current schedule, stepping over it.
+    pub fn step_schedule(&mut self) {
+        self.set_action(StepAction::Schedule);
+    }
+
+    /// Runs the remaining systems until the end of the frame.
+    pub fn step_frame(&mut self) {
+        self.set_action(StepAction::Frame);
+    }
+
+    /// Returns the names of the systems each stepping schedule will run next, in the order the
+    /// schedules first ran. Schedules that finished the current frame are skipped.
+    pub fn next_systems(&self) -> impl Iterator<Item = &str> {
+        self.cursors
+            .iter()
+            .filter_map(|cursor| cursor.next_system.as_deref())
+    }
+
+    /// Returns the cursor of the schedule with `id`, registering the schedule if needed.
+    fn cursor_id(&mut self, id: Option<usize>) -> usize {
+        match id {
+            Some(id) if id < self.cursors.len() => id,
+            _ => {
+                self.cursors.push(StepCursor::default());
+                self.cursors.len() - 1
+            }
+        }
+    }
+
+    /// Starts a new frame if every stepping schedule finished the current one.
+    fn begin_frame_if_finished(&mut self) {
+        if self.cursors.iter().all(|cursor| cursor.finished) {
+            for cursor in &mut self.cursors {
+                cursor.finished = false;
+            }
+            if self.action == StepAction::Frame {
+                self.action = StepAction::Wait;
+            }
+        }
+    }
+}
+
+/// How many systems a stepping schedule or stage runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Budget {
+    Nothing,
+    OneSystem,
+    ToEnd,
+}
+
+/// The position of a schedule or stage in its frame, for [`Stepping`].
+#[derive(Debug, Default)]
+pub(crate) struct StepPosition {
+    /// The position of the next system to run.
+    pub(crate) next: usize,
+    /// The cursor of the schedule in the [`Stepping`] resource, once it ran while stepping.
+    cursor_id: Option<usize>,
+}
+
+impl StepPosition {
+    /// Returns how many of the `len` systems to run, updating the [`Stepping`] resource.
+    ///
+    /// The position goes back to the first system when a new frame starts.
+    pub(crate) fn budget(&mut self, len: usize, world: &mut World) -> Budget {
+        let mut stepping = match world.get_resource_mut::<Stepping>() {
+            Some(stepping) if stepping.enabled => stepping,
+            _ => {
+                self.restart_if_finished(len);
+                return Budget::ToEnd;
+            }
+        };
+
+        let id = stepping.cursor_id(self.cursor_id);
+        self.cursor_id = Some(id);
+        stepping.begin_frame_if_finished();
+        if !stepping.cursors[id].finished {
+            self.restart_if_finished(len);
+        }
+
+        let has_systems = self.next < len;
+        match stepping.action {
+            StepAction::Wait => Budget::Nothing,
+            StepAction::System if has_systems => {
+                stepping.action = StepAction::Wait;
+                Budget::OneSystem
+            }
+            StepAction::Schedule if has_systems => {
+                stepping.action = StepAction::Wait;
+                Budget::ToEnd
+            }
+            // The action is left for the other schedules.
+            StepAction::System | StepAction::Schedule => Budget::Nothing,
+            StepAction::Frame => Budget::ToEnd,
+        }
+    }
+
+    /// Records where the schedule stopped in the [`Stepping`] resource.
+    pub(crate) fn update_cursor(
+        &self,
+        len: usize,
+        next_system: Option<Cow<'static, str>>,
+        world: &mut World,
+    ) {
+        if let (Some(id), Some(mut stepping)) =
+            (self.cursor_id, world.get_resource_mut::<Stepping>())
+        {
+            if let Some(cursor) = stepping.cursors.get_mut(id) {
+                cursor.finished = self.next >= len;
+                cursor.next_system = next_system;
+            }
+        }
+    }
+
+    fn restart_if_finished(&mut self, len: usize) {
+        if self.next >= len {
+            self.next = 0;
+        }
+    }
+}
+
+/// Runs the systems of a [`SystemSchedule`] on the current thread, like a
+/// [`SingleThreadedExecutor`](super::SingleThreadedExecutor), but can be paused between any two
+/// systems and stepped through with the [`Stepping`] resource.
+///
+/// Buffers are applied at [`apply_system_buffers`](super::apply_system_buffers) and at the end of
+/// each frame of the schedule, not after each step.
+#[derive(Debug, Default)]
+pub struct SteppingExecutor {
+    /// The position in the order of the schedule of the next system to run.
+    position: StepPosition,
+    /// Sets whose conditions were already evaluated during this frame.
+    evaluated_sets: FixedBitSet,
+    /// Sets whose conditions were all `true` during this frame.
+    running_sets: FixedBitSet,
+    /// Systems that ran since buffers were last applied.
+    unapplied_systems: Vec<usize>,
+}
+
+impl SystemExecutor for SteppingExecutor {
+    fn init(&mut self, schedule: &SystemSchedule) {
+        let set_count = schedule.set_conditions.len();
+        self.position.next = 0;
+        self.evaluated_sets = FixedBitSet::with_capacity(set_count);
+        self.running_sets = FixedBitSet::with_capacity(set_count);
+        self.unapplied_systems = Vec::with_capacity(schedule.systems.len());
+    }
+
+    fn run(&mut self, schedule: &mut SystemSchedule, world: &mut World) {
+        let len = schedule.order.len();
+        let budget = self.position.budget(len, world);
+        if self.position.next == 0 {
+            self.evaluated_sets.clear();
+            self.running_sets.clear();
+        }
+
+        if budget != Budget::Nothing {
+            while self.position.next < len {
+                let system_index = schedule.order[self.position.next];
+                self.position.next += 1;
+                if !should_run(
+                    schedule,
+                    system_index,
+                    &mut self.evaluated_sets,
+                    &mut self.running_sets,
+                    world,
+                ) {
+                    continue;
+                }
+
+                if schedule.apply_buffers[system_index] {
+                    apply_buffers(&mut self.unapplied_systems, schedule, world);
+                } else {
+                    let system = &mut schedule.systems[system_index];
+                    #[cfg(feature = "trace")]
+                    let _system_span =
+                        bevy_utils::tracing::info_span!("system", name = &*system.name()).entered();
+                    system.run((), world);
+                    self.unapplied_systems.push(system_index);
+                }
+
+                if budget == Budget::OneSystem {
+                    break;
+                }
+            }
+
+            if self.position.next >= len {
+                apply_buffers(&mut self.unapplied_systems, schedule, world);
+            }
+        }
+
+        let next_system = schedule
+            .order
+            .get(self.position.next)
+            .map(|&index| schedule.systems[index].name());
+        self.position.update_cursor(len, next_system, world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        schedule_v3::{
+            IntoSystemConfig, IntoSystemConfigs, Schedule, StepAction, Stepping, SteppingExecutor,
+        },
+        system::{ResMut, Resource},
+        world::World,
+    };
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<u32>);
+
+    fn push(value: u32) -> impl FnMut(ResMut<Order>) {
+        move |mut order: ResMut<Order>| order.0.push(value)
+    }
+
+    fn stepping_schedule(values: [u32; 3]) -> Schedule {
+        let mut schedule = Schedule::with_executor(Box::new(SteppingExecutor::default()));
+        schedule.add_systems((push(values[0]), push(values[1]), push(values[2])).chain());
+        schedule
+    }
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let mut stepping = Stepping::default();
+        stepping.enable();
+        world.insert_resource(stepping);
+        world
+    }
+
+    fn step(world: &mut World, action: StepAction, schedules: &mut [&mut Schedule]) -> Vec<u32> {
+        world.resource_mut::<Stepping>().set_action(action);
+        for schedule in schedules {
+            schedule.run(world);
+        }
+        std::mem::take(&mut world.resource_mut::<Order>().0)
+    }
+
+    #[test]
+    fn runs_normally_without_stepping() {
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let mut schedule = stepping_schedule([0, 1, 2]);
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Order>().0, vec![0, 1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn step_systems_across_frames() {
+        let mut world = world();
+        let mut schedule = stepping_schedule([0, 1, 2]);
+        let schedules = &mut [&mut schedule];
+
+        assert!(step(&mut world, StepAction::Wait, schedules).is_empty());
+        assert_eq!(step(&mut world, StepAction::System, schedules), vec![0]);
+        assert!(step(&mut world, StepAction::Wait, schedules).is_empty());
+        assert_eq!(step(&mut world, StepAction::System, schedules), vec![1]);
+        assert_eq!(step(&mut world, StepAction::Frame, schedules), vec![2]);
+        // The frame is over, so stepping waits again.
+        assert!(step(&mut world, StepAction::Wait, schedules).is_empty());
+        assert_eq!(world.resource::<Stepping>().action(), StepAction::Wait);
+        assert_eq!(step(&mut world, StepAction::System, schedules), vec![0]);
+    }
+
+    #[test]
+    fn step_over_and_into_schedules() {
+        let mut world = world();
+        let mut first = stepping_schedule([0, 1, 2]);
+        let mut second = stepping_schedule([10, 11, 12]);
+        let schedules = &mut [&mut first, &mut second];
+
+        assert_eq!(step(&mut world, StepAction::System, schedules), vec![0]);
+        assert_eq!(
+            step(&mut world, StepAction::Schedule, schedules),
+            vec![1, 2]
+        );
+        assert_eq!(step(&mut world, StepAction::System, schedules), vec![10]);
+        assert_eq!(
+            world.resource::<Stepping>().next_systems().count(),
+            1,
+            "only the second schedule has systems left"
+        );
+        assert_eq!(
+            step(&mut world, StepAction::Schedule, schedules),
+            vec![11, 12]
+        );
+        // Every schedule finished, so the next step starts a new frame.
+        assert_eq!(step(&mut world, StepAction::System, schedules), vec![0]);
+    }
+
+    #[test]
+    fn disabling_finishes_the_frame() {
+        let mut world = world();
+        let mut schedule = stepping_schedule([0, 1, 2]);
+        schedule.add_system(push(3).run_if(|| false));
+        let schedules = &mut [&mut schedule];
+
+        assert_eq!(step(&mut world, StepAction::System, schedules), vec![0]);
+        world.resource_mut::<Stepping>().disable();
+        assert_eq!(step(&mut world, StepAction::Wait, schedules), vec![1, 2]);
+        assert_eq!(step(&mut world, StepAction::Wait, schedules), vec![0, 1, 2]);
+    }
+}
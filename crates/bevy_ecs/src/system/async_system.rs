@@ -0,0 +1,573 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    ptr,
+    sync::{
+        atomic::{AtomicPtr, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll},
+    thread::{self, ThreadId},
+};
+
+use bevy_utils::{futures::noop_waker, synccell::SyncCell, Duration};
+
+use crate as bevy_ecs;
+use crate::{
+    event::{Event, Events},
+    system::Resource,
+    world::World,
+};
+
+type BoxedAsyncSystem = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// The world lent to the async systems while they are polled.
+#[derive(Default)]
+struct WorldSlot {
+    world: AtomicPtr<World>,
+    /// The thread polling the async systems, which is the only one allowed to use the world.
+    thread: Mutex<Option<ThreadId>>,
+    /// Incremented each time the world is lent, so that a borrow from a previous poll can not
+    /// give the world back.
+    generation: AtomicUsize,
+    /// The time elapsed on the clock of the async systems, see [`AsyncSystems::advance`].
+    elapsed: Mutex<Duration>,
+}
+
+/// The async systems of a [`World`], written as futures and polled once per frame by
+/// [`AsyncSystems::poll_system`].
+///
+/// An async system is spawned from a closure receiving an [`AsyncWorld`], and may span many
+/// frames: it can `await` the next frame, a delay, an event, a condition on the world or any
+/// other future, such as a [`Task`](bevy_tasks::Task) running on one of the `bevy_tasks` pools.
+/// Between two awaits, it accesses the world with [`AsyncWorld::with`].
+///
+/// Delays are measured with a clock advanced by [`AsyncSystems::advance`], which `bevy_time`
+/// calls every frame with the delta of its `Time` resource, so that they follow its pause and
+/// relative speed.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::{AsyncSystems, AsyncWorld};
+/// # use bevy_utils::Duration;
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// let mut world = World::new();
+/// world.init_resource::<AsyncSystems>();
+/// let mut stage = SystemStage::single(AsyncSystems::poll_system);
+///
+/// world
+///     .resource_mut::<AsyncSystems>()
+///     .spawn(|world: AsyncWorld| async move {
+///         world.sleep(Duration::from_millis(10)).await;
+///         for _ in 0..3 {
+///             world.with(|world| {
+///                 world.spawn(Enemy);
+///             });
+///             world.next_frame().await;
+///         }
+///     });
+///
+/// while !world.resource::<AsyncSystems>().is_empty() {
+///     world
+///         .resource_mut::<AsyncSystems>()
+///         .advance(Duration::from_millis(5));
+///     stage.run(&mut world);
+/// }
+/// assert_eq!(world.query::<&Enemy>().iter(&world).count(), 3);
+/// ```
+#[derive(Resource, Default)]
+pub struct AsyncSystems {
+    systems: Vec<SyncCell<BoxedAsyncSystem>>,
+    world: Arc<WorldSlot>,
+}
+
+impl std::fmt::Debug for AsyncSystems {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncSystems")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl AsyncSystems {
+    /// Spawns the async system returned by `system`, and returns a handle to its output.
+    ///
+    /// The system is first polled the next time [`AsyncSystems::poll_system`] runs. It keeps
+    /// running if the returned [`AsyncTask`] is dropped.
+    pub fn spawn<S, Fut>(&mut self, system: S) -> AsyncTask<Fut::Output>
+    where
+        S: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        let future = system(AsyncWorld {
+            world: self.world.clone(),
+        });
+        let state = Arc::new(Mutex::new(TaskState::Running));
+        let task = AsyncTask {
+            state: state.clone(),
+        };
+        self.systems.push(SyncCell::new(Box::pin(async move {
+            let output = future.await;
+            *state.lock().unwrap() = TaskState::Finished(output);
+        })));
+        task
+    }
+
+    /// Returns the number of async systems that did not finish yet.
+    pub fn len(&self) -> usize {
+        self.systems.len()
+    }
+
+    /// Returns `true` if every async system finished.
+    pub fn is_empty(&self) -> bool {
+        self.systems.is_empty()
+    }
+
+    /// Returns the time elapsed on the clock used by [`AsyncWorld::sleep`].
+    pub fn elapsed(&self) -> Duration {
+        *self.world.elapsed.lock().unwrap()
+    }
+
+    /// Advances the clock used by [`AsyncWorld::sleep`] by `delta`.
+    pub fn advance(&mut self, delta: Duration) {
+        *self.world.elapsed.lock().unwrap() += delta;
+    }
+
+    /// Exclusive system polling each async system of the [`AsyncSystems`] resource once.
+    ///
+    /// It should run once per frame; `bevy_app` adds it to the update stage with
+    /// `App::add_async_system`.
+    pub fn poll_system(world: &mut World) {
+        let (slot, mut systems) = {
+            let mut async_systems = world.resource_mut::<AsyncSystems>();
+            let systems = std::mem::take(&mut async_systems.systems);
+            (async_systems.world.clone(), systems)
+        };
+
+        // Takes the world back from the async systems, even if one of them panics.
+        struct Lend<'a>(&'a WorldSlot);
+        impl Drop for Lend<'_> {
+            fn drop(&mut self) {
+                self.0.world.store(ptr::null_mut(), Ordering::Release);
+                *self.0.thread.lock().unwrap() = None;
+            }
+        }
+        let lend = Lend(&slot);
+        slot.generation.fetch_add(1, Ordering::AcqRel);
+        *slot.thread.lock().unwrap() = Some(thread::current().id());
+        slot.world.store(world, Ordering::Release);
+        let waker = noop_waker();
+        let mut context = Context::from_waker(&waker);
+        systems.retain_mut(|system| system.get().as_mut().poll(&mut context).is_pending());
+        drop(lend);
+
+        // Systems spawned while polling run after the others.
+        match world.get_resource_mut::<AsyncSystems>() {
+            Some(mut async_systems) => {
+                let spawned = std::mem::replace(&mut async_systems.systems, systems);
+                async_systems.systems.extend(spawned);
+            }
+            // An async system removed the resource.
+            None => world.insert_resource(AsyncSystems {
+                systems,
+                world: slot,
+            }),
+        }
+    }
+}
+
+/// The world of an async system spawned with [`AsyncSystems::spawn`].
+///
+/// The world can only be accessed while the async system is polled by
+/// [`AsyncSystems::poll_system`], and its borrow can not be held across awaits.
+#[derive(Clone)]
+pub struct AsyncWorld {
+    world: Arc<WorldSlot>,
+}
+
+impl std::fmt::Debug for AsyncWorld {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncWorld").finish_non_exhaustive()
+    }
+}
+
+impl AsyncWorld {
+    /// Runs `f` with exclusive access to the world.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the async system is not being polled by [`AsyncSystems::poll_system`], if
+    /// called from another thread than the one polling it, such as from a clone of this
+    /// [`AsyncWorld`] moved to another thread, or if called from inside `f`.
+    pub fn with<R>(&self, f: impl FnOnce(&mut World) -> R) -> R {
+        let slot = &*self.world;
+        assert!(
+            *slot.thread.lock().unwrap() == Some(thread::current().id()),
+            "The world of an async system can only be accessed while it is polled by `AsyncSystems::poll_system`, on the polling thread"
+        );
+        let generation = slot.generation.load(Ordering::Acquire);
+        let world = slot.world.swap(ptr::null_mut(), Ordering::AcqRel);
+        assert!(
+            !world.is_null(),
+            "The world of an async system can not be accessed while it is already borrowed"
+        );
+
+        // Gives the world back to the other calls, even if `f` panics, unless the poll that lent
+        // it is over.
+        struct Restore<'a> {
+            slot: &'a WorldSlot,
+            world: *mut World,
+            generation: usize,
+        }
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                if self.slot.generation.load(Ordering::Acquire) == self.generation {
+                    let _ = self.slot.world.compare_exchange(
+                        ptr::null_mut(),
+                        self.world,
+                        Ordering::AcqRel,
+                        Ordering::Acquire,
+                    );
+                }
+            }
+        }
+        let _restore = Restore {
+            slot,
+            world,
+            generation,
+        };
+        // SAFETY: the pointer was lent by `AsyncSystems::poll_system` from a `&mut World` that is
+        // not used until it is taken back, on this thread, and is removed from the slot while `f`
+        // runs.
+        f(unsafe { &mut *world })
+    }
+
+    /// Waits until the next frame, when the async system is polled again.
+    pub async fn next_frame(&self) {
+        NextFrame { polled: false }.await;
+    }
+
+    /// Waits for `count` frames.
+    pub async fn frames(&self, count: usize) {
+        for _ in 0..count {
+            self.next_frame().await;
+        }
+    }
+
+    /// Returns the time elapsed on the clock of the async systems.
+    ///
+    /// See [`AsyncSystems::advance`].
+    pub fn elapsed(&self) -> Duration {
+        *self.world.elapsed.lock().unwrap()
+    }
+
+    /// Waits until the clock of the async systems advanced by at least `duration`, checking once
+    /// per frame.
+    ///
+    /// See [`AsyncSystems::advance`].
+    pub async fn sleep(&self, duration: Duration) {
+        let end = self.elapsed() + duration;
+        while self.elapsed() < end {
+            self.next_frame().await;
+        }
+    }
+
+    /// Waits until `f` returns `Some`, checking once per frame starting with the current one,
+    /// and returns its value.
+    pub async fn wait_for<R>(&self, mut f: impl FnMut(&mut World) -> Option<R>) -> R {
+        loop {
+            if let Some(value) = self.with(&mut f) {
+                return value;
+            }
+            self.next_frame().await;
+        }
+    }
+
+    /// Waits until `condition` returns `true`, checking once per frame starting with the current
+    /// one.
+    pub async fn wait_until(&self, mut condition: impl FnMut(&mut World) -> bool) {
+        self.wait_for(|world| condition(world).then_some(())).await;
+    }
+
+    /// Waits for the next event of type `E` sent after this call, and returns a clone of it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Events<E>`] resource does not exist.
+    pub async fn next_event<E: Event + Clone>(&self) -> E {
+        let mut reader = self.with(|world| world.resource::<Events<E>>().get_reader_current());
+        self.wait_for(|world| reader.iter(world.resource::<Events<E>>()).next().cloned())
+            .await
+    }
+
+    /// Spawns another async system, and returns a handle to its output.
+    ///
+    /// See [`AsyncSystems::spawn`].
+    pub fn spawn<S, Fut>(&self, system: S) -> AsyncTask<Fut::Output>
+    where
+        S: FnOnce(AsyncWorld) -> Fut,
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        self.with(|world| world.resource_mut::<AsyncSystems>().spawn(system))
+    }
+}
+
+struct NextFrame {
+    polled: bool,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.polled {
+            Poll::Ready(())
+        } else {
+            self.polled = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+enum TaskState<T> {
+    Running,
+    Finished(T),
+    Taken,
+}
+
+/// A handle to the output of an async system spawned with [`AsyncSystems::spawn`].
+///
+/// It can be checked from regular systems with [`AsyncTask::take`], or awaited by other async
+/// systems.
+pub struct AsyncTask<T> {
+    state: Arc<Mutex<TaskState<T>>>,
+}
+
+impl<T> std::fmt::Debug for AsyncTask<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AsyncTask")
+            .field("finished", &self.is_finished())
+            .finish()
+    }
+}
+
+impl<T> AsyncTask<T> {
+    /// Returns `true` if the async system finished.
+    pub fn is_finished(&self) -> bool {
+        !matches!(*self.state.lock().unwrap(), TaskState::Running)
+    }
+
+    /// Returns the output of the async system if it finished, and was not taken yet.
+    pub fn take(&mut self) -> Option<T> {
+        let mut state = self.state.lock().unwrap();
+        match std::mem::replace(&mut *state, TaskState::Taken) {
+            TaskState::Finished(output) => Some(output),
+            previous => {
+                *state = previous;
+                None
+            }
+        }
+    }
+}
+
+impl<T> Future for AsyncTask<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<T> {
+        match self.take() {
+            Some(output) => Poll::Ready(output),
+            None if self.is_finished() => panic!("`AsyncTask` polled after its output was taken"),
+            None => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsyncSystems, AsyncWorld};
+    use crate as bevy_ecs;
+    use crate::{
+        event::Events,
+        schedule::{Stage, SystemStage},
+        system::{ResMut, Resource},
+        world::World,
+    };
+    use bevy_tasks::TaskPool;
+    use bevy_utils::Duration;
+
+    #[derive(Resource, Default, Debug, PartialEq)]
+    struct Log(Vec<&'static str>);
+
+    fn world() -> (World, SystemStage) {
+        let mut world = World::new();
+        world.init_resource::<AsyncSystems>();
+        world.init_resource::<Log>();
+        world.init_resource::<Events<u32>>();
+        let stage = SystemStage::single(AsyncSystems::poll_system);
+        (world, stage)
+    }
+
+    fn log(world: &AsyncWorld, message: &'static str) {
+        world.with(|world| world.resource_mut::<Log>().0.push(message));
+    }
+
+    #[test]
+    fn frames() {
+        let (mut world, mut stage) = world();
+        let mut task = world
+            .resource_mut::<AsyncSystems>()
+            .spawn(|world: AsyncWorld| async move {
+                log(&world, "a");
+                world.next_frame().await;
+                log(&world, "b");
+                world.frames(2).await;
+                log(&world, "c");
+                7
+            });
+
+        stage.run(&mut world);
+        assert_eq!(world.resource::<Log>().0, ["a"]);
+        stage.run(&mut world);
+        stage.run(&mut world);
+        assert_eq!(world.resource::<Log>().0, ["a", "b"]);
+        assert!(!task.is_finished());
+        assert_eq!(task.take(), None);
+        stage.run(&mut world);
+        assert_eq!(world.resource::<Log>().0, ["a", "b", "c"]);
+        assert!(world.resource::<AsyncSystems>().is_empty());
+        assert_eq!(task.take(), Some(7));
+        assert_eq!(task.take(), None);
+        assert!(task.is_finished());
+    }
+
+    #[test]
+    fn sleep() {
+        let (mut world, mut stage) = world();
+        world
+            .resource_mut::<AsyncSystems>()
+            .spawn(|world: AsyncWorld| async move {
+                world.sleep(Duration::from_millis(10)).await;
+                log(&world, "awake");
+            });
+
+        stage.run(&mut world);
+        world
+            .resource_mut::<AsyncSystems>()
+            .advance(Duration::from_millis(6));
+        stage.run(&mut world);
+        assert!(world.resource::<Log>().0.is_empty());
+        world
+            .resource_mut::<AsyncSystems>()
+            .advance(Duration::from_millis(6));
+        stage.run(&mut world);
+        assert_eq!(world.resource::<Log>().0, ["awake"]);
+        assert_eq!(
+            world.resource::<AsyncSystems>().elapsed(),
+            Duration::from_millis(12)
+        );
+    }
+
+    #[test]
+    fn removed_resource_is_restored() {
+        let (mut world, mut stage) = world();
+        world
+            .resource_mut::<AsyncSystems>()
+            .spawn(|world: AsyncWorld| async move {
+                world.with(|world| world.remove_resource::<AsyncSystems>());
+                world.next_frame().await;
+                log(&world, "restored");
+            });
+
+        stage.run(&mut world);
+        assert_eq!(world.resource::<AsyncSystems>().len(), 1);
+        stage.run(&mut world);
+        assert_eq!(world.resource::<Log>().0, ["restored"]);
+    }
+
+    #[test]
+    fn events_and_tasks() {
+        let (mut world, mut stage) = world();
+        world
+            .resource_mut::<AsyncSystems>()
+            .spawn(|world: AsyncWorld| async move {
+                let event = world.next_event::<u32>().await;
+                let doubled = world.spawn(move |_| async move { event * 2 }).await;
+                world.with(|world| world.send_event(doubled));
+            });
+
+        stage.run(&mut world);
+        world.send_event(3u32);
+        stage.run(&mut world);
+        stage.run(&mut world);
+        stage.run(&mut world);
+        let events = world.resource::<Events<u32>>();
+        let mut reader = events.get_reader();
+        assert_eq!(reader.iter(events).copied().collect::<Vec<_>>(), [3, 6]);
+    }
+
+    #[test]
+    fn other_threads_can_not_access_the_world() {
+        let (mut world, mut stage) = world();
+        world
+            .resource_mut::<AsyncSystems>()
+            .spawn(|world: AsyncWorld| async move {
+                let clone = world.clone();
+                let result = std::thread::spawn(move || clone.with(|_| ())).join();
+                assert!(result.is_err());
+                log(&world, "polling thread");
+            });
+
+        stage.run(&mut world);
+        assert_eq!(world.resource::<Log>().0, ["polling thread"]);
+    }
+
+    #[test]
+    fn regular_systems_spawn_async_systems() {
+        let (mut world, mut stage) = world();
+        let task_pool = TaskPool::new();
+        let task = task_pool.spawn(async { "computed" });
+        world.insert_resource(PendingTask(Some(task)));
+
+        #[derive(Resource)]
+        struct PendingTask(Option<bevy_tasks::Task<&'static str>>);
+
+        fn spawn_wait(mut async_systems: ResMut<AsyncSystems>, mut pending: ResMut<PendingTask>) {
+            if let Some(task) = pending.0.take() {
+                async_systems.spawn(|world: AsyncWorld| async move {
+                    let message = task.await;
+                    log(&world, message);
+                });
+            }
+        }
+        SystemStage::single(spawn_wait).run(&mut world);
+
+        for _ in 0..1000 {
+            if world.resource::<AsyncSystems>().is_empty() {
+                break;
+            }
+            stage.run(&mut world);
+            std::thread::sleep(std::time::Duration::from_millis(1));
+        }
+        assert_eq!(world.resource::<Log>().0, ["computed"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn world_outside_of_poll() {
+        let (mut world, _) = world();
+        let mut slot = None;
+        world
+            .resource_mut::<AsyncSystems>()
+            .spawn(|world: AsyncWorld| {
+                slot = Some(world);
+                async {}
+            });
+        slot.unwrap().with(|_| ());
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::{Debug, Display};
+
+use bevy_utils::tracing::warn;
+
+use crate as bevy_ecs;
+use crate::{entity::Entity, system::Resource, world::World};
+
+use super::Command;
+
+/// An error returned by a [`FallibleCommand`].
+#[derive(Debug)]
+pub enum CommandError {
+    /// The entity targeted by the command does not exist.
+    NoSuchEntity {
+        entity: Entity,
+        /// What the command tried to do, such as `despawn`.
+        action: String,
+    },
+    /// Any other error, returned by a custom command.
+    Other(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::NoSuchEntity { entity, action } => write!(
+                f,
+                "error[B0003]: Could not {action} entity {entity:?} because it doesn't exist in this World."
+            ),
+            CommandError::Other(error) => Display::fmt(error, f),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+/// What to do when a [`FallibleCommand`] fails.
+///
+/// A policy can be given to a single command with [`Commands::add_with_policy`](super::Commands::add_with_policy),
+/// or inserted as a resource to replace the [default policy](FallibleCommand::DEFAULT_ERROR_POLICY)
+/// of every command that was not given one.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::{CommandError, CommandErrorPolicy};
+/// fn count_errors(world: &mut World, error: CommandError) {
+///     world.get_resource_or_insert_with(|| ErrorCount(0)).0 += 1;
+/// }
+///
+/// #[derive(Resource)]
+/// struct ErrorCount(u32);
+///
+/// let mut world = World::new();
+/// world.insert_resource(CommandErrorPolicy::Handler(count_errors));
+/// ```
+#[derive(Resource, Debug, Clone, Copy)]
+pub enum CommandErrorPolicy {
+    /// Panics with the error.
+    Panic,
+    /// Logs the error as a warning.
+    Warn,
+    /// Silently discards the error.
+    Ignore,
+    /// Passes the error to a function, along with the [`World`].
+    Handler(fn(&mut World, CommandError)),
+}
+
+impl CommandErrorPolicy {
+    /// Handles `error` according to this policy.
+    pub fn handle(self, world: &mut World, error: CommandError) {
+        match self {
+            CommandErrorPolicy::Panic => panic!("{error}"),
+            CommandErrorPolicy::Warn => warn!("{error}"),
+            CommandErrorPolicy::Ignore => {}
+            CommandErrorPolicy::Handler(handler) => handler(world, error),
+        }
+    }
+}
+
+/// A [`World`] mutation that can fail.
+///
+/// Fallible commands are added with [`Commands::add_fallible`](super::Commands::add_fallible) or
+/// [`Commands::add_with_policy`](super::Commands::add_with_policy), and their errors are handled
+/// by a [`CommandErrorPolicy`].
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::system::{CommandError, CommandErrorPolicy, FallibleCommand};
+/// #[derive(Resource)]
+/// struct Gold(u32);
+///
+/// struct Spend(u32);
+///
+/// impl FallibleCommand for Spend {
+///     const DEFAULT_ERROR_POLICY: CommandErrorPolicy = CommandErrorPolicy::Warn;
+///
+///     fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+///         let mut gold = world.resource_mut::<Gold>();
+///         gold.0 = gold.0.checked_sub(self.0).ok_or_else(|| {
+///             CommandError::Other(format!("can not spend {} gold", self.0).into())
+///         })?;
+///         Ok(())
+///     }
+/// }
+///
+/// fn buy(mut commands: Commands) {
+///     commands.add_fallible(Spend(10));
+/// }
+/// # bevy_ecs::system::assert_is_system(buy);
+/// ```
+pub trait FallibleCommand: Send + 'static {
+    /// The policy used when neither the command nor the [`World`] specifies one.
+    const DEFAULT_ERROR_POLICY: CommandErrorPolicy = CommandErrorPolicy::Panic;
+
+    fn try_write(self, world: &mut World) -> Result<(), CommandError>;
+}
+
+/// Applies `command`, handling its error with `policy`, or else the [`CommandErrorPolicy`]
+/// resource, or else the default policy of the command.
+pub(crate) fn write_fallible<C: FallibleCommand>(
+    command: C,
+    world: &mut World,
+    policy: Option<CommandErrorPolicy>,
+) {
+    if let Err(error) = command.try_write(world) {
+        let policy = policy
+            .or_else(|| world.get_resource::<CommandErrorPolicy>().copied())
+            .unwrap_or(C::DEFAULT_ERROR_POLICY);
+        policy.handle(world, error);
+    }
+}
+
+/// [`Command`] applying a [`FallibleCommand`] with a given [`CommandErrorPolicy`].
+///
+/// See [`Commands::add_with_policy`](super::Commands::add_with_policy).
+#[derive(Debug)]
+pub struct WithErrorPolicy<C> {
+    pub command: C,
+    pub policy: Option<CommandErrorPolicy>,
+}
+
+impl<C: FallibleCommand> Command for WithErrorPolicy<C> {
+    fn write(self, world: &mut World) {
+        write_fallible(self.command, world, self.policy);
+    }
+}
@@ -1,17 +1,19 @@
 mod command_queue;
+mod fallible;
 mod parallel_scope;
 
 use crate::{
     bundle::Bundle,
     entity::{Entities, Entity},
-    world::{FromWorld, World},
+    world::{CloneEntityOptions, FromWorld, World},
 };
 use bevy_utils::tracing::{error, info};
 pub use command_queue::CommandQueue;
+pub use fallible::*;
 pub use parallel_scope::*;
 use std::marker::PhantomData;
 
-use super::Resource;
+use super::{Resource, RunSystem, SystemId};
 
 /// A [`World`] mutation.
 ///
@@ -536,6 +538,55 @@ impl<'w, 's> Commands<'w, 's> {
     pub fn add<C: Command>(&mut self, command: C) {
         self.queue.push(command);
     }
+
+    /// Pushes a [`Command`] to the queue running the system registered with `id`, see
+    /// [`World::run_system`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_ecs::{prelude::*, system::SystemId};
+    /// #[derive(Resource)]
+    /// struct OnClick(SystemId);
+    ///
+    /// fn button(mut commands: Commands, on_click: Res<OnClick>) {
+    ///     commands.run_system(on_click.0);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(button);
+    /// ```
+    pub fn run_system(&mut self, id: SystemId) {
+        self.add(RunSystem { system_id: id });
+    }
+
+    /// Pushes a [`FallibleCommand`] to the queue.
+    ///
+    /// If it fails, its error is handled by the [`CommandErrorPolicy`] resource if there is one,
+    /// or else by the [default policy](FallibleCommand::DEFAULT_ERROR_POLICY) of the command.
+    pub fn add_fallible<C: FallibleCommand>(&mut self, command: C) {
+        self.add(WithErrorPolicy {
+            command,
+            policy: None,
+        });
+    }
+
+    /// Pushes a [`FallibleCommand`] to the queue, handling its error with `policy`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::system::{CommandErrorPolicy, Despawn};
+    /// fn despawn_quietly(mut commands: Commands, query: Query<Entity>) {
+    ///     for entity in &query {
+    ///         commands.add_with_policy(Despawn { entity }, CommandErrorPolicy::Ignore);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(despawn_quietly);
+    /// ```
+    pub fn add_with_policy<C: FallibleCommand>(&mut self, command: C, policy: CommandErrorPolicy) {
+        self.add(WithErrorPolicy {
+            command,
+            policy: Some(policy),
+        });
+    }
 }
 
 /// A list of commands that will be run to modify an [entity](crate::entity).
@@ -569,7 +620,9 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
     ///
     /// # Panics
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// By default, the command will panic when applied if the associated entity does not exist.
+    /// This can be changed with a [`CommandErrorPolicy`] resource, or by using
+    /// [`try_insert`](Self::try_insert) instead.
     ///
     /// # Example
     ///
@@ -620,6 +673,21 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
         self
     }
 
+    /// Adds a [`Bundle`] of components to the entity, doing nothing if the entity does not exist
+    /// when the command is applied.
+    ///
+    /// See [`EntityCommands::insert`].
+    pub fn try_insert(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.commands.add_with_policy(
+            Insert {
+                entity: self.entity,
+                bundle,
+            },
+            CommandErrorPolicy::Ignore,
+        );
+        self
+    }
+
     #[deprecated(
         since = "0.9.0",
         note = "Use `insert` instead, which now accepts bundles, components, and tuples of bundles and components."
@@ -677,6 +745,24 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
         self
     }
 
+    /// Removes a [`Bundle`] of components from the entity, doing nothing if the entity does not
+    /// exist when the command is applied, even if a [`CommandErrorPolicy`] resource was inserted.
+    ///
+    /// See [`EntityCommands::remove`].
+    pub fn try_remove<T>(&mut self) -> &mut Self
+    where
+        T: Bundle,
+    {
+        self.commands.add_with_policy(
+            Remove::<T> {
+                entity: self.entity,
+                phantom: PhantomData,
+            },
+            CommandErrorPolicy::Ignore,
+        );
+        self
+    }
+
     #[deprecated(
         since = "0.9.0",
         note = "Use `remove` instead, which now accepts bundles, components, and tuples of bundles and components."
@@ -692,9 +778,11 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
     ///
     /// See [`World::despawn`] for more details.
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// The command will panic when applied if the associated entity does not exist.
+    /// By default, the command logs a warning when applied if the associated entity does not
+    /// exist. This can be changed with a [`CommandErrorPolicy`] resource, or by using
+    /// [`try_despawn`](Self::try_despawn) instead.
     ///
     /// # Example
     ///
@@ -719,6 +807,67 @@ impl<'w, 's, 'a> EntityCommands<'w, 's, 'a> {
         });
     }
 
+    /// Despawns the entity, doing nothing if it does not exist when the command is applied.
+    ///
+    /// See [`EntityCommands::despawn`].
+    pub fn try_despawn(&mut self) {
+        self.commands.add_with_policy(
+            Despawn {
+                entity: self.entity,
+            },
+            CommandErrorPolicy::Ignore,
+        );
+    }
+
+    /// Spawns a copy of the entity with clones of all its components, and returns the
+    /// [`EntityCommands`] of the copy.
+    ///
+    /// See [`World::clone_entity`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// The command panics when applied if the associated entity does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Resource)]
+    /// # struct Template { entity: Entity }
+    /// #
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// fn spawn_enemy_system(mut commands: Commands, template: Res<Template>) {
+    ///     commands.entity(template.entity).clone_entity().insert(Enemy);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(spawn_enemy_system);
+    /// ```
+    pub fn clone_entity(&mut self) -> EntityCommands<'w, 's, '_> {
+        self.clone_entity_with(CloneEntityOptions::default())
+    }
+
+    /// Spawns a copy of the entity with clones of the components allowed by `options`, and
+    /// returns the [`EntityCommands`] of the copy.
+    ///
+    /// See [`World::clone_entity_with`] for more details.
+    ///
+    /// # Errors
+    ///
+    /// The command panics when applied if the associated entity does not exist.
+    pub fn clone_entity_with(&mut self, options: CloneEntityOptions) -> EntityCommands<'w, 's, '_> {
+        let source = self.entity;
+        let mut clone = self.commands.spawn_empty();
+        let destination = clone.id();
+        clone.commands().add(CloneEntity {
+            source,
+            destination,
+            options,
+        });
+        clone
+    }
+
     /// Logs the components of the entity at the info level.
     ///
     /// # Panics
@@ -818,9 +967,59 @@ pub struct Despawn {
     pub entity: Entity,
 }
 
+impl FallibleCommand for Despawn {
+    const DEFAULT_ERROR_POLICY: CommandErrorPolicy = CommandErrorPolicy::Warn;
+
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        match world.get_entity_mut(self.entity) {
+            Some(entity) => {
+                entity.despawn();
+                Ok(())
+            }
+            None => Err(CommandError::NoSuchEntity {
+                entity: self.entity,
+                action: "despawn".to_string(),
+            }),
+        }
+    }
+}
+
 impl Command for Despawn {
     fn write(self, world: &mut World) {
-        world.despawn(self.entity);
+        write_fallible(self, world, None);
+    }
+}
+
+#[derive(Debug)]
+pub struct CloneEntity {
+    pub source: Entity,
+    pub destination: Entity,
+    pub options: CloneEntityOptions,
+}
+
+impl FallibleCommand for CloneEntity {
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        if !world.entities().contains(self.source) {
+            world.despawn(self.destination);
+            return Err(CommandError::NoSuchEntity {
+                entity: self.source,
+                action: "clone".to_string(),
+            });
+        }
+        if !world.entities().contains(self.destination) {
+            return Err(CommandError::NoSuchEntity {
+                entity: self.destination,
+                action: "clone components into".to_string(),
+            });
+        }
+        world.clone_components(self.source, self.destination, &self.options);
+        Ok(())
+    }
+}
+
+impl Command for CloneEntity {
+    fn write(self, world: &mut World) {
+        write_fallible(self, world, None);
     }
 }
 
@@ -829,16 +1028,33 @@ pub struct Insert<T> {
     pub bundle: T,
 }
 
+impl<T> FallibleCommand for Insert<T>
+where
+    T: Bundle + 'static,
+{
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        match world.get_entity_mut(self.entity) {
+            Some(mut entity) => {
+                entity.insert(self.bundle);
+                Ok(())
+            }
+            None => Err(CommandError::NoSuchEntity {
+                entity: self.entity,
+                action: format!(
+                    "insert a bundle (of type `{}`) for",
+                    std::any::type_name::<T>()
+                ),
+            }),
+        }
+    }
+}
+
 impl<T> Command for Insert<T>
 where
     T: Bundle + 'static,
 {
     fn write(self, world: &mut World) {
-        if let Some(mut entity) = world.get_entity_mut(self.entity) {
-            entity.insert(self.bundle);
-        } else {
-            panic!("error[B0003]: Could not insert a bundle (of type `{}`) for entity {:?} because it doesn't exist in this World.", std::any::type_name::<T>(), self.entity);
-        }
+        write_fallible(self, world, None);
     }
 }
 
@@ -848,16 +1064,37 @@ pub struct Remove<T> {
     pub phantom: PhantomData<T>,
 }
 
+impl<T> FallibleCommand for Remove<T>
+where
+    T: Bundle,
+{
+    const DEFAULT_ERROR_POLICY: CommandErrorPolicy = CommandErrorPolicy::Ignore;
+
+    fn try_write(self, world: &mut World) -> Result<(), CommandError> {
+        match world.get_entity_mut(self.entity) {
+            Some(mut entity_mut) => {
+                // remove intersection to gracefully handle components that were removed before running
+                // this command
+                entity_mut.remove_intersection::<T>();
+                Ok(())
+            }
+            None => Err(CommandError::NoSuchEntity {
+                entity: self.entity,
+                action: format!(
+                    "remove a bundle (of type `{}`) from",
+                    std::any::type_name::<T>()
+                ),
+            }),
+        }
+    }
+}
+
 impl<T> Command for Remove<T>
 where
     T: Bundle,
 {
     fn write(self, world: &mut World) {
-        if let Some(mut entity_mut) = world.get_entity_mut(self.entity) {
-            // remove intersection to gracefully handle components that were removed before running
-            // this command
-            entity_mut.remove_intersection::<T>();
-        }
+        write_fallible(self, world, None);
     }
 }
 
@@ -913,7 +1150,10 @@ mod tests {
     use crate::{
         self as bevy_ecs,
         component::Component,
-        system::{CommandQueue, Commands, Resource},
+        entity::Entity,
+        system::{
+            CommandError, CommandErrorPolicy, CommandQueue, Commands, Despawn, Insert, Resource,
+        },
         world::World,
     };
     use std::sync::{
@@ -1041,7 +1281,7 @@ mod tests {
             .iter(&world)
             .map(|v| v.0)
             .collect::<Vec<_>>();
-        assert_eq!(results_after_u64, vec![]);
+        assert_eq!(results_after_u64, Vec::<u64>::new());
     }
 
     #[test]
@@ -1067,4 +1307,103 @@ mod tests {
         assert!(!world.contains_resource::<W<i32>>());
         assert!(world.contains_resource::<W<f64>>());
     }
+
+    #[test]
+    fn try_variants_ignore_missing_entities() {
+        let mut world = World::default();
+        let entity = world.spawn_empty().id();
+        // The strictest policy: the `try_*` commands below must still ignore the missing entity.
+        world.insert_resource(CommandErrorPolicy::Panic);
+
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(entity).despawn();
+            commands
+                .entity(entity)
+                .try_insert(W(1u32))
+                .try_remove::<W<u32>>()
+                .try_despawn();
+        }
+        queue.apply(&mut world);
+        assert_eq!(world.entities().len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "error[B0003]")]
+    fn insert_on_missing_entity_panics_by_default() {
+        let mut world = World::default();
+        let entity = world.spawn_empty().id();
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(entity).despawn();
+        commands.entity(entity).insert(W(1u32));
+        queue.apply(&mut world);
+    }
+
+    #[test]
+    fn error_policies() {
+        #[derive(Resource, Default)]
+        struct Errors(Vec<Entity>);
+
+        fn record(world: &mut World, error: CommandError) {
+            match error {
+                CommandError::NoSuchEntity { entity, .. } => {
+                    world
+                        .get_resource_or_insert_with(Errors::default)
+                        .0
+                        .push(entity);
+                }
+                error => panic!("unexpected error: {error}"),
+            }
+        }
+
+        let mut world = World::default();
+        let missing = world.spawn_empty().id();
+        let alive = world.spawn_empty().id();
+        world.insert_resource(CommandErrorPolicy::Handler(record));
+
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(missing).despawn();
+            commands.entity(missing).insert(W(1u32)).despawn();
+            commands.entity(alive).insert(W(2u32));
+            // A per-command policy overrides the resource.
+            commands.add_with_policy(
+                Insert {
+                    entity: missing,
+                    bundle: W(3u32),
+                },
+                CommandErrorPolicy::Ignore,
+            );
+            commands.add_fallible(Despawn { entity: missing });
+        }
+        queue.apply(&mut world);
+
+        assert_eq!(world.resource::<Errors>().0, vec![missing; 3]);
+        assert_eq!(world.get::<W<u32>>(alive).unwrap().0, 2);
+    }
+
+    #[test]
+    fn clone_entity() {
+        #[derive(Component, Clone)]
+        struct Health(u32);
+
+        let mut world = World::default();
+        let entity = world.spawn((Health(3), W(0u32))).id();
+
+        let mut queue = CommandQueue::default();
+        let clone = {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.entity(entity).clone_entity().insert(W(1u64)).id()
+        };
+        queue.apply(&mut world);
+
+        assert_eq!(world.get::<Health>(clone).unwrap().0, 3);
+        assert!(world.get::<W<u32>>(clone).is_none());
+        assert_eq!(world.get::<W<u64>>(clone).unwrap().0, 1);
+        assert!(world.get::<W<u64>>(entity).is_none());
+    }
 }
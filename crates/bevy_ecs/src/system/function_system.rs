@@ -23,6 +23,7 @@ pub struct SystemMeta {
     // NOTE: this must be kept private. making a SystemMeta non-send is irreversible to prevent
     // SystemParams from overriding each other
     is_send: bool,
+    has_deferred: bool,
     pub(crate) last_change_tick: u32,
 }
 
@@ -33,6 +34,7 @@ impl SystemMeta {
             archetype_component_access: Access::default(),
             component_access_set: FilteredAccessSet::default(),
             is_send: true,
+            has_deferred: false,
             last_change_tick: 0,
         }
     }
@@ -50,6 +52,22 @@ impl SystemMeta {
     pub fn set_non_send(&mut self) {
         self.is_send = false;
     }
+
+    /// Returns true if the system has [`SystemParam`]s that record changes to apply later,
+    /// such as [`Commands`](crate::system::Commands).
+    #[inline]
+    pub fn has_deferred(&self) -> bool {
+        self.has_deferred
+    }
+
+    /// Marks the system as having [`SystemParam`]s that record changes to apply later.
+    ///
+    /// This is done automatically for the parameters whose state sets
+    /// [`SystemParamState::HAS_DEFERRED`](crate::system::SystemParamState::HAS_DEFERRED).
+    #[inline]
+    pub fn set_has_deferred(&mut self) {
+        self.has_deferred = true;
+    }
 }
 
 // TODO: Actually use this in FunctionSystem. We should probably only do this once Systems are constructed using a World reference
@@ -144,6 +162,9 @@ impl<Param: SystemParam> SystemState<Param> {
     pub fn new(world: &mut World) -> Self {
         let mut meta = SystemMeta::new::<Param>();
         meta.last_change_tick = world.change_tick().wrapping_sub(MAX_CHANGE_AGE);
+        if <Param::Fetch as SystemParamState>::HAS_DEFERRED {
+            meta.set_has_deferred();
+        }
         let param_state = <Param::Fetch as SystemParamState>::init(world, &mut meta);
         Self {
             meta,
@@ -387,6 +408,11 @@ where
         self.system_meta.is_send
     }
 
+    #[inline]
+    fn has_deferred(&self) -> bool {
+        self.system_meta.has_deferred
+    }
+
     #[inline]
     fn is_exclusive(&self) -> bool {
         false
@@ -429,6 +455,9 @@ where
     fn initialize(&mut self, world: &mut World) {
         self.world_id = Some(world.id());
         self.system_meta.last_change_tick = world.change_tick().wrapping_sub(MAX_CHANGE_AGE);
+        if <Param::Fetch as SystemParamState>::HAS_DEFERRED {
+            self.system_meta.set_has_deferred();
+        }
         self.param_state = Some(<Param::Fetch as SystemParamState>::init(
             world,
             &mut self.system_meta,
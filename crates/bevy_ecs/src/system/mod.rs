@@ -68,6 +68,7 @@
 //! - All tuples between 1 to 16 elements where each element implements [`SystemParam`]
 //! - [`()` (unit primitive type)](https://doc.rust-lang.org/stable/std/primitive.unit.html)
 
+mod async_system;
 mod commands;
 mod exclusive_function_system;
 mod exclusive_system_param;
@@ -77,7 +78,9 @@ mod query;
 mod system;
 mod system_param;
 mod system_piping;
+mod system_registry;
 
+pub use async_system::*;
 pub use commands::*;
 pub use exclusive_function_system::*;
 pub use exclusive_system_param::*;
@@ -86,6 +89,7 @@ pub use query::*;
 pub use system::*;
 pub use system_param::*;
 pub use system_piping::*;
+pub use system_registry::*;
 
 /// Ensure that a given function is a system
 ///
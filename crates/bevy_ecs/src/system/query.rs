@@ -7,7 +7,7 @@ use crate::{
     },
     world::{Mut, World},
 };
-use std::{any::TypeId, borrow::Borrow, fmt::Debug};
+use std::{any::TypeId, borrow::Borrow, cmp::Ordering, fmt::Debug};
 
 /// [System parameter] that provides selective access to the [`Component`] data stored in a [`World`].
 ///
@@ -191,6 +191,7 @@ use std::{any::TypeId, borrow::Borrow, fmt::Debug};
 /// |[`for_each`]\([`_mut`][`for_each_mut`]),<br>[`par_for_each`]\([`_mut`][`par_for_each_mut`])|Runs a specified function for each query item.|
 /// |[`iter_many`]\([`_mut`][`iter_many_mut`])|Iterates or runs a specified function over query items generated by a list of entities.|
 /// |[`iter_combinations`]\([`_mut`][`iter_combinations_mut`])|Returns an iterator over all combinations of a specified number of query items.|
+/// |[`iter_sorted_by`]\([`_mut`][`iter_mut_sorted_by`]),<br>[`iter_sorted_by_key`]\([`_mut`][`iter_mut_sorted_by_key`])|Returns an iterator over all query items in a specified order.|
 /// |[`get`]\([`_mut`][`get_mut`])|Returns the query item for the specified entity.|
 /// |[`many`]\([`_mut`][`many_mut`]),<br>[`get_many`]\([`_mut`][`get_many_mut`])|Returns the query items for the specified entities.|
 /// |[`single`]\([`_mut`][`single_mut`]),<br>[`get_single`]\([`_mut`][`get_single_mut`])|Returns the query item while verifying that there aren't others.|
@@ -227,6 +228,7 @@ use std::{any::TypeId, borrow::Borrow, fmt::Debug};
 /// |[`for_each`]\([`_mut`][`for_each_mut`]),<br>[`par_for_each`]\([`_mut`][`par_for_each_mut`])|O(n)|
 /// |[`iter_many`]\([`_mut`][`iter_many_mut`])|O(k)|
 /// |[`iter_combinations`]\([`_mut`][`iter_combinations_mut`])|O(<sub>n</sub>C<sub>r</sub>)|
+/// |[`iter_sorted_by`]\([`_mut`][`iter_mut_sorted_by`]),<br>[`iter_sorted_by_key`]\([`_mut`][`iter_mut_sorted_by_key`])|O(n log n)|
 /// |[`get`]\([`_mut`][`get_mut`])|O(1)|
 /// |([`get_`][`get_many`])[`many`]|O(k)|
 /// |([`get_`][`get_many_mut`])[`many_mut`]|O(k<sup>2</sup>)|
@@ -260,6 +262,10 @@ use std::{any::TypeId, borrow::Borrow, fmt::Debug};
 /// [`iter_many`]: Self::iter_many
 /// [`iter_many_mut`]: Self::iter_many_mut
 /// [`iter_mut`]: Self::iter_mut
+/// [`iter_mut_sorted_by`]: Self::iter_mut_sorted_by
+/// [`iter_mut_sorted_by_key`]: Self::iter_mut_sorted_by_key
+/// [`iter_sorted_by`]: Self::iter_sorted_by
+/// [`iter_sorted_by_key`]: Self::iter_sorted_by_key
 /// [`many`]: Self::many
 /// [`many_mut`]: Self::many_mut
 /// [`Or`]: crate::query::Or
@@ -582,6 +588,124 @@ impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> Query<'w, 's, Q, F> {
         }
     }
 
+    /// Returns an [`Iterator`] over the read-only query items, sorted by their [`Ord`] implementation.
+    ///
+    /// The items are collected and sorted on each call: to keep an order across system runs, see
+    /// [`QueryOrder`](crate::query::QueryOrder).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+    /// struct Initiative(u32);
+    ///
+    /// fn take_turns(query: Query<(&Initiative, Entity)>) {
+    ///     for (initiative, entity) in query.iter_sorted() {
+    ///         println!("{entity:?} plays at {}", initiative.0);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(take_turns);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`iter_sorted_by`](Self::iter_sorted_by) and [`iter_sorted_by_key`](Self::iter_sorted_by_key)
+    ///   to sort with a comparison or key function.
+    pub fn iter_sorted<'a>(&'a self) -> std::vec::IntoIter<ROQueryItem<'a, Q>>
+    where
+        ROQueryItem<'a, Q>: Ord,
+    {
+        self.iter_sorted_by(Ord::cmp)
+    }
+
+    /// Returns an [`Iterator`] over the read-only query items, sorted with the `compare` function.
+    ///
+    /// The sort is stable: items comparing equal keep their iteration order.
+    pub fn iter_sorted_by<'a>(
+        &'a self,
+        compare: impl FnMut(&ROQueryItem<'a, Q>, &ROQueryItem<'a, Q>) -> Ordering,
+    ) -> std::vec::IntoIter<ROQueryItem<'a, Q>> {
+        let mut items: Vec<_> = self.iter().collect();
+        items.sort_by(compare);
+        items.into_iter()
+    }
+
+    /// Returns an [`Iterator`] over the read-only query items, sorted by the key extracted by `f`.
+    ///
+    /// The sort is stable: items with equal keys keep their iteration order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Depth(i32);
+    ///
+    /// fn draw_sprites(query: Query<(Entity, &Depth)>) {
+    ///     for (entity, _) in query.iter_sorted_by_key(|(_, depth)| depth.0) {
+    ///         // Draw back to front.
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(draw_sprites);
+    /// ```
+    pub fn iter_sorted_by_key<'a, K: Ord>(
+        &'a self,
+        mut f: impl FnMut(&ROQueryItem<'a, Q>) -> K,
+    ) -> std::vec::IntoIter<ROQueryItem<'a, Q>> {
+        self.iter_sorted_by(|a, b| f(a).cmp(&f(b)))
+    }
+
+    /// Returns an [`Iterator`] over the query items, sorted with the `compare` function.
+    ///
+    /// The sort is stable: items comparing equal keep their iteration order.
+    ///
+    /// # See also
+    ///
+    /// - [`iter_sorted_by`](Self::iter_sorted_by) for read-only query items.
+    pub fn iter_mut_sorted_by<'a>(
+        &'a mut self,
+        compare: impl FnMut(&Q::Item<'a>, &Q::Item<'a>) -> Ordering,
+    ) -> std::vec::IntoIter<Q::Item<'a>> {
+        let mut items: Vec<_> = self.iter_mut().collect();
+        items.sort_by(compare);
+        items.into_iter()
+    }
+
+    /// Returns an [`Iterator`] over the query items, sorted by the key extracted by `f`.
+    ///
+    /// The sort is stable: items with equal keys keep their iteration order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Priority(u32);
+    ///
+    /// #[derive(Component)]
+    /// struct Budget(u32);
+    ///
+    /// fn allocate(mut query: Query<(&Priority, &mut Budget)>) {
+    ///     let mut remaining = 100;
+    ///     for (_, mut budget) in query.iter_mut_sorted_by_key(|(priority, _)| u32::MAX - priority.0) {
+    ///         budget.0 = remaining.min(10);
+    ///         remaining -= budget.0;
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(allocate);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`iter_sorted_by_key`](Self::iter_sorted_by_key) for read-only query items.
+    pub fn iter_mut_sorted_by_key<'a, K: Ord>(
+        &'a mut self,
+        mut f: impl FnMut(&Q::Item<'a>) -> K,
+    ) -> std::vec::IntoIter<Q::Item<'a>> {
+        self.iter_mut_sorted_by(|a, b| f(a).cmp(&f(b)))
+    }
+
     /// Returns an [`Iterator`] over the query items.
     ///
     /// # Safety
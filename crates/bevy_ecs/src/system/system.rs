@@ -36,6 +36,15 @@ pub trait System: Send + Sync + 'static {
     /// Returns true if the system must be run exclusively.
     fn is_exclusive(&self) -> bool;
 
+    /// Returns true if the system may record changes to apply in [`System::apply_buffers`],
+    /// for example with [`Commands`](crate::system::Commands).
+    ///
+    /// Defaults to `true`, which is always correct but keeps the system from being reordered
+    /// with other systems that have deferred changes.
+    fn has_deferred(&self) -> bool {
+        true
+    }
+
     /// Runs the system with the given input in the world. Unlike [`System::run`], this function
     /// takes a shared reference to [`World`] and may therefore break Rust's aliasing rules, making
     /// it unsafe to call.
@@ -108,6 +108,12 @@ pub type SystemParamItem<'w, 's, P> = <<P as SystemParam>::Fetch as SystemParamF
 /// Additionally, it is the implementor's responsibility to ensure there is no
 /// conflicting access across all [`SystemParam`]'s.
 pub unsafe trait SystemParamState: Send + Sync + 'static {
+    /// Whether [`apply`](SystemParamState::apply) may record changes to the [`World`].
+    ///
+    /// This defaults to `true` and should be overridden by states that don't implement `apply`,
+    /// so that systems without deferred changes can be reordered freely.
+    const HAS_DEFERRED: bool = true;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self;
     #[inline]
     fn new_archetype(&mut self, _archetype: &Archetype, _system_meta: &mut SystemMeta) {}
@@ -152,6 +158,8 @@ unsafe impl<Q: ReadOnlyWorldQuery, F: ReadOnlyWorldQuery> ReadOnlySystemParamFet
 unsafe impl<Q: WorldQuery + 'static, F: ReadOnlyWorldQuery + 'static> SystemParamState
     for QueryState<Q, F>
 {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         let state = QueryState::new(world);
         assert_component_access_compatibility(
@@ -370,6 +378,8 @@ impl<'a, T: Resource> SystemParam for Res<'a, T> {
 // SAFETY: Res ComponentId and ArchetypeComponentId access is applied to SystemMeta. If this Res
 // conflicts with any prior access, a panic will occur.
 unsafe impl<T: Resource> SystemParamState for ResState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         let component_id = world.initialize_resource::<T>();
         let combined_access = system_meta.component_access_set.combined_access_mut();
@@ -437,6 +447,8 @@ unsafe impl<T: Resource> ReadOnlySystemParamFetch for OptionResState<T> {}
 // SAFETY: this impl defers to `ResState`, which initializes
 // and validates the correct world access
 unsafe impl<T: Resource> SystemParamState for OptionResState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         Self(ResState::init(world, system_meta))
     }
@@ -477,6 +489,8 @@ impl<'a, T: Resource> SystemParam for ResMut<'a, T> {
 // SAFETY: Res ComponentId and ArchetypeComponentId access is applied to SystemMeta. If this Res
 // conflicts with any prior access, a panic will occur.
 unsafe impl<T: Resource> SystemParamState for ResMutState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         let component_id = world.initialize_resource::<T>();
         let combined_access = system_meta.component_access_set.combined_access_mut();
@@ -546,6 +560,8 @@ impl<'a, T: Resource> SystemParam for Option<ResMut<'a, T>> {
 // SAFETY: this impl defers to `ResMutState`, which initializes
 // and validates the correct world access
 unsafe impl<T: Resource> SystemParamState for OptionResMutState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         Self(ResMutState::init(world, system_meta))
     }
@@ -619,6 +635,8 @@ impl<'w> SystemParam for &'w World {
 
 // SAFETY: `read_all` access is set and conflicts result in a panic
 unsafe impl SystemParamState for WorldState {
+    const HAS_DEFERRED: bool = false;
+
     fn init(_world: &mut World, system_meta: &mut SystemMeta) -> Self {
         let mut access = Access::default();
         access.read_all();
@@ -765,6 +783,8 @@ impl<'a, T: FromWorld + Send + 'static> SystemParam for Local<'a, T> {
 
 // SAFETY: only local state is accessed
 unsafe impl<T: FromWorld + Send + 'static> SystemParamState for LocalState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, _system_meta: &mut SystemMeta) -> Self {
         Self(SyncCell::new(T::from_world(world)))
     }
@@ -857,6 +877,8 @@ impl<'a, T: Component> SystemParam for RemovedComponents<'a, T> {
 // SAFETY: no component access. removed component entity collections can be read in parallel and are
 // never mutably borrowed during system execution
 unsafe impl<T: Component> SystemParamState for RemovedComponentsState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, _system_meta: &mut SystemMeta) -> Self {
         Self {
             component_id: world.init_component::<T>(),
@@ -959,6 +981,8 @@ impl<'a, T: 'static> SystemParam for NonSend<'a, T> {
 // SAFETY: NonSendComponentId and ArchetypeComponentId access is applied to SystemMeta. If this
 // NonSend conflicts with any prior access, a panic will occur.
 unsafe impl<T: 'static> SystemParamState for NonSendState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         system_meta.set_non_send();
 
@@ -1030,6 +1054,8 @@ unsafe impl<T: 'static> ReadOnlySystemParamFetch for OptionNonSendState<T> {}
 // SAFETY: this impl defers to `NonSendState`, which initializes
 // and validates the correct world access
 unsafe impl<T: 'static> SystemParamState for OptionNonSendState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         Self(NonSendState::init(world, system_meta))
     }
@@ -1071,6 +1097,8 @@ impl<'a, T: 'static> SystemParam for NonSendMut<'a, T> {
 // SAFETY: NonSendMut ComponentId and ArchetypeComponentId access is applied to SystemMeta. If this
 // NonSendMut conflicts with any prior access, a panic will occur.
 unsafe impl<T: 'static> SystemParamState for NonSendMutState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         system_meta.set_non_send();
 
@@ -1143,6 +1171,8 @@ impl<'a, T: 'static> SystemParam for Option<NonSendMut<'a, T>> {
 // SAFETY: this impl defers to `NonSendMutState`, which initializes
 // and validates the correct world access
 unsafe impl<T: 'static> SystemParamState for OptionNonSendMutState<T> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         Self(NonSendMutState::init(world, system_meta))
     }
@@ -1185,6 +1215,8 @@ pub struct ArchetypesState;
 
 // SAFETY: no component value access
 unsafe impl SystemParamState for ArchetypesState {
+    const HAS_DEFERRED: bool = false;
+
     fn init(_world: &mut World, _system_meta: &mut SystemMeta) -> Self {
         Self
     }
@@ -1217,6 +1249,8 @@ pub struct ComponentsState;
 
 // SAFETY: no component value access
 unsafe impl SystemParamState for ComponentsState {
+    const HAS_DEFERRED: bool = false;
+
     fn init(_world: &mut World, _system_meta: &mut SystemMeta) -> Self {
         Self
     }
@@ -1249,6 +1283,8 @@ pub struct EntitiesState;
 
 // SAFETY: no component value access
 unsafe impl SystemParamState for EntitiesState {
+    const HAS_DEFERRED: bool = false;
+
     fn init(_world: &mut World, _system_meta: &mut SystemMeta) -> Self {
         Self
     }
@@ -1281,6 +1317,8 @@ pub struct BundlesState;
 
 // SAFETY: no component value access
 unsafe impl SystemParamState for BundlesState {
+    const HAS_DEFERRED: bool = false;
+
     fn init(_world: &mut World, _system_meta: &mut SystemMeta) -> Self {
         Self
     }
@@ -1342,6 +1380,8 @@ pub struct SystemChangeTickState {}
 
 // SAFETY: `SystemParamTickState` doesn't require any world access
 unsafe impl SystemParamState for SystemChangeTickState {
+    const HAS_DEFERRED: bool = false;
+
     fn init(_world: &mut World, _system_meta: &mut SystemMeta) -> Self {
         Self {}
     }
@@ -1425,6 +1465,8 @@ pub struct SystemNameState {
 
 // SAFETY: no component value access
 unsafe impl SystemParamState for SystemNameState {
+    const HAS_DEFERRED: bool = false;
+
     fn init(_world: &mut World, system_meta: &mut SystemMeta) -> Self {
         Self {
             name: system_meta.name.clone(),
@@ -1480,6 +1522,8 @@ macro_rules! impl_system_param_tuple {
         #[allow(clippy::undocumented_unsafe_blocks)] // false positive by clippy
         #[allow(non_snake_case)]
         unsafe impl<$($param: SystemParamState),*> SystemParamState for ($($param,)*) {
+            const HAS_DEFERRED: bool = false $(|| $param::HAS_DEFERRED)*;
+
             #[inline]
             fn init(_world: &mut World, _system_meta: &mut SystemMeta) -> Self {
                 (($($param::init(_world, _system_meta),)*))
@@ -1625,6 +1669,8 @@ where
 unsafe impl<S: SystemParamState, P: SystemParam + 'static> SystemParamState
     for StaticSystemParamState<S, P>
 {
+    const HAS_DEFERRED: bool = S::HAS_DEFERRED;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         Self(S::init(world, system_meta), PhantomData)
     }
@@ -1640,11 +1686,11 @@ unsafe impl<S: SystemParamState, P: SystemParam + 'static> SystemParamState
 
 #[cfg(test)]
 mod tests {
-    use super::SystemParam;
+    use super::*;
     use crate::{
         self as bevy_ecs, // Necessary for the `SystemParam` Derive when used inside `bevy_ecs`.
         query::{ReadOnlyWorldQuery, WorldQuery},
-        system::Query,
+        system::{Commands, IntoSystem, Query, System},
     };
 
     // Compile test for #2838
@@ -1657,4 +1703,69 @@ mod tests {
     > {
         _query: Query<'w, 's, Q, F>,
     }
+
+    #[derive(Resource)]
+    struct Counter(usize);
+
+    // A third-party parameter that applies changes without declaring it.
+    struct Deferred;
+
+    impl SystemParam for Deferred {
+        type Fetch = DeferredState;
+    }
+
+    struct DeferredState;
+
+    // SAFETY: no component or resource access to report
+    unsafe impl SystemParamState for DeferredState {
+        fn init(_world: &mut World, _system_meta: &mut SystemMeta) -> Self {
+            DeferredState
+        }
+
+        fn apply(&mut self, world: &mut World) {
+            world.resource_mut::<Counter>().0 += 1;
+        }
+    }
+
+    impl<'w, 's> SystemParamFetch<'w, 's> for DeferredState {
+        type Item = Deferred;
+
+        unsafe fn get_param(
+            _state: &'s mut Self,
+            _system_meta: &SystemMeta,
+            _world: &'w World,
+            _change_tick: u32,
+        ) -> Self::Item {
+            Deferred
+        }
+    }
+
+    #[derive(SystemParam)]
+    struct WithCommands<'w, 's> {
+        _commands: Commands<'w, 's>,
+    }
+
+    #[derive(SystemParam)]
+    struct WithoutDeferred<'w, 's> {
+        _counter: Res<'w, Counter>,
+        _query: Query<'w, 's, Entity>,
+    }
+
+    #[test]
+    fn has_deferred() {
+        fn has_deferred<Marker>(system: impl IntoSystem<(), (), Marker>) -> bool {
+            let mut world = World::new();
+            world.insert_resource(Counter(0));
+            let mut system = IntoSystem::into_system(system);
+            system.initialize(&mut world);
+            system.has_deferred()
+        }
+
+        assert!(!has_deferred(|_: Res<Counter>, _: Query<Entity>| {}));
+        assert!(!has_deferred(|_: WithoutDeferred| {}));
+        assert!(has_deferred(|_: Commands| {}));
+        assert!(has_deferred(|_: Res<Counter>, _: WithCommands| {}));
+        assert!(has_deferred(|_: ParamSet<(Res<Counter>, Commands)>| {}));
+        assert!(has_deferred(|_: Deferred| {}));
+    }
 }
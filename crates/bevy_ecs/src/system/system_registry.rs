@@ -0,0 +1,386 @@
+use std::fmt::{Debug, Display};
+
+use bevy_utils::tracing::warn;
+
+use crate::{
+    system::{BoxedSystem, Command, IntoSystem},
+    world::World,
+};
+
+/// Identifies a system registered in a [`World`] with [`World::register_system`].
+///
+/// Once a system is removed, its id stays invalid, even if another system is registered later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SystemId {
+    index: u32,
+    generation: u32,
+}
+
+/// An error returned when running or removing a registered system fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegisteredSystemError {
+    /// No system is registered with this id, or it was removed.
+    SystemIdNotRegistered(SystemId),
+    /// The system is currently running, and tried to run or remove itself.
+    Recursive(SystemId),
+}
+
+impl Display for RegisteredSystemError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegisteredSystemError::SystemIdNotRegistered(id) => {
+                write!(f, "system {id:?} is not registered in this World")
+            }
+            RegisteredSystemError::Recursive(id) => {
+                write!(f, "system {id:?} tried to run or remove itself")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegisteredSystemError {}
+
+struct SystemSlot {
+    generation: u32,
+    /// `None` while the slot is free or while the system is running.
+    system: Option<BoxedSystem>,
+    registered: bool,
+}
+
+/// The systems registered in a [`World`] to be run on demand.
+#[derive(Default)]
+pub struct SystemRegistry {
+    slots: Vec<SystemSlot>,
+    free: Vec<u32>,
+}
+
+impl Debug for SystemRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SystemRegistry")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl SystemRegistry {
+    /// Returns the number of registered systems.
+    pub fn len(&self) -> usize {
+        self.slots.len() - self.free.len()
+    }
+
+    /// Returns `true` if no system is registered.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if a system is registered with this id.
+    pub fn contains(&self, id: SystemId) -> bool {
+        self.slot(id).is_some()
+    }
+
+    fn slot(&self, id: SystemId) -> Option<&SystemSlot> {
+        self.slots
+            .get(id.index as usize)
+            .filter(|slot| slot.registered && slot.generation == id.generation)
+    }
+
+    fn slot_mut(&mut self, id: SystemId) -> Option<&mut SystemSlot> {
+        self.slots
+            .get_mut(id.index as usize)
+            .filter(|slot| slot.registered && slot.generation == id.generation)
+    }
+
+    fn insert(&mut self, system: BoxedSystem) -> SystemId {
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index as usize];
+            slot.system = Some(system);
+            slot.registered = true;
+            SystemId {
+                index,
+                generation: slot.generation,
+            }
+        } else {
+            let index = self.slots.len() as u32;
+            self.slots.push(SystemSlot {
+                generation: 0,
+                system: Some(system),
+                registered: true,
+            });
+            SystemId {
+                index,
+                generation: 0,
+            }
+        }
+    }
+
+    pub(crate) fn check_change_ticks(&mut self, change_tick: u32) {
+        for system in self
+            .slots
+            .iter_mut()
+            .filter_map(|slot| slot.system.as_mut())
+        {
+            system.check_change_tick(change_tick);
+        }
+    }
+}
+
+impl World {
+    /// Registers a system to be run on demand with [`World::run_system`] or
+    /// [`Commands::run_system`](crate::system::Commands::run_system), and returns its [`SystemId`].
+    ///
+    /// The system is initialized right away. Its state, such as its [`Local`](crate::system::Local)s
+    /// and change detection ticks, persists between runs.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Resource, Default)]
+    /// struct Clicks(u32);
+    ///
+    /// fn on_click(mut clicks: ResMut<Clicks>, mut count: Local<u32>) {
+    ///     *count += 1;
+    ///     clicks.0 = *count;
+    /// }
+    ///
+    /// let mut world = World::new();
+    /// world.init_resource::<Clicks>();
+    /// let on_click = world.register_system(on_click);
+    ///
+    /// world.run_system(on_click).unwrap();
+    /// world.run_system(on_click).unwrap();
+    /// assert_eq!(world.resource::<Clicks>().0, 2);
+    /// ```
+    pub fn register_system<Params>(&mut self, system: impl IntoSystem<(), (), Params>) -> SystemId {
+        self.register_boxed_system(Box::new(IntoSystem::into_system(system)))
+    }
+
+    /// Registers an already boxed system, see [`World::register_system`].
+    pub fn register_boxed_system(&mut self, mut system: BoxedSystem) -> SystemId {
+        system.initialize(self);
+        self.systems.insert(system)
+    }
+
+    /// Returns the systems registered with [`World::register_system`].
+    pub fn registered_systems(&self) -> &SystemRegistry {
+        &self.systems
+    }
+
+    /// Runs the system registered with `id`, then applies its command buffers.
+    ///
+    /// A system can run other registered systems, but not itself.
+    pub fn run_system(&mut self, id: SystemId) -> Result<(), RegisteredSystemError> {
+        let system = self
+            .systems
+            .slot_mut(id)
+            .ok_or(RegisteredSystemError::SystemIdNotRegistered(id))?
+            .system
+            .take()
+            .ok_or(RegisteredSystemError::Recursive(id))?;
+
+        // Gives the system back to its slot, even if it panics.
+        struct Restore<'w> {
+            world: &'w mut World,
+            id: SystemId,
+            system: Option<BoxedSystem>,
+        }
+        impl Drop for Restore<'_> {
+            fn drop(&mut self) {
+                // The slot is still registered, as a running system can not be removed.
+                if let Some(slot) = self.world.systems.slot_mut(self.id) {
+                    slot.system = self.system.take();
+                }
+            }
+        }
+        let mut restore = Restore {
+            world: self,
+            id,
+            system: Some(system),
+        };
+        let Restore { world, system, .. } = &mut restore;
+        let system = system.as_mut().unwrap();
+        system.run((), world);
+        system.apply_buffers(world);
+        Ok(())
+    }
+
+    /// Removes the system registered with `id` and returns it.
+    ///
+    /// A system can not remove itself while it is running.
+    pub fn remove_system(&mut self, id: SystemId) -> Result<BoxedSystem, RegisteredSystemError> {
+        let slot = self
+            .systems
+            .slot_mut(id)
+            .ok_or(RegisteredSystemError::SystemIdNotRegistered(id))?;
+        let system = slot
+            .system
+            .take()
+            .ok_or(RegisteredSystemError::Recursive(id))?;
+        slot.registered = false;
+        slot.generation = slot.generation.wrapping_add(1);
+        self.systems.free.push(id.index);
+        Ok(system)
+    }
+}
+
+/// [`Command`] running a system registered with [`World::register_system`].
+///
+/// Logs a warning if the system is not registered.
+#[derive(Debug)]
+pub struct RunSystem {
+    pub system_id: SystemId,
+}
+
+impl Command for RunSystem {
+    fn write(self, world: &mut World) {
+        if let Err(error) = world.run_system(self.system_id) {
+            warn!("Could not run registered system: {error}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        prelude::*,
+        system::{CommandQueue, RegisteredSystemError, SystemId},
+    };
+
+    #[derive(Resource, Default, PartialEq, Debug)]
+    struct Counter(u32);
+
+    fn count_up(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn run_registered_system() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let id = world.register_system(count_up);
+        world.run_system(id).unwrap();
+        world.run_system(id).unwrap();
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
+
+    #[test]
+    fn local_state_persists() {
+        fn doubling(mut last: Local<u32>, mut counter: ResMut<Counter>) {
+            *last = (*last).max(1) * 2;
+            counter.0 = *last;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let id = world.register_system(doubling);
+        for _ in 0..3 {
+            world.run_system(id).unwrap();
+        }
+        assert_eq!(*world.resource::<Counter>(), Counter(8));
+    }
+
+    #[test]
+    fn change_detection_persists() {
+        #[derive(Resource, Default)]
+        struct Changes(u32);
+
+        fn count_changes(counter: Res<Counter>, mut changes: ResMut<Changes>) {
+            if counter.is_changed() {
+                changes.0 += 1;
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        world.init_resource::<Changes>();
+        let id = world.register_system(count_changes);
+        world.run_system(id).unwrap();
+        world.run_system(id).unwrap();
+        assert_eq!(world.resource::<Changes>().0, 1);
+        world.resource_mut::<Counter>().0 += 1;
+        world.run_system(id).unwrap();
+        assert_eq!(world.resource::<Changes>().0, 2);
+    }
+
+    #[test]
+    fn removed_system_is_not_registered() {
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let id = world.register_system(count_up);
+        assert!(world.remove_system(id).is_ok());
+        assert_eq!(
+            world.run_system(id),
+            Err(RegisteredSystemError::SystemIdNotRegistered(id))
+        );
+        // A new system reusing the slot gets a different id.
+        let new_id = world.register_system(count_up);
+        assert_ne!(id, new_id);
+        assert!(world.remove_system(id).is_err());
+        assert_eq!(world.registered_systems().len(), 1);
+    }
+
+    #[test]
+    fn recursive_run_fails() {
+        #[derive(Resource)]
+        struct Id(SystemId);
+        #[derive(Resource)]
+        struct Result(std::result::Result<(), RegisteredSystemError>);
+
+        fn run_self(world: &mut World) {
+            let id = world.resource::<Id>().0;
+            let result = world.run_system(id);
+            world.insert_resource(Result(result));
+        }
+
+        let mut world = World::new();
+        let id = world.register_system(run_self);
+        world.insert_resource(Id(id));
+        world.run_system(id).unwrap();
+        assert_eq!(
+            world.resource::<Result>().0,
+            Err(RegisteredSystemError::Recursive(id))
+        );
+    }
+
+    #[test]
+    fn panicking_system_can_run_again() {
+        fn panic_once(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+            if counter.0 == 1 {
+                panic!("first run");
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let id = world.register_system(panic_once);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            world.run_system(id).unwrap();
+        }));
+        assert!(result.is_err());
+        assert_eq!(world.run_system(id), Ok(()));
+        assert_eq!(*world.resource::<Counter>(), Counter(2));
+    }
+
+    #[test]
+    fn run_system_command() {
+        fn spawn(mut commands: Commands) {
+            commands.spawn_empty();
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let count_id = world.register_system(count_up);
+        let spawn_id = world.register_system(spawn);
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.run_system(count_id);
+        commands.run_system(spawn_id);
+        queue.apply(&mut world);
+
+        assert_eq!(*world.resource::<Counter>(), Counter(1));
+        // Commands of the registered system are applied right after it runs.
+        assert_eq!(world.entities().len(), 1);
+    }
+}
@@ -0,0 +1,337 @@
+use std::{
+    alloc::{alloc, dealloc, handle_alloc_error},
+    any::TypeId,
+    ptr::NonNull,
+};
+
+use bevy_ptr::OwningPtr;
+use bevy_utils::HashSet;
+
+use crate::{
+    component::{clone_component, Component, ComponentId, ComponentInfo},
+    entity::Entity,
+    world::World,
+};
+
+#[cfg(feature = "bevy_reflect")]
+use crate::reflect::ReflectComponent;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::TypeRegistryArc;
+
+/// Options of [`World::clone_entity_with`] and
+/// [`EntityCommands::clone_entity_with`](crate::system::EntityCommands::clone_entity_with).
+///
+/// By default, every component that can be cloned is cloned.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::CloneEntityOptions;
+/// #[derive(Component, Clone)]
+/// struct Health(u32);
+///
+/// #[derive(Component, Clone)]
+/// struct Player;
+///
+/// let mut world = World::new();
+/// let player = world.spawn((Health(10), Player)).id();
+///
+/// let copy = world.clone_entity_with(player, &CloneEntityOptions::default().deny::<Player>());
+/// assert_eq!(world.get::<Health>(copy).unwrap().0, 10);
+/// assert!(world.get::<Player>(copy).is_none());
+/// ```
+#[derive(Clone, Default)]
+pub struct CloneEntityOptions {
+    allowed: Option<HashSet<TypeId>>,
+    denied: HashSet<TypeId>,
+    #[cfg(feature = "bevy_reflect")]
+    type_registry: Option<TypeRegistryArc>,
+}
+
+impl std::fmt::Debug for CloneEntityOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CloneEntityOptions")
+            .field("allowed", &self.allowed)
+            .field("denied", &self.denied)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CloneEntityOptions {
+    /// Only clones the components allowed with this method.
+    pub fn allow<T: Component>(mut self) -> Self {
+        self.allowed
+            .get_or_insert_with(HashSet::default)
+            .insert(TypeId::of::<T>());
+        self
+    }
+
+    /// Does not clone the component `T`.
+    pub fn deny<T: Component>(mut self) -> Self {
+        self.denied.insert(TypeId::of::<T>());
+        self
+    }
+
+    /// Clones the components that do not have a [`ComponentInfo::clone_fn`] using their
+    /// [`ReflectComponent`] in `type_registry`, such as the one of the `AppTypeRegistry`
+    /// resource.
+    #[cfg(feature = "bevy_reflect")]
+    pub fn with_type_registry(mut self, type_registry: TypeRegistryArc) -> Self {
+        self.type_registry = Some(type_registry);
+        self
+    }
+
+    /// Returns `true` if the component is cloned with these options.
+    pub fn allows(&self, info: &ComponentInfo) -> bool {
+        match info.type_id() {
+            Some(type_id) => {
+                !self.denied.contains(&type_id)
+                    && match &self.allowed {
+                        Some(allowed) => allowed.contains(&type_id),
+                        None => true,
+                    }
+            }
+            None => self.allowed.is_none(),
+        }
+    }
+}
+
+impl World {
+    /// Registers the [`Clone`] implementation of the component `T`, used by
+    /// [`World::clone_entity`].
+    ///
+    /// This is only needed for components whose [`Component::clone_fn`] returns `None`, such as
+    /// generic components deriving [`Component`].
+    pub fn register_component_clone<T: Component + Clone>(&mut self) -> ComponentId {
+        let id = self.init_component::<T>();
+        // SAFETY: `id` is the component id of `T`.
+        unsafe {
+            self.components.set_clone_fn(id, clone_component::<T>);
+        }
+        id
+    }
+
+    /// Spawns a copy of `entity` with clones of all its components, and returns the new entity.
+    ///
+    /// See [`World::clone_entity_with`] for which components are cloned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` does not exist.
+    pub fn clone_entity(&mut self, entity: Entity) -> Entity {
+        self.clone_entity_with(entity, &CloneEntityOptions::default())
+    }
+
+    /// Spawns a copy of `entity` with clones of the components allowed by `options`, and returns
+    /// the new entity.
+    ///
+    /// Components with a [`ComponentInfo::clone_fn`] are cloned with it: this is the [`Clone`]
+    /// implementation of components deriving [`Component`], or the one registered with
+    /// [`World::register_component_clone`]. Other components are cloned through reflection if they have a
+    /// [`ReflectComponent`](crate::reflect::ReflectComponent) in the type registry of `options`,
+    /// and are skipped otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `entity` does not exist.
+    pub fn clone_entity_with(&mut self, entity: Entity, options: &CloneEntityOptions) -> Entity {
+        let clone = self.spawn_empty().id();
+        self.clone_components(entity, clone, options);
+        clone
+    }
+
+    /// Inserts into `destination` clones of the components of `source` allowed by `options`.
+    ///
+    /// See [`World::clone_entity_with`] for which components are cloned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` or `destination` does not exist.
+    pub fn clone_components(
+        &mut self,
+        source: Entity,
+        destination: Entity,
+        options: &CloneEntityOptions,
+    ) {
+        self.flush();
+        let location = match self.entities.get(source) {
+            Some(location) => location,
+            None => panic!("Entity {source:?} does not exist"),
+        };
+        assert!(
+            self.entities.contains(destination),
+            "Entity {destination:?} does not exist"
+        );
+
+        let mut ids = Vec::new();
+        let mut values = Vec::new();
+        #[cfg(feature = "bevy_reflect")]
+        let mut reflected = Vec::new();
+        for id in self.archetypes[location.archetype_id].components() {
+            let info = self.components.get_info(id).unwrap();
+            if !options.allows(info) {
+                continue;
+            }
+            if let Some(clone) = info.clone_fn() {
+                let layout = info.layout();
+                let value = if layout.size() == 0 {
+                    // Zero-sized values only need to be aligned.
+                    NonNull::new(layout.align() as *mut u8).unwrap()
+                } else {
+                    // SAFETY: the layout has a non-zero size.
+                    match NonNull::new(unsafe { alloc(layout) }) {
+                        Some(value) => value,
+                        None => handle_alloc_error(layout),
+                    }
+                };
+                // SAFETY: the entity has the component `id`, `clone` clones values of it and
+                // `value` was allocated with its layout.
+                unsafe { clone(self.get_by_id(source, id).unwrap(), value) };
+                ids.push(id);
+                values.push((value, layout));
+                continue;
+            }
+            #[cfg(feature = "bevy_reflect")]
+            if let (Some(type_registry), Some(type_id)) = (&options.type_registry, info.type_id()) {
+                if let Some(reflect_component) = type_registry
+                    .read()
+                    .get_type_data::<ReflectComponent>(type_id)
+                {
+                    reflected.push(reflect_component.clone());
+                }
+            }
+        }
+
+        let mut destination_mut = self.entity_mut(destination);
+        // SAFETY: each value was initialized with a clone of the component with the same index
+        // in `ids`, and is moved out by the insertion.
+        unsafe {
+            destination_mut
+                .insert_by_ids(&ids, values.iter().map(|(value, _)| OwningPtr::new(*value)));
+        }
+        for (value, layout) in values {
+            if layout.size() != 0 {
+                // SAFETY: `value` was allocated with `layout`, and its value was moved out.
+                unsafe { dealloc(value.as_ptr(), layout) };
+            }
+        }
+
+        #[cfg(feature = "bevy_reflect")]
+        for reflect_component in reflected {
+            let value = reflect_component
+                .reflect(self, source)
+                .unwrap()
+                .clone_value();
+            reflect_component.insert(self, destination, &*value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{component::Component, world::World};
+
+    use super::CloneEntityOptions;
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct A(String);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    #[component(storage = "SparseSet")]
+    struct B(Vec<u32>);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Marker;
+
+    #[derive(Component, Debug, PartialEq)]
+    struct NotCloned;
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Generic<T>(T);
+
+    #[test]
+    fn clone_entity() {
+        let mut world = World::new();
+        let entity = world
+            .spawn((A("a".to_string()), B(vec![1, 2]), Marker, NotCloned))
+            .id();
+        let clone = world.clone_entity(entity);
+
+        assert_ne!(clone, entity);
+        assert_eq!(world.get::<A>(clone), Some(&A("a".to_string())));
+        assert_eq!(world.get::<B>(clone), Some(&B(vec![1, 2])));
+        assert_eq!(world.get::<Marker>(clone), Some(&Marker));
+        assert_eq!(world.get::<NotCloned>(clone), None);
+        // The original is left untouched.
+        assert_eq!(world.get::<A>(entity), Some(&A("a".to_string())));
+        assert_eq!(world.get::<B>(entity), Some(&B(vec![1, 2])));
+    }
+
+    #[test]
+    fn register_component_clone() {
+        let mut world = World::new();
+        let entity = world.spawn(Generic(1)).id();
+        let clone = world.clone_entity(entity);
+        assert_eq!(world.get::<Generic<i32>>(clone), None);
+
+        world.register_component_clone::<Generic<i32>>();
+        let clone = world.clone_entity(entity);
+        assert_eq!(world.get::<Generic<i32>>(clone), Some(&Generic(1)));
+    }
+
+    #[test]
+    fn filters() {
+        let mut world = World::new();
+        let entity = world.spawn((A("a".to_string()), B(vec![1]), Marker)).id();
+
+        let clone = world.clone_entity_with(entity, &CloneEntityOptions::default().deny::<B>());
+        assert!(world.get::<A>(clone).is_some());
+        assert!(world.get::<B>(clone).is_none());
+        assert!(world.get::<Marker>(clone).is_some());
+
+        let options = CloneEntityOptions::default()
+            .allow::<A>()
+            .allow::<B>()
+            .deny::<B>();
+        let clone = world.clone_entity_with(entity, &options);
+        assert!(world.get::<A>(clone).is_some());
+        assert!(world.get::<B>(clone).is_none());
+        assert!(world.get::<Marker>(clone).is_none());
+    }
+
+    #[test]
+    fn clone_components_into_existing_entity() {
+        let mut world = World::new();
+        let source = world.spawn((A("source".to_string()), Marker)).id();
+        let destination = world.spawn((A("destination".to_string()), NotCloned)).id();
+        world.clone_components(source, destination, &CloneEntityOptions::default());
+        assert_eq!(world.get::<A>(destination), Some(&A("source".to_string())));
+        assert!(world.get::<Marker>(destination).is_some());
+        assert!(world.get::<NotCloned>(destination).is_some());
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn reflect_fallback() {
+        use crate::reflect::ReflectComponent;
+        use bevy_reflect::{Reflect, TypeRegistryArc};
+
+        #[derive(Component, Reflect, Default, Debug, PartialEq)]
+        #[reflect(Component)]
+        struct Reflected(u32);
+
+        let registry = TypeRegistryArc::default();
+        registry.write().register::<Reflected>();
+
+        let mut world = World::new();
+        let entity = world.spawn((Reflected(4), Marker)).id();
+        let clone = world.clone_entity(entity);
+        assert_eq!(world.get::<Reflected>(clone), None);
+
+        let options = CloneEntityOptions::default().with_type_registry(registry);
+        let clone = world.clone_entity_with(entity, &options);
+        assert_eq!(world.get::<Reflected>(clone), Some(&Reflected(4)));
+        assert!(world.get::<Marker>(clone).is_some());
+    }
+}
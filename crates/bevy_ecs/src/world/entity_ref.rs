@@ -1,9 +1,10 @@
 use crate::{
     archetype::{Archetype, ArchetypeId, Archetypes},
-    bundle::{Bundle, BundleInfo},
+    bundle::{Bundle, BundleInfo, DynamicInsertBundle},
     change_detection::{MutUntyped, Ticks},
-    component::{Component, ComponentId, ComponentTicks, Components, StorageType},
+    component::{Component, ComponentEvent, ComponentId, ComponentTicks, Components, StorageType},
     entity::{Entities, Entity, EntityLocation},
+    observer::ObserverId,
     storage::{SparseSet, Storages},
     world::{Mut, World},
 };
@@ -251,11 +252,15 @@ impl<'w> EntityMut<'w> {
     ///
     /// This will overwrite any previous value(s) of the same component type.
     pub fn insert<T: Bundle>(&mut self, bundle: T) -> &mut Self {
+        self.assert_not_despawned();
         let change_tick = self.world.change_tick();
-        let bundle_info = self
+        let bundle_id = self
             .world
             .bundles
-            .init_info::<T>(&mut self.world.components, &mut self.world.storages);
+            .init_info::<T>(&mut self.world.components, &mut self.world.storages)
+            .id();
+        let bundle_info = self.world.bundles.get(bundle_id).unwrap();
+        let listeners = self.bundle_listeners(bundle_info);
         let mut bundle_inserter = bundle_info.get_bundle_inserter(
             &mut self.world.entities,
             &mut self.world.archetypes,
@@ -268,10 +273,191 @@ impl<'w> EntityMut<'w> {
         unsafe {
             self.location = bundle_inserter.insert(self.entity, self.location.index, bundle);
         }
+        if let Some((added, inserted)) = listeners {
+            self.trigger_insert(&added, &inserted);
+        }
 
         self
     }
 
+    /// Inserts a component with the given [`ComponentId`] into the entity, taking ownership of
+    /// the value behind `component`.
+    ///
+    /// This will overwrite any previous value of the same component.
+    ///
+    /// **You should prefer to use the typed API [`EntityMut::insert`] where possible and only
+    /// use this in cases where the actual component types are not known at
+    /// compile time.**
+    ///
+    /// # Panics
+    ///
+    /// Panics if `component_id` is not registered in this entity's [`World`].
+    ///
+    /// # Safety
+    ///
+    /// `component` must point to a valid value of the component described by the
+    /// [`ComponentDescriptor`](crate::component::ComponentDescriptor) of `component_id`.
+    pub unsafe fn insert_by_id(
+        &mut self,
+        component_id: ComponentId,
+        component: OwningPtr<'_>,
+    ) -> &mut Self {
+        self.insert_by_ids(&[component_id], std::iter::once(component))
+    }
+
+    /// Inserts components with the given [`ComponentId`]s into the entity, taking ownership of
+    /// the values behind the pointers yielded by `components`.
+    ///
+    /// This will overwrite any previous values of the same components.
+    ///
+    /// **You should prefer to use the typed API [`EntityMut::insert`] where possible and only
+    /// use this in cases where the actual component types are not known at
+    /// compile time.**
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the `component_ids` is not registered in this entity's [`World`], or if
+    /// it is listed more than once.
+    ///
+    /// # Safety
+    ///
+    /// `components` must yield exactly one pointer for each of the `component_ids`, in the same
+    /// order, each pointing to a valid value of the component described by the
+    /// [`ComponentDescriptor`](crate::component::ComponentDescriptor) of its id.
+    pub unsafe fn insert_by_ids<'a, I: IntoIterator<Item = OwningPtr<'a>>>(
+        &mut self,
+        component_ids: &[ComponentId],
+        components: I,
+    ) -> &mut Self {
+        self.assert_not_despawned();
+        let change_tick = self.world.change_tick();
+        let bundle_id = self
+            .world
+            .bundles
+            .init_dynamic_info(&mut self.world.components, component_ids)
+            .id();
+        let bundle_info = self.world.bundles.get(bundle_id).unwrap();
+        let listeners = self.bundle_listeners(bundle_info);
+        let mut bundle_inserter = bundle_info.get_bundle_inserter(
+            &mut self.world.entities,
+            &mut self.world.archetypes,
+            &mut self.world.components,
+            &mut self.world.storages,
+            self.location.archetype_id,
+            change_tick,
+        );
+        // SAFETY: location matches current entity. The caller guarantees that `components`
+        // matches `bundle_info`
+        self.location = bundle_inserter.insert(
+            self.entity,
+            self.location.index,
+            DynamicInsertBundle {
+                components: components.into_iter(),
+            },
+        );
+        if let Some((added, inserted)) = listeners {
+            self.trigger_insert(&added, &inserted);
+        }
+
+        self
+    }
+
+    /// Returns the components of `bundle_info` this entity does not have yet, and all of them,
+    /// if inserting them may run hooks or observers.
+    #[allow(clippy::type_complexity)]
+    fn bundle_listeners(
+        &self,
+        bundle_info: &BundleInfo,
+    ) -> Option<(Vec<ComponentId>, Vec<ComponentId>)> {
+        let components = bundle_info.components();
+        self.world
+            .has_component_listeners(self.entity, components)
+            .then(|| {
+                (
+                    self.world
+                        .components_added_to(self.location.archetype_id, components),
+                    components.to_vec(),
+                )
+            })
+    }
+
+    /// Runs the hooks and observers for components that were just added and inserted.
+    pub(crate) fn trigger_insert(&mut self, added: &[ComponentId], inserted: &[ComponentId]) {
+        self.world
+            .trigger_component_event(ComponentEvent::Add, self.entity, added);
+        self.world
+            .trigger_component_event(ComponentEvent::Insert, self.entity, inserted);
+        self.refresh_location();
+    }
+
+    /// Runs the hooks and observers for components that are about to be removed, if they may
+    /// exist. Returns `false` if the entity was despawned by them.
+    fn trigger_remove(&mut self, component_ids: &[ComponentId]) -> bool {
+        if !self
+            .world
+            .has_component_listeners(self.entity, component_ids)
+        {
+            return true;
+        }
+        let archetype = &self.world.archetypes[self.location.archetype_id];
+        let removed = component_ids
+            .iter()
+            .copied()
+            .filter(|&id| archetype.contains(id))
+            .collect::<Vec<_>>();
+        self.world
+            .trigger_component_event(ComponentEvent::Remove, self.entity, &removed);
+        self.refresh_location();
+        !self.is_despawned()
+    }
+
+    /// Updates the location of the entity after hooks or observers ran, which may have moved
+    /// or despawned it.
+    fn refresh_location(&mut self) {
+        self.location = self
+            .world
+            .entities
+            .get(self.entity)
+            .unwrap_or(EntityLocation {
+                archetype_id: ArchetypeId::INVALID,
+                index: usize::MAX,
+            });
+    }
+
+    /// Returns `true` if the entity was despawned by a [hook](crate::component::ComponentHooks)
+    /// or an [observer](crate::observer) run by this [`EntityMut`].
+    ///
+    /// A despawned [`EntityMut`] can not be used to access or modify the entity anymore.
+    #[inline]
+    pub fn is_despawned(&self) -> bool {
+        self.location.archetype_id == ArchetypeId::INVALID
+    }
+
+    #[inline]
+    #[track_caller]
+    fn assert_not_despawned(&self) {
+        assert!(
+            !self.is_despawned(),
+            "Entity {:?} was despawned by a hook or an observer",
+            self.entity
+        );
+    }
+
+    /// Registers an observer on this entity, run when `event` happens to its component `T`.
+    ///
+    /// Returns the [`ObserverId`] to remove it with [`World::remove_observer`]. See the
+    /// [`observer`](crate::observer) module.
+    pub fn observe<T: Component>(
+        &mut self,
+        event: ComponentEvent,
+        observer: impl FnMut(&mut World, Entity, ComponentId) + Send + Sync + 'static,
+    ) -> ObserverId {
+        self.assert_not_despawned();
+        let component_id = self.world.init_component::<T>();
+        self.world
+            .observe_by_id(self.entity, event, component_id, observer)
+    }
+
     #[deprecated(
         since = "0.9.0",
         note = "Use `remove` instead, which now accepts bundles, components, and tuples of bundles and components."
@@ -285,6 +471,27 @@ impl<'w> EntityMut<'w> {
     ///
     /// Returns `None` if the entity does not contain the bundle.
     pub fn remove<T: Bundle>(&mut self) -> Option<T> {
+        self.assert_not_despawned();
+        let bundle_id = self
+            .world
+            .bundles
+            .init_info::<T>(&mut self.world.components, &mut self.world.storages)
+            .id();
+        let component_ids = self.world.bundles.get(bundle_id).unwrap().components();
+        if self
+            .world
+            .has_component_listeners(self.entity, component_ids)
+        {
+            let archetype = &self.world.archetypes[self.location.archetype_id];
+            if !component_ids.iter().all(|&id| archetype.contains(id)) {
+                return None;
+            }
+            let component_ids = component_ids.to_vec();
+            if !self.trigger_remove(&component_ids) {
+                return None;
+            }
+        }
+
         let archetypes = &mut self.world.archetypes;
         let storages = &mut self.world.storages;
         let components = &mut self.world.components;
@@ -418,6 +625,23 @@ impl<'w> EntityMut<'w> {
     // TODO: move to BundleInfo
     /// Remove any components in the bundle that the entity has.
     pub fn remove_intersection<T: Bundle>(&mut self) {
+        self.assert_not_despawned();
+        let bundle_id = self
+            .world
+            .bundles
+            .init_info::<T>(&mut self.world.components, &mut self.world.storages)
+            .id();
+        let component_ids = self.world.bundles.get(bundle_id).unwrap().components();
+        if self
+            .world
+            .has_component_listeners(self.entity, component_ids)
+        {
+            let component_ids = component_ids.to_vec();
+            if !self.trigger_remove(&component_ids) {
+                return;
+            }
+        }
+
         let archetypes = &mut self.world.archetypes;
         let storages = &mut self.world.storages;
         let components = &mut self.world.components;
@@ -480,8 +704,24 @@ impl<'w> EntityMut<'w> {
         }
     }
 
-    pub fn despawn(self) {
+    pub fn despawn(mut self) {
+        self.assert_not_despawned();
         debug!("Despawning entity {:?}", self.entity);
+        let archetype = &self.world.archetypes[self.location.archetype_id];
+        if self.world.observers.has_observers(self.entity)
+            || archetype.components().any(|id| {
+                self.world
+                    .components
+                    .get_info(id)
+                    .map_or(false, |info| !info.hooks().is_empty())
+            })
+        {
+            let component_ids = archetype.components().collect::<Vec<_>>();
+            if !self.trigger_remove(&component_ids) {
+                return;
+            }
+            self.world.despawn_observers(self.entity);
+        }
         let world = self.world;
         world.flush();
         let location = world
@@ -959,7 +1199,7 @@ mod tests {
         let b = vec![1];
         super::sorted_remove(&mut a, &b);
 
-        assert_eq!(a, vec![]);
+        assert_eq!(a, Vec::<i32>::new());
 
         let mut a = vec![1];
         let b = vec![2];
@@ -1,9 +1,13 @@
+mod entity_clone;
 mod entity_ref;
+mod snapshot;
 mod spawn_batch;
 mod world_cell;
 
 pub use crate::change_detection::Mut;
+pub use entity_clone::*;
 pub use entity_ref::*;
+pub use snapshot::*;
 pub use spawn_batch::*;
 pub use world_cell::*;
 
@@ -12,12 +16,16 @@ use crate::{
     bundle::{Bundle, BundleInserter, BundleSpawner, Bundles},
     change_detection::{MutUntyped, Ticks},
     component::{
-        Component, ComponentDescriptor, ComponentId, ComponentInfo, ComponentTicks, Components,
+        Component, ComponentDescriptor, ComponentHooks, ComponentId, ComponentInfo, ComponentTicks,
+        Components,
+    },
+    entity::{AllocAtWithoutReplacement, Disabled, Entities, Entity},
+    observer::Observers,
+    query::{
+        DynamicAccess, DynamicQuery, DynamicQueryState, QueryState, ReadOnlyWorldQuery, WorldQuery,
     },
-    entity::{AllocAtWithoutReplacement, Entities, Entity},
-    query::{QueryState, ReadOnlyWorldQuery, WorldQuery},
     storage::{ResourceData, SparseSet, Storages},
-    system::Resource,
+    system::{Resource, SystemRegistry},
 };
 use bevy_ptr::{OwningPtr, Ptr, UnsafeCellDeref};
 use bevy_utils::tracing::warn;
@@ -58,6 +66,9 @@ pub struct World {
     pub(crate) storages: Storages,
     pub(crate) bundles: Bundles,
     pub(crate) removed_components: SparseSet<ComponentId, Vec<Entity>>,
+    pub(crate) observers: Observers,
+    pub(crate) systems: SystemRegistry,
+    pub(crate) rollback: RollbackRegistry,
     /// Access cache used by [WorldCell].
     pub(crate) archetype_component_access: ArchetypeComponentAccess,
     main_thread_validator: MainThreadValidator,
@@ -67,7 +78,7 @@ pub struct World {
 
 impl Default for World {
     fn default() -> Self {
-        Self {
+        let mut world = Self {
             id: WorldId::new().expect("More `bevy` `World`s have been created than is supported"),
             entities: Default::default(),
             components: Default::default(),
@@ -75,13 +86,19 @@ impl Default for World {
             storages: Default::default(),
             bundles: Default::default(),
             removed_components: Default::default(),
+            observers: Default::default(),
+            systems: Default::default(),
+            rollback: Default::default(),
             archetype_component_access: Default::default(),
             main_thread_validator: Default::default(),
             // Default value is `1`, and `last_change_tick`s default to `0`, such that changes
             // are detected on first system runs and for direct world queries.
             change_tick: AtomicU32::new(1),
             last_change_tick: 0,
-        }
+        };
+        // Registered up front so that every query knows which archetypes to exclude.
+        world.init_component::<Disabled>();
+        world
     }
 }
 
@@ -172,6 +189,26 @@ impl World {
             .init_component_with_descriptor(&mut self.storages, descriptor)
     }
 
+    /// Returns the [`ComponentHooks`] of the [`Component`] type `T`, to register hooks run when
+    /// it is added to, inserted into or removed from an entity.
+    ///
+    /// The component is initialized if it was not already.
+    pub fn register_component_hooks<T: Component>(&mut self) -> &mut ComponentHooks {
+        let id = self.init_component::<T>();
+        self.register_component_hooks_by_id(id).unwrap()
+    }
+
+    /// Returns the [`ComponentHooks`] of the component with the given [`ComponentId`], to
+    /// register hooks run when it is added to, inserted into or removed from an entity.
+    ///
+    /// Returns `None` if the component is not registered in this [`World`].
+    pub fn register_component_hooks_by_id(
+        &mut self,
+        component_id: ComponentId,
+    ) -> Option<&mut ComponentHooks> {
+        self.components.get_hooks_mut(component_id)
+    }
+
     /// Returns the [`ComponentId`] of the given [`Component`] type `T`.
     ///
     /// The returned `ComponentId` is specific to the `World` instance
@@ -451,10 +488,16 @@ impl World {
     pub fn spawn<B: Bundle>(&mut self, bundle: B) -> EntityMut {
         self.flush();
         let entity = self.entities.alloc();
+        let mut added = None;
         let entity_location = {
-            let bundle_info = self
+            let bundle_id = self
                 .bundles
-                .init_info::<B>(&mut self.components, &mut self.storages);
+                .init_info::<B>(&mut self.components, &mut self.storages)
+                .id();
+            let bundle_info = self.bundles.get(bundle_id).unwrap();
+            if self.has_component_listeners(entity, bundle_info.components()) {
+                added = Some(bundle_info.components().to_vec());
+            }
             let mut spawner = bundle_info.get_bundle_spawner(
                 &mut self.entities,
                 &mut self.archetypes,
@@ -468,7 +511,11 @@ impl World {
         };
 
         // SAFETY: entity and location are valid, as they were just created above
-        unsafe { EntityMut::new(self, entity, entity_location) }
+        let mut entity_mut = unsafe { EntityMut::new(self, entity, entity_location) };
+        if let Some(added) = added {
+            entity_mut.trigger_insert(&added, &added);
+        }
+        entity_mut
     }
 
     /// # Safety
@@ -690,6 +737,39 @@ impl World {
         QueryState::new(self)
     }
 
+    /// Returns a [`QueryState`] for a [`DynamicQuery`] over the given components, which can be
+    /// chosen at runtime.
+    ///
+    /// Each item fetched by the query contains a [`DynamicItem`](crate::query::DynamicItem) for
+    /// every component accessed with [`DynamicAccess::Read`] or [`DynamicAccess::Write`], in the
+    /// order they are given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the [`ComponentId`]s is not registered in this [`World`], or if the
+    /// accesses conflict with each other.
+    #[inline]
+    pub fn query_dynamic(
+        &mut self,
+        components: impl IntoIterator<Item = (ComponentId, DynamicAccess)>,
+    ) -> QueryState<DynamicQuery, ()> {
+        self.query_dynamic_filtered::<()>(components)
+    }
+
+    /// Returns a [`QueryState`] for a [`DynamicQuery`] over the given components, filtered by
+    /// the statically known filter `F`.
+    ///
+    /// See [`World::query_dynamic`].
+    #[inline]
+    pub fn query_dynamic_filtered<F: ReadOnlyWorldQuery>(
+        &mut self,
+        components: impl IntoIterator<Item = (ComponentId, DynamicAccess)>,
+    ) -> QueryState<DynamicQuery, F> {
+        let fetch_state = DynamicQueryState::new(self, components);
+        let filter_state = F::init_state(self);
+        QueryState::new_with_state(self, fetch_state, filter_state)
+    }
+
     /// Returns an iterator of entities that had components of type `T` removed
     /// since the last call to [`World::clear_trackers`].
     pub fn removed<T: Component>(&self) -> std::iter::Cloned<std::slice::Iter<'_, Entity>> {
@@ -1055,6 +1135,39 @@ impl World {
         let iter = iter.into_iter();
         let change_tick = *self.change_tick.get_mut();
 
+        let has_listeners = {
+            let bundle_id = self
+                .bundles
+                .init_info::<B>(&mut self.components, &mut self.storages)
+                .id();
+            let bundle_info = self.bundles.get(bundle_id).unwrap();
+            !self.observers.is_empty() || self.has_component_hooks(bundle_info.components())
+        };
+        if has_listeners {
+            // Hooks and observers need the whole world, so insert one entity at a time.
+            let mut invalid_entities = Vec::new();
+            for (entity, bundle) in iter {
+                match self.entities.alloc_at_without_replacement(entity) {
+                    AllocAtWithoutReplacement::Exists(location) => {
+                        // SAFETY: `location` is the location of the existing `entity`
+                        unsafe { EntityMut::new(self, entity, location) }.insert(bundle);
+                    }
+                    AllocAtWithoutReplacement::DidNotExist => {
+                        // SAFETY: `entity` was just allocated
+                        unsafe { self.spawn_at_empty_internal(entity) }.insert(bundle);
+                    }
+                    AllocAtWithoutReplacement::ExistsWithWrongGeneration => {
+                        invalid_entities.push(entity);
+                    }
+                }
+            }
+            return if invalid_entities.is_empty() {
+                Ok(())
+            } else {
+                Err(invalid_entities)
+            };
+        }
+
         let bundle_info = self
             .bundles
             .init_info::<B>(&mut self.components, &mut self.storages);
@@ -1416,6 +1529,7 @@ impl World {
         self.storages.tables.check_change_ticks(change_tick);
         self.storages.sparse_sets.check_change_ticks(change_tick);
         self.storages.resources.check_change_ticks(change_tick);
+        self.systems.check_change_ticks(change_tick);
     }
 
     pub fn clear_entities(&mut self) {
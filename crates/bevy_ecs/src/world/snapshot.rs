@@ -0,0 +1,596 @@
+use std::{any::TypeId, collections::BTreeMap, fmt::Debug, ops::Range};
+
+use bevy_utils::HashSet;
+
+use crate::{
+    component::{Component, ComponentId, ComponentStorage, ComponentTicks, StorageType},
+    entity::{Entity, EntityMap},
+    storage::TableId,
+    system::Resource,
+    world::{get_ticks_with_type, World},
+};
+
+/// Clones the values of a component or resource type out of a [`World`].
+type TakeSnapshot<Id> = fn(&World, Id) -> Box<dyn RollbackData>;
+
+/// The component and resource types saved by [`World::snapshot`], registered with
+/// [`World::register_rollback_component`] and [`World::register_rollback_resource`].
+///
+/// Types are kept sorted, so that snapshots are taken and restored in the same order on every
+/// run.
+#[derive(Default)]
+pub struct RollbackRegistry {
+    components: BTreeMap<ComponentId, TakeSnapshot<ComponentId>>,
+    resources: BTreeMap<TypeId, TakeSnapshot<()>>,
+}
+
+impl Debug for RollbackRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RollbackRegistry")
+            .field("components", &self.components.keys())
+            .field("resources", &self.resources.len())
+            .finish()
+    }
+}
+
+impl RollbackRegistry {
+    /// Returns `true` if the component with `id` is saved in snapshots.
+    pub fn contains_component(&self, id: ComponentId) -> bool {
+        self.components.contains_key(&id)
+    }
+
+    /// Returns `true` if the resource of type `R` is saved in snapshots.
+    pub fn contains_resource<R: Resource>(&self) -> bool {
+        self.resources.contains_key(&TypeId::of::<R>())
+    }
+}
+
+/// The saved values of a single component or resource type.
+trait RollbackData: Send + Sync + 'static {
+    /// Restores the saved values. Entities of the snapshot are mapped to their current id with
+    /// `entity_map`, and were already respawned if needed.
+    fn restore(&self, world: &mut World, entity_map: &EntityMap);
+}
+
+struct ComponentSnapshot<T> {
+    id: ComponentId,
+    entities: Vec<Entity>,
+    values: Vec<T>,
+    ticks: Vec<ComponentTicks>,
+    /// The rows of `entities`, `values` and `ticks` copied from each table, in table order.
+    tables: Vec<(TableId, Range<usize>)>,
+}
+
+impl<T: Component + Clone> ComponentSnapshot<T> {
+    fn take(world: &World, id: ComponentId) -> Box<dyn RollbackData> {
+        let mut snapshot = ComponentSnapshot::<T> {
+            id,
+            entities: Vec::new(),
+            values: Vec::new(),
+            ticks: Vec::new(),
+            tables: Vec::new(),
+        };
+        match T::Storage::STORAGE_TYPE {
+            StorageType::Table => {
+                for (index, table) in world.storages.tables.iter().enumerate() {
+                    if let Some(column) = table.get_column(id) {
+                        let start = snapshot.entities.len();
+                        snapshot.entities.extend_from_slice(table.entities());
+                        // SAFETY: the column stores values of the component `T`, and `&World`
+                        // ensures that nothing mutates them.
+                        unsafe {
+                            let values = column.get_data_slice::<T>();
+                            snapshot
+                                .values
+                                .extend(values.iter().map(|value| (*value.get()).clone()));
+                            let ticks = column.get_ticks_slice();
+                            snapshot
+                                .ticks
+                                .extend(ticks.iter().map(|ticks| *ticks.get()));
+                        }
+                        let rows = start..snapshot.entities.len();
+                        snapshot.tables.push((TableId::new(index), rows));
+                    }
+                }
+            }
+            StorageType::SparseSet => {
+                if let Some(sparse_set) = world.storages.sparse_sets.get(id) {
+                    for archetype in world.archetypes.iter().filter(|a| a.contains(id)) {
+                        for archetype_entity in archetype.entities() {
+                            let entity = archetype_entity.entity();
+                            let (value, ticks) = sparse_set.get_with_ticks(entity).unwrap();
+                            snapshot.entities.push(entity);
+                            // SAFETY: the sparse set stores values of the component `T`, and
+                            // `&World` ensures that nothing mutates them.
+                            unsafe {
+                                snapshot.values.push(value.deref::<T>().clone());
+                                snapshot.ticks.push(*ticks.get());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Box::new(snapshot)
+    }
+
+    /// Overwrites the column of a table whose entities are still those of `rows`, in the same
+    /// order, returning `false` if the table changed since the snapshot.
+    fn restore_table(
+        &self,
+        world: &World,
+        table: TableId,
+        rows: Range<usize>,
+        entity_map: &EntityMap,
+        change_tick: u32,
+    ) -> bool {
+        let table = match world.storages.tables.get(table) {
+            Some(table) if table.entity_count() == rows.len() => table,
+            _ => return false,
+        };
+        let column = match table.get_column(self.id) {
+            Some(column) => column,
+            None => return false,
+        };
+        let unchanged = table
+            .entities()
+            .iter()
+            .zip(&self.entities[rows.clone()])
+            .all(|(&current, &saved)| entity_map.get(saved).ok() == Some(current));
+        if !unchanged {
+            return false;
+        }
+
+        // SAFETY: the column stores values of the component `T`, and the caller has exclusive
+        // access to the world.
+        unsafe {
+            let values = column
+                .get_data_slice::<T>()
+                .iter()
+                .zip(&self.values[rows.clone()]);
+            for (current, saved) in values {
+                (*current.get()).clone_from(saved);
+            }
+            let ticks = column.get_ticks_slice().iter().zip(&self.ticks[rows]);
+            for (current, saved) in ticks {
+                restore_ticks(&mut *current.get(), saved, change_tick);
+            }
+        }
+        true
+    }
+
+    /// Overwrites the value of `entity` in its sparse set, returning `false` if it does not have
+    /// the component.
+    fn restore_sparse(&self, world: &World, entity: Entity, row: usize, change_tick: u32) -> bool {
+        let sparse_set = world.storages.sparse_sets.get(self.id);
+        match sparse_set.and_then(|sparse_set| sparse_set.get_with_ticks(entity)) {
+            // SAFETY: the sparse set stores values of the component `T`, and the caller has
+            // exclusive access to the world.
+            Some((value, ticks)) => unsafe {
+                value
+                    .assert_unique()
+                    .deref_mut::<T>()
+                    .clone_from(&self.values[row]);
+                restore_ticks(&mut *ticks.get(), &self.ticks[row], change_tick);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Restores the value of `entity` by inserting it if needed, which moves the entity to
+    /// another archetype.
+    fn restore_entity(&self, world: &mut World, entity: Entity, row: usize, change_tick: u32) {
+        if let Some(mut current) = world.get_mut::<T>(entity) {
+            current.clone_from(&self.values[row]);
+        } else {
+            world.entity_mut(entity).insert(self.values[row].clone());
+        }
+        let location = world.entities.get(entity).unwrap();
+        // SAFETY: the entity exists at `location` and `&mut World` grants exclusive access.
+        let ticks = unsafe {
+            &mut *get_ticks_with_type(world, TypeId::of::<T>(), entity, location)
+                .unwrap()
+                .get()
+        };
+        restore_ticks(ticks, &self.ticks[row], change_tick);
+    }
+}
+
+/// The component keeps the tick it was added at, so it is not reported as added again, but is
+/// reported as changed, since its value may differ.
+fn restore_ticks(ticks: &mut ComponentTicks, saved: &ComponentTicks, change_tick: u32) {
+    ticks.added = saved.added;
+    ticks.set_changed(change_tick);
+    ticks.check_ticks(change_tick);
+}
+
+impl<T: Component + Clone> RollbackData for ComponentSnapshot<T> {
+    fn restore(&self, world: &mut World, entity_map: &EntityMap) {
+        let targets: HashSet<Entity> = self
+            .entities
+            .iter()
+            .map(|&entity| entity_map.get(entity).unwrap())
+            .collect();
+        let added: Vec<Entity> = world
+            .archetypes
+            .iter()
+            .filter(|archetype| archetype.contains(self.id))
+            .flat_map(|archetype| archetype.entities())
+            .map(|archetype_entity| archetype_entity.entity())
+            .filter(|entity| !targets.contains(entity))
+            .collect();
+        for entity in added {
+            world.entity_mut(entity).remove::<T>();
+        }
+
+        // Tables that did not change since the snapshot are copied in bulk, and only the
+        // remaining entities are restored one by one.
+        let change_tick = world.change_tick();
+        let mut remaining = Vec::new();
+        match T::Storage::STORAGE_TYPE {
+            StorageType::Table => {
+                for (table, rows) in &self.tables {
+                    if !self.restore_table(world, *table, rows.clone(), entity_map, change_tick) {
+                        remaining.extend(rows.clone());
+                    }
+                }
+            }
+            StorageType::SparseSet => {
+                for (row, &entity) in self.entities.iter().enumerate() {
+                    let entity = entity_map.get(entity).unwrap();
+                    if !self.restore_sparse(world, entity, row, change_tick) {
+                        remaining.push(row);
+                    }
+                }
+            }
+        }
+        for row in remaining {
+            let entity = entity_map.get(self.entities[row]).unwrap();
+            self.restore_entity(world, entity, row, change_tick);
+        }
+    }
+}
+
+struct ResourceSnapshot<R> {
+    value: Option<R>,
+}
+
+impl<R: Resource + Clone> ResourceSnapshot<R> {
+    fn take(world: &World, _: ()) -> Box<dyn RollbackData> {
+        Box::new(ResourceSnapshot {
+            value: world.get_resource::<R>().cloned(),
+        })
+    }
+}
+
+impl<R: Resource + Clone> RollbackData for ResourceSnapshot<R> {
+    fn restore(&self, world: &mut World, _entity_map: &EntityMap) {
+        match &self.value {
+            Some(value) => match world.get_resource_mut::<R>() {
+                Some(mut current) => *current = value.clone(),
+                None => world.insert_resource(value.clone()),
+            },
+            None => {
+                world.remove_resource::<R>();
+            }
+        }
+    }
+}
+
+/// The saved state of the rollback components and resources of a [`World`], taken with
+/// [`World::snapshot`] and restored with [`World::restore_snapshot`].
+///
+/// Values are cloned directly from the component storages, without reflection. When restoring,
+/// the columns of tables whose entities did not change are overwritten in bulk, and only the
+/// other entities are updated one by one.
+pub struct WorldSnapshot {
+    /// Every entity that had at least one rollback component.
+    entities: Vec<Entity>,
+    data: Vec<Box<dyn RollbackData>>,
+}
+
+impl Debug for WorldSnapshot {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WorldSnapshot")
+            .field("entities", &self.entities)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WorldSnapshot {
+    /// Returns the entities that had at least one rollback component when the snapshot was taken.
+    pub fn entities(&self) -> &[Entity] {
+        &self.entities
+    }
+}
+
+impl World {
+    /// Registers the component `T` to be saved by [`World::snapshot`].
+    ///
+    /// Entities with at least one rollback component are rollback entities: they are respawned
+    /// or despawned by [`World::restore_snapshot`] as needed.
+    pub fn register_rollback_component<T: Component + Clone>(&mut self) {
+        let id = self.init_component::<T>();
+        self.rollback
+            .components
+            .insert(id, ComponentSnapshot::<T>::take);
+    }
+
+    /// Registers the resource `R` to be saved by [`World::snapshot`].
+    pub fn register_rollback_resource<R: Resource + Clone>(&mut self) {
+        self.rollback
+            .resources
+            .insert(TypeId::of::<R>(), ResourceSnapshot::<R>::take);
+    }
+
+    /// Returns the component and resource types saved in snapshots.
+    pub fn rollback_registry(&self) -> &RollbackRegistry {
+        &self.rollback
+    }
+
+    /// Saves the values of the rollback components and resources, see
+    /// [`World::register_rollback_component`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component, Clone, PartialEq, Debug)]
+    /// struct Position(i32);
+    ///
+    /// let mut world = World::new();
+    /// world.register_rollback_component::<Position>();
+    /// let player = world.spawn(Position(0)).id();
+    ///
+    /// let snapshot = world.snapshot();
+    /// world.get_mut::<Position>(player).unwrap().0 += 5;
+    /// world.spawn(Position(10));
+    ///
+    /// world.restore_snapshot(&snapshot);
+    /// assert_eq!(world.get::<Position>(player), Some(&Position(0)));
+    /// assert_eq!(world.entities().len(), 1);
+    /// ```
+    pub fn snapshot(&self) -> WorldSnapshot {
+        let mut entities = self.rollback_entities();
+        // Entities are respawned in this order, which must not depend on the archetypes.
+        entities.sort_unstable();
+
+        let data = self
+            .rollback
+            .components
+            .iter()
+            .map(|(&id, take)| take(self, id))
+            .chain(self.rollback.resources.values().map(|take| take(self, ())))
+            .collect();
+
+        WorldSnapshot { entities, data }
+    }
+
+    /// Returns the entities with at least one rollback component, each appearing once.
+    fn rollback_entities(&self) -> Vec<Entity> {
+        self.archetypes
+            .iter()
+            .filter(|archetype| {
+                archetype
+                    .components()
+                    .any(|id| self.rollback.contains_component(id))
+            })
+            .flat_map(|archetype| archetype.entities())
+            .map(|entity| entity.entity())
+            .collect()
+    }
+
+    /// Restores the values of the rollback components and resources saved in `snapshot`.
+    ///
+    /// Rollback entities spawned since the snapshot are despawned, and those despawned since are
+    /// respawned, with the same id if it was not reused. The returned map gives the current id of
+    /// every entity of the snapshot, so that components storing entities can be updated.
+    ///
+    /// Restored components keep the tick they were added at when the snapshot was taken, so they
+    /// are not reported as [`Added`](crate::query::Added) again, but are reported as
+    /// [`Changed`](crate::query::Changed).
+    pub fn restore_snapshot(&mut self, snapshot: &WorldSnapshot) -> EntityMap {
+        self.flush();
+
+        let mut current = self.rollback_entities();
+        // Despawning frees ids in this order, so it decides the ids of the respawned entities.
+        current.sort_unstable();
+        let saved: HashSet<Entity> = snapshot.entities.iter().copied().collect();
+        for entity in current {
+            if !saved.contains(&entity) {
+                self.despawn(entity);
+            }
+        }
+
+        let mut entity_map = EntityMap::default();
+        for &entity in &snapshot.entities {
+            let mapped = match self.get_or_spawn(entity) {
+                Some(entity_mut) => entity_mut.id(),
+                // The id was reused by another entity.
+                None => self.spawn_empty().id(),
+            };
+            entity_map.insert(entity, mapped);
+        }
+
+        for data in &snapshot.data {
+            data.restore(self, &entity_map);
+        }
+        entity_map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component,
+        entity::Entity,
+        query::{Added, Changed},
+        system::Resource,
+        world::World,
+    };
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Position(i32);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    #[component(storage = "SparseSet")]
+    struct Health(u32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Name(&'static str);
+
+    #[derive(Resource, Clone, Debug, PartialEq)]
+    struct Frame(u32);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.register_rollback_component::<Position>();
+        world.register_rollback_component::<Health>();
+        world.register_rollback_resource::<Frame>();
+        world
+    }
+
+    #[test]
+    fn restore_values_and_resources() {
+        let mut world = world();
+        let a = world.spawn((Position(1), Health(10), Name("a"))).id();
+        let b = world.spawn(Position(2)).id();
+        world.insert_resource(Frame(0));
+        let snapshot = world.snapshot();
+
+        world.get_mut::<Position>(a).unwrap().0 = 5;
+        world.entity_mut(a).remove::<Health>();
+        world.entity_mut(b).insert(Health(3));
+        world.resource_mut::<Frame>().0 = 1;
+
+        world.restore_snapshot(&snapshot);
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        assert_eq!(world.get::<Health>(a), Some(&Health(10)));
+        assert_eq!(world.get::<Health>(b), None);
+        // Other components are left untouched.
+        assert_eq!(world.get::<Name>(a), Some(&Name("a")));
+        assert_eq!(world.resource::<Frame>(), &Frame(0));
+
+        // Snapshots can be restored any number of times.
+        world.get_mut::<Position>(b).unwrap().0 = 7;
+        world.remove_resource::<Frame>();
+        world.restore_snapshot(&snapshot);
+        assert_eq!(world.get::<Position>(b), Some(&Position(2)));
+        assert_eq!(world.resource::<Frame>(), &Frame(0));
+    }
+
+    #[test]
+    fn respawn_and_despawn_entities() {
+        let mut world = world();
+        let kept = world.spawn(Position(0)).id();
+        let despawned = world.spawn((Position(1), Health(1))).id();
+        let non_rollback = world.spawn(Name("static")).id();
+        let snapshot = world.snapshot();
+
+        world.despawn(despawned);
+        let spawned = world.spawn(Health(2)).id();
+        assert_eq!(spawned.index(), despawned.index());
+
+        let entity_map = world.restore_snapshot(&snapshot);
+        assert!(world.get_entity(spawned).is_none());
+        assert_eq!(entity_map.get(kept).unwrap(), kept);
+        assert_eq!(entity_map.get(despawned).unwrap(), despawned);
+        assert_eq!(world.get::<Health>(despawned), Some(&Health(1)));
+        assert!(world.get_entity(non_rollback).is_some());
+        assert_eq!(world.entities().len(), 3);
+
+        // The id is now used by another entity, so it is remapped.
+        world.despawn(despawned);
+        let reused = world.spawn(Name("reused")).id();
+        assert_eq!(reused.index(), despawned.index());
+        let entity_map = world.restore_snapshot(&snapshot);
+        let respawned = entity_map.get(despawned).unwrap();
+        assert_ne!(respawned, despawned);
+        assert_eq!(world.get::<Position>(respawned), Some(&Position(1)));
+        assert_eq!(world.get::<Name>(reused), Some(&Name("reused")));
+    }
+
+    #[test]
+    fn deterministic_entity_map() {
+        // Two peers running the same simulation.
+        let mut worlds = [world(), world()];
+        for world in &mut worlds {
+            for i in 0..8 {
+                match i % 3 {
+                    0 => world.spawn(Position(i)),
+                    1 => world.spawn(Health(i as u32)),
+                    _ => world.spawn((Position(i), Health(i as u32))),
+                };
+            }
+        }
+        let snapshot = worlds[0].snapshot();
+        assert_eq!(snapshot.entities(), worlds[1].snapshot().entities());
+
+        let entity_maps = worlds.map(|mut world| {
+            // Every id is reused, so every entity is respawned with a new id.
+            for &entity in snapshot.entities() {
+                world.despawn(entity);
+                world.spawn(Name("reused"));
+            }
+            let entity_map = world.restore_snapshot(&snapshot);
+            snapshot
+                .entities()
+                .iter()
+                .map(|&entity| entity_map.get(entity).unwrap())
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(entity_maps[0], entity_maps[1]);
+        assert!(snapshot
+            .entities()
+            .iter()
+            .zip(&entity_maps[0])
+            .all(|(saved, restored)| saved != restored));
+    }
+
+    #[test]
+    fn restore_moved_entities() {
+        let mut world = world();
+        let entities: Vec<Entity> = (0..4)
+            .map(|i| world.spawn((Position(i), Health(i as u32))).id())
+            .collect();
+        let snapshot = world.snapshot();
+
+        // Moves the last entity of the table in place of the first one.
+        world.entity_mut(entities[0]).remove::<Position>();
+        world.entity_mut(entities[2]).insert(Name("moved"));
+        for &entity in &entities[1..] {
+            world.get_mut::<Position>(entity).unwrap().0 += 10;
+            world.get_mut::<Health>(entity).unwrap().0 += 10;
+        }
+
+        world.restore_snapshot(&snapshot);
+        for (i, &entity) in entities.iter().enumerate() {
+            assert_eq!(world.get::<Position>(entity), Some(&Position(i as i32)));
+            assert_eq!(world.get::<Health>(entity), Some(&Health(i as u32)));
+        }
+    }
+
+    #[test]
+    fn change_ticks() {
+        let mut world = world();
+        let entity = world.spawn(Position(0)).id();
+        let snapshot = world.snapshot();
+
+        let mut added = world.query_filtered::<(), Added<Position>>();
+        let mut changed = world.query_filtered::<(), Changed<Position>>();
+        world.clear_trackers();
+        world.entity_mut(entity).remove::<Position>();
+        world.restore_snapshot(&snapshot);
+
+        // The component was re-inserted, but existed when the snapshot was taken.
+        assert_eq!(added.iter(&world).count(), 0);
+        assert_eq!(changed.iter(&world).count(), 1);
+
+        // Values copied in bulk are reported as changed too.
+        world.clear_trackers();
+        world.restore_snapshot(&snapshot);
+        assert_eq!(added.iter(&world).count(), 0);
+        assert_eq!(changed.iter(&world).count(), 1);
+    }
+}
@@ -11,7 +11,13 @@ where
     I::Item: Bundle,
 {
     inner: I,
-    spawner: BundleSpawner<'w, 'w>,
+    spawner: BatchSpawner<'w>,
+}
+
+enum BatchSpawner<'w> {
+    Spawner(BundleSpawner<'w, 'w>),
+    /// Hooks need the whole world, so bundles with hooks are spawned one at a time.
+    World(&'w mut World),
 }
 
 impl<'w, I> SpawnBatchIter<'w, I>
@@ -28,9 +34,17 @@ where
         let (lower, upper) = iter.size_hint();
         let length = upper.unwrap_or(lower);
 
-        let bundle_info = world
+        let bundle_id = world
             .bundles
-            .init_info::<I::Item>(&mut world.components, &mut world.storages);
+            .init_info::<I::Item>(&mut world.components, &mut world.storages)
+            .id();
+        if world.has_component_hooks(world.bundles.get(bundle_id).unwrap().components()) {
+            return Self {
+                inner: iter,
+                spawner: BatchSpawner::World(world),
+            };
+        }
+        let bundle_info = world.bundles.get(bundle_id).unwrap();
         world.entities.reserve(length as u32);
         let mut spawner = bundle_info.get_bundle_spawner(
             &mut world.entities,
@@ -43,7 +57,7 @@ where
 
         Self {
             inner: iter,
-            spawner,
+            spawner: BatchSpawner::Spawner(spawner),
         }
     }
 }
@@ -67,8 +81,11 @@ where
 
     fn next(&mut self) -> Option<Entity> {
         let bundle = self.inner.next()?;
-        // SAFETY: bundle matches spawner type
-        unsafe { Some(self.spawner.spawn(bundle)) }
+        match &mut self.spawner {
+            // SAFETY: bundle matches spawner type
+            BatchSpawner::Spawner(spawner) => unsafe { Some(spawner.spawn(bundle)) },
+            BatchSpawner::World(world) => Some(world.spawn(bundle).id()),
+        }
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
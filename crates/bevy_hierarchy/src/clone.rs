@@ -0,0 +1,202 @@
+use crate::{
+    child_builder::BuildWorldChildren,
+    components::{Children, Parent},
+};
+use bevy_ecs::{
+    entity::Entity,
+    system::{Command, EntityCommands},
+    world::{CloneEntityOptions, World},
+};
+
+/// Clones the given entity and all its descendants into the given destination entity
+#[derive(Debug)]
+pub struct CloneRecursive {
+    /// Entity to clone
+    pub source: Entity,
+    /// Entity receiving the clones of the components of `source`
+    pub destination: Entity,
+    /// Options used to clone `source` and each of its descendants
+    pub options: CloneEntityOptions,
+}
+
+/// Function for cloning an entity and all its descendants, returning the clone
+///
+/// The clone is added to the children of the entity's parent, if it has one.
+/// See [`World::clone_entity_with`] for which components are cloned.
+pub fn clone_entity_recursive(
+    world: &mut World,
+    entity: Entity,
+    options: &CloneEntityOptions,
+) -> Entity {
+    let clone = world.spawn_empty().id();
+    clone_with_children_recursive(world, entity, clone, options);
+    clone
+}
+
+/// Function for cloning the components of an entity and all its descendants into `destination`
+pub fn clone_with_children_recursive(
+    world: &mut World,
+    source: Entity,
+    destination: Entity,
+    options: &CloneEntityOptions,
+) {
+    let options = options.clone().deny::<Parent>().deny::<Children>();
+    clone_with_children_recursive_inner(world, source, destination, &options);
+    if let Some(parent) = world.get::<Parent>(source).map(Parent::get) {
+        world.entity_mut(parent).push_children(&[destination]);
+    }
+}
+
+// Should only be called by `clone_with_children_recursive`!
+fn clone_with_children_recursive_inner(
+    world: &mut World,
+    source: Entity,
+    destination: Entity,
+    options: &CloneEntityOptions,
+) {
+    world.clone_components(source, destination, options);
+    let children = match world.get::<Children>(source) {
+        Some(children) => children.to_vec(),
+        None => return,
+    };
+    let clones: Vec<Entity> = children.iter().map(|_| world.spawn_empty().id()).collect();
+    world.entity_mut(destination).push_children(&clones);
+    for (child, clone) in children.into_iter().zip(clones) {
+        clone_with_children_recursive_inner(world, child, clone, options);
+    }
+}
+
+impl Command for CloneRecursive {
+    fn write(self, world: &mut World) {
+        #[cfg(feature = "trace")]
+        let _span = bevy_utils::tracing::info_span!(
+            "command",
+            name = "CloneRecursive",
+            entity = bevy_utils::tracing::field::debug(self.source)
+        )
+        .entered();
+        clone_with_children_recursive(world, self.source, self.destination, &self.options);
+    }
+}
+
+/// Trait that holds functions for cloning recursively down the transform hierarchy
+pub trait CloneRecursiveExt<'w, 's> {
+    /// Clones the provided entity alongside all descendants, and returns the clone.
+    ///
+    /// The clone is added to the children of the entity's parent, if it has one.
+    fn clone_recursive(&mut self) -> EntityCommands<'w, 's, '_>;
+
+    /// Clones the provided entity alongside all descendants with the given options, and returns
+    /// the clone.
+    ///
+    /// The clone is added to the children of the entity's parent, if it has one.
+    fn clone_recursive_with(&mut self, options: CloneEntityOptions) -> EntityCommands<'w, 's, '_>;
+}
+
+impl<'w, 's, 'a> CloneRecursiveExt<'w, 's> for EntityCommands<'w, 's, 'a> {
+    fn clone_recursive(&mut self) -> EntityCommands<'w, 's, '_> {
+        self.clone_recursive_with(CloneEntityOptions::default())
+    }
+
+    fn clone_recursive_with(&mut self, options: CloneEntityOptions) -> EntityCommands<'w, 's, '_> {
+        let source = self.id();
+        let mut clone = self.commands().spawn_empty();
+        let destination = clone.id();
+        clone.commands().add(CloneRecursive {
+            source,
+            destination,
+            options,
+        });
+        clone
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        system::{CommandQueue, Commands},
+        world::{CloneEntityOptions, World},
+    };
+
+    use super::{clone_entity_recursive, CloneRecursiveExt};
+    use crate::{child_builder::BuildWorldChildren, components::Children, Parent};
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct N(&'static str);
+
+    #[derive(Component, Clone)]
+    struct Secret;
+
+    fn names(world: &World, children: &Children) -> Vec<&'static str> {
+        children
+            .iter()
+            .map(|&child| world.get::<N>(child).unwrap().0)
+            .collect()
+    }
+
+    #[test]
+    fn clone_hierarchy() {
+        let mut world = World::new();
+        let root = world.spawn(N("root")).id();
+        let parent = world.spawn((N("parent"), Secret)).id();
+        world.entity_mut(root).push_children(&[parent]);
+        world.entity_mut(parent).with_children(|parent| {
+            parent.spawn(N("a")).with_children(|a| {
+                a.spawn((N("a1"), Secret));
+            });
+            parent.spawn(N("b"));
+        });
+
+        let options = CloneEntityOptions::default().deny::<Secret>();
+        let clone = clone_entity_recursive(&mut world, parent, &options);
+
+        assert_eq!(world.get::<N>(clone), Some(&N("parent")));
+        assert!(world.get::<Secret>(clone).is_none());
+        assert_eq!(world.get::<Parent>(clone).unwrap().get(), root);
+        assert_eq!(
+            names(&world, world.get::<Children>(root).unwrap()),
+            ["parent", "parent"]
+        );
+
+        let children = world.get::<Children>(clone).unwrap();
+        assert_eq!(names(&world, children), ["a", "b"]);
+        let a = children[0];
+        assert_ne!(a, world.get::<Children>(parent).unwrap()[0]);
+        assert_eq!(world.get::<Parent>(a).unwrap().get(), clone);
+        let a1 = world.get::<Children>(a).unwrap()[0];
+        assert_eq!(world.get::<N>(a1), Some(&N("a1")));
+        assert!(world.get::<Secret>(a1).is_none());
+
+        // The original hierarchy is left untouched.
+        assert_eq!(
+            names(&world, world.get::<Children>(parent).unwrap()),
+            ["a", "b"]
+        );
+    }
+
+    #[test]
+    fn clone_recursive_command() {
+        let mut world = World::new();
+        let parent = world.spawn(N("parent")).id();
+        world.entity_mut(parent).with_children(|parent| {
+            parent.spawn(N("child"));
+        });
+
+        let mut queue = CommandQueue::default();
+        let clone = {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands
+                .entity(parent)
+                .clone_recursive()
+                .insert(Secret)
+                .id()
+        };
+        queue.apply(&mut world);
+
+        assert!(world.get::<Secret>(clone).is_some());
+        assert!(world.get::<Parent>(clone).is_none());
+        let children = world.get::<Children>(clone).unwrap();
+        assert_eq!(names(&world, children), ["child"]);
+    }
+}
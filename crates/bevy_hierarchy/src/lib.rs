@@ -10,6 +10,9 @@ pub use components::*;
 mod hierarchy;
 pub use hierarchy::*;
 
+mod clone;
+pub use clone::*;
+
 mod child_builder;
 pub use child_builder::*;
 
@@ -22,12 +25,15 @@ pub use valid_parent_check_plugin::*;
 mod query_extension;
 pub use query_extension::*;
 
+mod relation;
+pub use relation::*;
+
 #[doc(hidden)]
 pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
-        child_builder::*, components::*, hierarchy::*, query_extension::*, HierarchyPlugin,
-        ValidParentCheckPlugin,
+        child_builder::*, clone::*, components::*, hierarchy::*, query_extension::*, relation::*,
+        HierarchyPlugin, ValidParentCheckPlugin,
     };
 }
 
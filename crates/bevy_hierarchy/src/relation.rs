@@ -0,0 +1,616 @@
+use std::{collections::VecDeque, fmt, marker::PhantomData, ops::Deref};
+
+use bevy_ecs::{
+    component::{Component, ComponentId},
+    entity::{Entity, EntityMap, MapEntities, MapEntitiesError},
+    query::{ReadOnlyWorldQuery, WorldQuery},
+    system::{Command, EntityCommands, Query},
+    world::{EntityMut, World},
+};
+use bevy_utils::HashSet;
+use core::slice;
+use smallvec::SmallVec;
+
+/// A kind of typed, directed relationship between entities, like "targets", "owned by" or
+/// "connected to".
+///
+/// Relating a source entity to a target entity stores the target in the source's
+/// [`RelationTargets<R>`] and the source in the target's [`RelationSources<R>`]. Both sides are
+/// kept in sync: removing either component, or despawning either entity, removes the
+/// relationship from the other side.
+///
+/// # Example
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_hierarchy::prelude::*;
+/// struct OwnedBy;
+///
+/// impl Relation for OwnedBy {
+///     // Items are despawned along with their owner.
+///     const DESPAWN_WITH_TARGET: bool = true;
+/// }
+///
+/// let mut world = World::new();
+/// let owner = world.spawn_empty().id();
+/// let item = world.spawn_empty().relate::<OwnedBy>(owner).id();
+///
+/// assert_eq!(&**world.get::<RelationSources<OwnedBy>>(owner).unwrap(), &[item]);
+///
+/// world.despawn(owner);
+/// assert!(world.get_entity(item).is_none());
+/// ```
+pub trait Relation: Send + Sync + 'static {
+    /// Whether source entities are despawned when one of their targets is despawned.
+    ///
+    /// When `false`, despawning a target only removes it from the relationships of its sources.
+    const DESPAWN_WITH_TARGET: bool = false;
+}
+
+/// Contains the targets of the [`Relation`] `R` of this entity.
+///
+/// Use [`BuildRelations`] or [`BuildWorldRelations`] to change the relationship, and
+/// [`RelationQueryExt`] to traverse it.
+#[derive(Component)]
+pub struct RelationTargets<R: Relation> {
+    entities: SmallVec<[Entity; 8]>,
+    marker: PhantomData<fn() -> R>,
+}
+
+/// Contains the sources of the [`Relation`] `R` targeting this entity.
+///
+/// This is the other side of [`RelationTargets<R>`] and is kept in sync with it.
+#[derive(Component)]
+pub struct RelationSources<R: Relation> {
+    entities: SmallVec<[Entity; 8]>,
+    marker: PhantomData<fn() -> R>,
+}
+
+macro_rules! impl_relation_edges {
+    ($name:ident) => {
+        impl<R: Relation> $name<R> {
+            fn new(entity: Entity) -> Self {
+                Self {
+                    entities: smallvec::smallvec![entity],
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<R: Relation> RelationEdges for $name<R> {
+            fn edges(&self) -> &[Entity] {
+                &self.entities
+            }
+        }
+
+        impl<R: Relation> EdgesMut for $name<R> {
+            fn edges_mut(&mut self) -> &mut SmallVec<[Entity; 8]> {
+                &mut self.entities
+            }
+        }
+
+        impl<R: Relation> fmt::Debug for $name<R> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&self.entities)
+                    .finish()
+            }
+        }
+
+        impl<R: Relation> MapEntities for $name<R> {
+            fn map_entities(&mut self, entity_map: &EntityMap) -> Result<(), MapEntitiesError> {
+                for entity in &mut self.entities {
+                    *entity = entity_map.get(*entity)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        impl<R: Relation> Deref for $name<R> {
+            type Target = [Entity];
+
+            fn deref(&self) -> &Self::Target {
+                &self.entities[..]
+            }
+        }
+
+        impl<'a, R: Relation> IntoIterator for &'a $name<R> {
+            type Item = <Self::IntoIter as Iterator>::Item;
+
+            type IntoIter = slice::Iter<'a, Entity>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.entities.iter()
+            }
+        }
+    };
+}
+
+impl_relation_edges!(RelationTargets);
+impl_relation_edges!(RelationSources);
+
+/// A component storing one side of a [`Relation`], used to traverse it with
+/// [`RelationQueryExt::iter_related`].
+pub trait RelationEdges: Component {
+    /// Returns the entities on the other side of the relationship.
+    fn edges(&self) -> &[Entity];
+}
+
+/// Mutable access to the edges of [`RelationTargets`] and [`RelationSources`], kept private so
+/// that both sides can only be changed together.
+trait EdgesMut {
+    fn edges_mut(&mut self) -> &mut SmallVec<[Entity; 8]>;
+}
+
+/// Registers the hooks keeping both sides of `R` in sync, if not already registered.
+fn register_relation_hooks<R: Relation>(world: &mut World) {
+    world
+        .register_component_hooks::<RelationTargets<R>>()
+        .try_on_remove(on_remove_targets::<R>);
+    world
+        .register_component_hooks::<RelationSources<R>>()
+        .try_on_remove(on_remove_sources::<R>);
+}
+
+/// Removes `source` from the sources of each of its former targets.
+fn on_remove_targets<R: Relation>(world: &mut World, source: Entity, _: ComponentId) {
+    // Taking the list ensures a relationship is only unlinked once, even if the removal
+    // cascades back to this entity.
+    let targets = match world.get_mut::<RelationTargets<R>>(source) {
+        Some(mut targets) => std::mem::take(&mut targets.entities),
+        None => return,
+    };
+    for target in targets {
+        remove_edge::<RelationSources<R>>(world, target, source, false);
+    }
+}
+
+/// Removes `target` from the targets of each of its former sources, despawning them if
+/// [`Relation::DESPAWN_WITH_TARGET`] is set.
+fn on_remove_sources<R: Relation>(world: &mut World, target: Entity, _: ComponentId) {
+    let sources = match world.get_mut::<RelationSources<R>>(target) {
+        Some(mut sources) => std::mem::take(&mut sources.entities),
+        None => return,
+    };
+    for source in sources {
+        remove_edge::<RelationTargets<R>>(world, source, target, R::DESPAWN_WITH_TARGET);
+    }
+}
+
+/// Removes `other` from the `C` edges of `entity`, removing `C` once it is empty.
+///
+/// If `despawn` is set, `entity` is despawned instead.
+fn remove_edge<C: RelationEdges + EdgesMut>(
+    world: &mut World,
+    entity: Entity,
+    other: Entity,
+    despawn: bool,
+) {
+    let mut entity_mut = match world.get_entity_mut(entity) {
+        Some(entity_mut) => entity_mut,
+        None => return,
+    };
+    let is_empty = match entity_mut.get_mut::<C>() {
+        Some(mut edges) => {
+            let edges = edges.edges_mut();
+            edges.retain(|entity| *entity != other);
+            edges.is_empty()
+        }
+        None => return,
+    };
+    if despawn {
+        entity_mut.despawn();
+    } else if is_empty && entity != other {
+        entity_mut.remove::<C>();
+    }
+}
+
+fn relate<R: Relation>(world: &mut World, source: Entity, target: Entity) {
+    register_relation_hooks::<R>(world);
+    let mut source_mut = world.entity_mut(source);
+    if let Some(mut targets) = source_mut.get_mut::<RelationTargets<R>>() {
+        if targets.entities.contains(&target) {
+            return;
+        }
+        targets.entities.push(target);
+    } else {
+        source_mut.insert(RelationTargets::<R>::new(target));
+    }
+    let mut target_mut = world.entity_mut(target);
+    if let Some(mut sources) = target_mut.get_mut::<RelationSources<R>>() {
+        sources.entities.push(source);
+    } else {
+        target_mut.insert(RelationSources::<R>::new(source));
+    }
+}
+
+fn unrelate<R: Relation>(world: &mut World, source: Entity, target: Entity) {
+    let is_related = world
+        .get::<RelationTargets<R>>(source)
+        .map_or(false, |targets| targets.entities.contains(&target));
+    if is_related {
+        remove_edge::<RelationTargets<R>>(world, source, target, false);
+        remove_edge::<RelationSources<R>>(world, target, source, false);
+    }
+}
+
+/// Command that relates `source` to `target` with the [`Relation`] `R`.
+pub struct Relate<R: Relation> {
+    /// The entity the relationship starts from.
+    pub source: Entity,
+    /// The entity the relationship points to.
+    pub target: Entity,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Relation> Command for Relate<R> {
+    fn write(self, world: &mut World) {
+        relate::<R>(world, self.source, self.target);
+    }
+}
+
+/// Command that removes the [`Relation`] `R` from `source` to `target`.
+pub struct Unrelate<R: Relation> {
+    /// The entity the relationship starts from.
+    pub source: Entity,
+    /// The entity the relationship points to.
+    pub target: Entity,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Relation> Command for Unrelate<R> {
+    fn write(self, world: &mut World) {
+        unrelate::<R>(world, self.source, self.target);
+    }
+}
+
+/// Command that removes all the targets of the [`Relation`] `R` from `source`.
+pub struct UnrelateAll<R: Relation> {
+    /// The entity the relationships start from.
+    pub source: Entity,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Relation> Command for UnrelateAll<R> {
+    fn write(self, world: &mut World) {
+        if let Some(mut source) = world.get_entity_mut(self.source) {
+            source.remove::<RelationTargets<R>>();
+        }
+    }
+}
+
+macro_rules! impl_command_debug {
+    ($name:ident { $($field:ident),* }) => {
+        impl<R: Relation> fmt::Debug for $name<R> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($field), &self.$field))*
+                    .finish()
+            }
+        }
+    };
+}
+
+impl_command_debug!(Relate { source, target });
+impl_command_debug!(Unrelate { source, target });
+impl_command_debug!(UnrelateAll { source });
+
+/// Trait that defines relating an entity to others through [`Commands`](bevy_ecs::system::Commands).
+pub trait BuildRelations {
+    /// Relates this entity to `target` with the [`Relation`] `R`.
+    ///
+    /// Does nothing if the entities are already related.
+    fn relate<R: Relation>(&mut self, target: Entity) -> &mut Self;
+    /// Removes the [`Relation`] `R` from this entity to `target`.
+    fn unrelate<R: Relation>(&mut self, target: Entity) -> &mut Self;
+    /// Removes all the targets of the [`Relation`] `R` from this entity.
+    fn unrelate_all<R: Relation>(&mut self) -> &mut Self;
+}
+
+impl<'w, 's, 'a> BuildRelations for EntityCommands<'w, 's, 'a> {
+    fn relate<R: Relation>(&mut self, target: Entity) -> &mut Self {
+        let source = self.id();
+        self.commands().add(Relate::<R> {
+            source,
+            target,
+            marker: PhantomData,
+        });
+        self
+    }
+
+    fn unrelate<R: Relation>(&mut self, target: Entity) -> &mut Self {
+        let source = self.id();
+        self.commands().add(Unrelate::<R> {
+            source,
+            target,
+            marker: PhantomData,
+        });
+        self
+    }
+
+    fn unrelate_all<R: Relation>(&mut self) -> &mut Self {
+        let source = self.id();
+        self.commands().add(UnrelateAll::<R> {
+            source,
+            marker: PhantomData,
+        });
+        self
+    }
+}
+
+/// Trait that defines relating an entity to others directly through the [`World`].
+pub trait BuildWorldRelations {
+    /// Relates this entity to `target` with the [`Relation`] `R`.
+    ///
+    /// Does nothing if the entities are already related.
+    fn relate<R: Relation>(&mut self, target: Entity) -> &mut Self;
+    /// Removes the [`Relation`] `R` from this entity to `target`.
+    fn unrelate<R: Relation>(&mut self, target: Entity) -> &mut Self;
+    /// Removes all the targets of the [`Relation`] `R` from this entity.
+    fn unrelate_all<R: Relation>(&mut self) -> &mut Self;
+}
+
+impl<'w> BuildWorldRelations for EntityMut<'w> {
+    fn relate<R: Relation>(&mut self, target: Entity) -> &mut Self {
+        let source = self.id();
+        self.world_scope(|world| relate::<R>(world, source, target));
+        self
+    }
+
+    fn unrelate<R: Relation>(&mut self, target: Entity) -> &mut Self {
+        let source = self.id();
+        self.world_scope(|world| unrelate::<R>(world, source, target));
+        self
+    }
+
+    fn unrelate_all<R: Relation>(&mut self) -> &mut Self {
+        self.remove::<RelationTargets<R>>();
+        self
+    }
+}
+
+/// An extension trait for [`Query`] that adds methods traversing [`Relation`]s.
+pub trait RelationQueryExt<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> {
+    /// Returns an [`Iterator`] of [`Entity`]s over all the entities transitively related to
+    /// `entity`, excluding `entity` itself.
+    ///
+    /// Follows targets on a [`Query`] of [`RelationTargets<R>`] and sources on a [`Query`] of
+    /// [`RelationSources<R>`]. Traverses the relationship breadth-first, visiting each entity
+    /// once even if the relationship has cycles.
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_hierarchy::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Marker;
+    /// struct ConnectedTo;
+    /// impl Relation for ConnectedTo {}
+    ///
+    /// fn system(
+    ///     query: Query<Entity, With<Marker>>,
+    ///     connections: Query<&RelationTargets<ConnectedTo>>,
+    /// ) {
+    ///     let entity = query.single();
+    ///     for reachable in connections.iter_related(entity) {
+    ///         // Do something!
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    fn iter_related<E: RelationEdges>(&'w self, entity: Entity) -> RelatedIter<'w, 's, Q, F, E>
+    where
+        Q::ReadOnly: WorldQuery<Item<'w> = &'w E>;
+}
+
+impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery> RelationQueryExt<'w, 's, Q, F>
+    for Query<'w, 's, Q, F>
+{
+    fn iter_related<E: RelationEdges>(&'w self, entity: Entity) -> RelatedIter<'w, 's, Q, F, E>
+    where
+        Q::ReadOnly: WorldQuery<Item<'w> = &'w E>,
+    {
+        RelatedIter::new(self, entity)
+    }
+}
+
+/// An [`Iterator`] of [`Entity`]s over the entities transitively related to an [`Entity`].
+///
+/// Traverses the relationship breadth-first.
+pub struct RelatedIter<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery, E: RelationEdges>
+where
+    Q::ReadOnly: WorldQuery<Item<'w> = &'w E>,
+{
+    edges_query: &'w Query<'w, 's, Q, F>,
+    visited: HashSet<Entity>,
+    vecdeque: VecDeque<Entity>,
+}
+
+impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery, E: RelationEdges> RelatedIter<'w, 's, Q, F, E>
+where
+    Q::ReadOnly: WorldQuery<Item<'w> = &'w E>,
+{
+    /// Returns a new [`RelatedIter`].
+    pub fn new(edges_query: &'w Query<'w, 's, Q, F>, entity: Entity) -> Self {
+        let mut iter = RelatedIter {
+            edges_query,
+            visited: HashSet::default(),
+            vecdeque: VecDeque::new(),
+        };
+        iter.visited.insert(entity);
+        iter.visit_edges(entity);
+        iter
+    }
+
+    fn visit_edges(&mut self, entity: Entity) {
+        if let Ok(edges) = self.edges_query.get(entity) {
+            for &other in edges.edges() {
+                if self.visited.insert(other) {
+                    self.vecdeque.push_back(other);
+                }
+            }
+        }
+    }
+}
+
+impl<'w, 's, Q: WorldQuery, F: ReadOnlyWorldQuery, E: RelationEdges> Iterator
+    for RelatedIter<'w, 's, Q, F, E>
+where
+    Q::ReadOnly: WorldQuery<Item<'w> = &'w E>,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entity = self.vecdeque.pop_front()?;
+        self.visit_edges(entity);
+        Some(entity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        system::{CommandQueue, Commands, Query, SystemState},
+        world::World,
+    };
+
+    use super::*;
+
+    struct Targets;
+    impl Relation for Targets {}
+
+    struct OwnedBy;
+    impl Relation for OwnedBy {
+        const DESPAWN_WITH_TARGET: bool = true;
+    }
+
+    fn targets<R: Relation>(world: &World, entity: Entity) -> Vec<Entity> {
+        world
+            .get::<RelationTargets<R>>(entity)
+            .map_or(Vec::new(), |targets| targets.to_vec())
+    }
+
+    fn sources<R: Relation>(world: &World, entity: Entity) -> Vec<Entity> {
+        world
+            .get::<RelationSources<R>>(entity)
+            .map_or(Vec::new(), |sources| sources.to_vec())
+    }
+
+    #[test]
+    fn relate_updates_both_sides() {
+        let world = &mut World::new();
+        let [a, b, c] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        world
+            .entity_mut(a)
+            .relate::<Targets>(b)
+            .relate::<Targets>(c);
+        world.entity_mut(b).relate::<Targets>(c);
+        // Relating twice is a no-op.
+        world.entity_mut(a).relate::<Targets>(b);
+
+        assert_eq!(targets::<Targets>(world, a), vec![b, c]);
+        assert_eq!(targets::<Targets>(world, b), vec![c]);
+        assert_eq!(sources::<Targets>(world, b), vec![a]);
+        assert_eq!(sources::<Targets>(world, c), vec![a, b]);
+        // Different relations are independent.
+        assert!(targets::<OwnedBy>(world, a).is_empty());
+
+        world.entity_mut(a).unrelate::<Targets>(c);
+        assert_eq!(targets::<Targets>(world, a), vec![b]);
+        assert_eq!(sources::<Targets>(world, c), vec![b]);
+
+        world.entity_mut(b).unrelate_all::<Targets>();
+        assert!(world.get::<RelationTargets<Targets>>(b).is_none());
+        assert!(world.get::<RelationSources<Targets>>(c).is_none());
+        assert_eq!(sources::<Targets>(world, b), vec![a]);
+    }
+
+    #[test]
+    fn despawn_cleans_up_both_sides() {
+        let world = &mut World::new();
+        let [a, b, c] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        world.entity_mut(a).relate::<Targets>(b);
+        world.entity_mut(b).relate::<Targets>(c);
+
+        world.despawn(b);
+        assert!(world.get::<RelationTargets<Targets>>(a).is_none());
+        assert!(world.get::<RelationSources<Targets>>(c).is_none());
+        assert!(world.get_entity(a).is_some());
+        assert!(world.get_entity(c).is_some());
+    }
+
+    #[test]
+    fn despawn_with_target() {
+        let world = &mut World::new();
+        let [owner, item, nested, other] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        world.entity_mut(item).relate::<OwnedBy>(owner);
+        world.entity_mut(nested).relate::<OwnedBy>(item);
+        world.entity_mut(other).relate::<OwnedBy>(owner);
+        world.entity_mut(other).unrelate::<OwnedBy>(owner);
+
+        world.despawn(owner);
+        assert!(world.get_entity(item).is_none());
+        assert!(world.get_entity(nested).is_none());
+        assert!(world.get_entity(other).is_some());
+    }
+
+    #[test]
+    fn despawn_with_target_cycle() {
+        let world = &mut World::new();
+        let [a, b] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        world.entity_mut(a).relate::<OwnedBy>(b);
+        world.entity_mut(b).relate::<OwnedBy>(a);
+
+        world.despawn(a);
+        assert!(world.get_entity(a).is_none());
+        assert!(world.get_entity(b).is_none());
+    }
+
+    #[test]
+    fn relate_commands() {
+        let world = &mut World::new();
+        let [a, b] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, world);
+        commands.entity(a).relate::<Targets>(b);
+        queue.apply(world);
+        assert_eq!(targets::<Targets>(world, a), vec![b]);
+        assert_eq!(sources::<Targets>(world, b), vec![a]);
+
+        let mut commands = Commands::new(&mut queue, world);
+        commands.entity(a).unrelate::<Targets>(b);
+        queue.apply(world);
+        assert!(targets::<Targets>(world, a).is_empty());
+        assert!(sources::<Targets>(world, b).is_empty());
+    }
+
+    #[test]
+    fn related_iter() {
+        let world = &mut World::new();
+        let [a, b, c, d] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        world
+            .entity_mut(a)
+            .relate::<Targets>(b)
+            .relate::<Targets>(c);
+        world.entity_mut(c).relate::<Targets>(d);
+        world.entity_mut(d).relate::<Targets>(a);
+
+        let mut system_state = SystemState::<(
+            Query<&RelationTargets<Targets>>,
+            Query<&RelationSources<Targets>>,
+        )>::new(world);
+        let (targets_query, sources_query) = system_state.get(world);
+
+        let forward: Vec<_> = targets_query.iter_related(a).collect();
+        assert_eq!(forward, vec![b, c, d]);
+
+        let backward: Vec<_> = sources_query.iter_related(b).collect();
+        assert_eq!(backward, vec![a, d, c]);
+    }
+}
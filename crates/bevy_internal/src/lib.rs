@@ -173,5 +173,11 @@ pub mod dynamic_plugin {
     pub use bevy_dynamic_plugin::*;
 }
 
+#[cfg(feature = "bevy_remote")]
+pub mod remote {
+    //! Inspection of running apps by external tools over a local socket
+    pub use bevy_remote::*;
+}
+
 #[cfg(target_os = "android")]
 pub use ndk_glue;
@@ -12,7 +12,7 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::token::Comma;
-use syn::{Meta, NestedMeta, Path};
+use syn::{Lit, Meta, NestedMeta, Path};
 
 // The "special" trait idents that are used internally for reflection.
 // Received via attributes like `#[reflect(PartialEq, Hash, ...)]`
@@ -20,6 +20,12 @@ const DEBUG_ATTR: &str = "Debug";
 const PARTIAL_EQ_ATTR: &str = "PartialEq";
 const HASH_ATTR: &str = "Hash";
 
+// The attribute listing the methods to register, like `#[reflect(methods(reset, ...))]`
+const METHODS_ATTR: &str = "methods";
+
+// The attribute disabling the `TypePath` derive, like `#[reflect(type_path = false)]`
+const TYPE_PATH_ATTR: &str = "type_path";
+
 // The traits listed below are not considered "special" (i.e. they use the `ReflectMyTrait` syntax)
 // but useful to know exist nonetheless
 pub(crate) const REFLECT_DEFAULT: &str = "ReflectDefault";
@@ -118,12 +124,33 @@ impl TraitImpl {
 ///
 /// > __Note:__ Registering a custom function only works for special traits.
 ///
+/// Methods of the type can also be registered as `ReflectMethods` type data, using their name:
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(methods(reset))]
+/// struct Timer {
+///   elapsed: f32,
+/// }
+///
+/// impl Timer {
+///   fn reset(&mut self) {
+///     self.elapsed = 0.0;
+///   }
+/// }
+/// ```
+///
+/// The `TypePath` implementation of the derive can be disabled with `#[reflect(type_path = false)]`,
+/// for types whose type parameters can't be required to implement `TypePath`.
+///
 #[derive(Default, Clone)]
 pub(crate) struct ReflectTraits {
     debug: TraitImpl,
     hash: TraitImpl,
     partial_eq: TraitImpl,
     idents: Vec<Ident>,
+    methods: Vec<Ident>,
+    skip_type_path: bool,
 }
 
 impl ReflectTraits {
@@ -177,6 +204,28 @@ impl ReflectTraits {
 
                     let ident = segment.ident.to_string();
 
+                    if ident == METHODS_ATTR {
+                        for nested in list.nested.iter() {
+                            match nested {
+                                NestedMeta::Meta(Meta::Path(path))
+                                    if path.get_ident().is_some() =>
+                                {
+                                    add_unique_method(
+                                        &mut traits.methods,
+                                        path.get_ident().unwrap().clone(),
+                                    )?;
+                                }
+                                _ => {
+                                    return Err(syn::Error::new(
+                                        nested.span(),
+                                        "expected the name of a method",
+                                    ))
+                                }
+                            }
+                        }
+                        continue;
+                    }
+
                     // Track the span where the trait is implemented for future errors
                     let span = ident.span();
 
@@ -198,6 +247,18 @@ impl ReflectTraits {
                         }
                     }
                 }
+                // Handles `#[reflect( type_path = false )]`
+                NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident(TYPE_PATH_ATTR) => {
+                    match &pair.lit {
+                        Lit::Bool(lit) if !lit.value => traits.skip_type_path = true,
+                        lit => {
+                            return Err(syn::Error::new(
+                                lit.span(),
+                                "expected `false` to disable the `TypePath` implementation",
+                            ))
+                        }
+                    }
+                }
                 _ => {}
             }
         }
@@ -216,6 +277,16 @@ impl ReflectTraits {
         &self.idents
     }
 
+    /// The names of the methods registered with `#[reflect(methods(...))]`.
+    pub fn methods(&self) -> &[Ident] {
+        &self.methods
+    }
+
+    /// Returns true if `TypePath` is implemented manually, using `#[reflect(type_path = false)]`.
+    pub fn skip_type_path(&self) -> bool {
+        self.skip_type_path
+    }
+
     /// Returns the implementation of `Reflect::reflect_hash` as a `TokenStream`.
     ///
     /// If `Hash` was not registered, returns `None`.
@@ -300,6 +371,14 @@ impl ReflectTraits {
                 }
                 idents
             },
+            methods: {
+                let mut methods = self.methods;
+                for method in other.methods {
+                    add_unique_method(&mut methods, method)?;
+                }
+                methods
+            },
+            skip_type_path: self.skip_type_path || other.skip_type_path,
         })
     }
 }
@@ -323,3 +402,18 @@ fn add_unique_ident(idents: &mut Vec<Ident>, ident: Ident) -> Result<(), syn::Er
     idents.push(ident);
     Ok(())
 }
+
+/// Adds a method name to a vector of method names if it is not already present.
+///
+/// Returns an error if the method already exists in the list.
+fn add_unique_method(methods: &mut Vec<Ident>, method: Ident) -> Result<(), syn::Error> {
+    if methods.contains(&method) {
+        return Err(syn::Error::new(
+            method.span(),
+            "method registered multiple times",
+        ));
+    }
+
+    methods.push(method);
+    Ok(())
+}
@@ -101,7 +101,6 @@ pub(crate) struct EnumVariant<'a> {
     /// The fields within this variant.
     pub fields: EnumVariantFields<'a>,
     /// The reflection-based attributes on the variant.
-    #[allow(dead_code)]
     pub attrs: ReflectFieldAttr,
     /// The index of this variant within the enum.
     #[allow(dead_code)]
@@ -320,7 +319,7 @@ impl<'a> ReflectMeta<'a> {
         crate::registration::impl_get_type_registration(
             self.type_name,
             &self.bevy_reflect_path,
-            self.traits.idents(),
+            &self.traits,
             self.generics,
             None,
         )
@@ -356,7 +355,7 @@ impl<'a> ReflectStruct<'a> {
         crate::registration::impl_get_type_registration(
             self.meta.type_name(),
             reflect_path,
-            self.meta.traits().idents(),
+            self.meta.traits(),
             self.meta.generics(),
             Some(&self.serialization_denylist),
         )
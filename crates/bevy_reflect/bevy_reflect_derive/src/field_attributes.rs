@@ -5,15 +5,22 @@
 //! the derive helper attribute for `Reflect`, which looks like: `#[reflect(ignore)]`.
 
 use crate::REFLECT_ATTRIBUTE_NAME;
-use quote::ToTokens;
+use proc_macro2::TokenTree;
+use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
-use syn::{Attribute, Lit, Meta, NestedMeta};
+use syn::{Attribute, Expr, ExprRange, Lit, LitStr, Meta, NestedMeta, Path, RangeLimits, Token};
 
 pub(crate) static IGNORE_SERIALIZATION_ATTR: &str = "skip_serializing";
 pub(crate) static IGNORE_ALL_ATTR: &str = "ignore";
 
 pub(crate) static DEFAULT_ATTR: &str = "default";
 
+pub(crate) static RANGE_ATTR: &str = "range";
+pub(crate) static STEP_ATTR: &str = "step";
+pub(crate) static TOOLTIP_ATTR: &str = "tooltip";
+pub(crate) static READ_ONLY_ATTR: &str = "read_only";
+pub(crate) static HIDDEN_ATTR: &str = "hidden";
+
 /// Stores data about if the field should be visible via the Reflect and serialization interfaces
 ///
 /// Note the relationship between serialization and reflection is such that a member must be reflected in order to be serialized.
@@ -52,6 +59,56 @@ pub(crate) struct ReflectFieldAttr {
     pub ignore: ReflectIgnoreBehavior,
     /// Sets the default behavior of this field.
     pub default: DefaultBehavior,
+    /// Metadata for inspectors, exposed as `FieldAttributes`.
+    pub metadata: FieldMetadata,
+}
+
+/// Metadata for inspectors declared on a field or variant, like `#[reflect(read_only)]`.
+#[derive(Default)]
+pub(crate) struct FieldMetadata {
+    pub min: Option<Expr>,
+    pub max: Option<Expr>,
+    pub step: Option<f64>,
+    pub tooltip: Option<LitStr>,
+    pub read_only: bool,
+    pub hidden: bool,
+    /// The values declared with `#[reflect(@expr)]`.
+    pub custom: Vec<Expr>,
+}
+
+impl FieldMetadata {
+    /// Returns the `.with_attributes(...)` call setting this metadata, if any was declared.
+    pub fn with_attributes(&self, bevy_reflect_path: &Path) -> Option<proc_macro2::TokenStream> {
+        let mut setters = Vec::new();
+        if let Some(min) = &self.min {
+            setters.push(quote!(.with_min((#min) as f64)));
+        }
+        if let Some(max) = &self.max {
+            setters.push(quote!(.with_max((#max) as f64)));
+        }
+        if let Some(step) = self.step {
+            let step = proc_macro2::Literal::f64_suffixed(step);
+            setters.push(quote!(.with_step(#step)));
+        }
+        if let Some(tooltip) = &self.tooltip {
+            setters.push(quote!(.with_tooltip(#tooltip)));
+        }
+        if self.read_only {
+            setters.push(quote!(.with_read_only(true)));
+        }
+        if self.hidden {
+            setters.push(quote!(.with_hidden(true)));
+        }
+        for value in &self.custom {
+            setters.push(quote!(.with_custom_attribute(#value)));
+        }
+        if setters.is_empty() {
+            return None;
+        }
+        Some(quote! {
+            .with_attributes(#bevy_reflect_path::FieldAttributes::default() #(#setters)*)
+        })
+    }
 }
 
 /// Controls how the default value is determined for a field.
@@ -78,6 +135,14 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
         .iter()
         .filter(|a| a.path.is_ident(REFLECT_ATTRIBUTE_NAME));
     for attr in attrs {
+        if is_custom_attribute(attr) {
+            let value = attr.parse_args_with(|input: syn::parse::ParseStream| {
+                input.parse::<Token![@]>()?;
+                input.parse::<Expr>()
+            })?;
+            args.metadata.custom.push(value);
+            continue;
+        }
         let meta = attr.parse_meta()?;
         if let Err(err) = parse_meta(&mut args, &meta) {
             if let Some(ref mut error) = errors {
@@ -95,6 +160,17 @@ pub(crate) fn parse_field_attrs(attrs: &[Attribute]) -> Result<ReflectFieldAttr,
     }
 }
 
+/// Returns `true` for custom attributes like `#[reflect(@expr)]`, which are not valid [`Meta`].
+fn is_custom_attribute(attr: &Attribute) -> bool {
+    let Some(TokenTree::Group(group)) = attr.tokens.clone().into_iter().next() else {
+        return false;
+    };
+    matches!(
+        group.stream().into_iter().next(),
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '@'
+    )
+}
+
 /// Recursively parses attribute metadata for things like `#[reflect(ignore)]` and `#[reflect(default = "foo")]`
 fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error> {
     match meta {
@@ -112,6 +188,14 @@ fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error
             args.default = DefaultBehavior::Default;
             Ok(())
         }
+        Meta::Path(path) if path.is_ident(READ_ONLY_ATTR) => {
+            args.metadata.read_only = true;
+            Ok(())
+        }
+        Meta::Path(path) if path.is_ident(HIDDEN_ATTR) => {
+            args.metadata.hidden = true;
+            Ok(())
+        }
         Meta::Path(path) => Err(syn::Error::new(
             path.span(),
             format!("unknown attribute parameter: {}", path.to_token_stream()),
@@ -131,6 +215,54 @@ fn parse_meta(args: &mut ReflectFieldAttr, meta: &Meta) -> Result<(), syn::Error
                 }
             }
         }
+        Meta::NameValue(pair) if pair.path.is_ident(RANGE_ATTR) => match &pair.lit {
+            Lit::Str(lit_str) => {
+                let range: ExprRange = lit_str.parse()?;
+                if let (RangeLimits::HalfOpen(_), Some(_)) = (&range.limits, &range.to) {
+                    return Err(syn::Error::new(
+                        lit_str.span(),
+                        "expected an inclusive range like \"0.0..=1.0\"",
+                    ));
+                }
+                args.metadata.min = range.from.map(|from| *from);
+                args.metadata.max = range.to.map(|to| *to);
+                Ok(())
+            }
+            err => Err(syn::Error::new(
+                err.span(),
+                format!(
+                    "expected a string literal containing a range, but found: {}",
+                    err.to_token_stream()
+                ),
+            )),
+        },
+        Meta::NameValue(pair) if pair.path.is_ident(STEP_ATTR) => match &pair.lit {
+            Lit::Float(lit) => {
+                args.metadata.step = Some(lit.base10_parse()?);
+                Ok(())
+            }
+            Lit::Int(lit) => {
+                args.metadata.step = Some(lit.base10_parse()?);
+                Ok(())
+            }
+            err => Err(syn::Error::new(
+                err.span(),
+                format!("expected a number, but found: {}", err.to_token_stream()),
+            )),
+        },
+        Meta::NameValue(pair) if pair.path.is_ident(TOOLTIP_ATTR) => match &pair.lit {
+            Lit::Str(lit_str) => {
+                args.metadata.tooltip = Some(lit_str.clone());
+                Ok(())
+            }
+            err => Err(syn::Error::new(
+                err.span(),
+                format!(
+                    "expected a string literal, but found: {}",
+                    err.to_token_stream()
+                ),
+            )),
+        },
         Meta::NameValue(pair) => {
             let path = &pair.path;
             Err(syn::Error::new(
@@ -349,18 +349,20 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
         }
 
         let mut push_variant =
-            |_variant: &EnumVariant, arguments: proc_macro2::TokenStream, field_len: usize| {
+            |variant: &EnumVariant, arguments: proc_macro2::TokenStream, field_len: usize| {
                 #[cfg(feature = "documentation")]
                 let with_docs = {
-                    let doc = quote::ToTokens::to_token_stream(&_variant.doc);
+                    let doc = quote::ToTokens::to_token_stream(&variant.doc);
                     Some(quote!(.with_docs(#doc)))
                 };
                 #[cfg(not(feature = "documentation"))]
                 let with_docs: Option<proc_macro2::TokenStream> = None;
 
+                let with_attributes = variant.attrs.metadata.with_attributes(bevy_reflect_path);
                 variant_info.push(quote! {
                     #bevy_reflect_path::VariantInfo::#variant_type_ident(
                         #bevy_reflect_path::#variant_info_ident::new(#arguments)
+                        #with_attributes
                         #with_docs
                     )
                 });
@@ -391,9 +393,11 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     #[cfg(not(feature = "documentation"))]
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
+                    let with_attributes = field.attrs.metadata.with_attributes(bevy_reflect_path);
                     let field_ty = &field.data.ty;
                     quote! {
                         #bevy_reflect_path::UnnamedField::new::<#field_ty>(#reflect_idx)
+                        #with_attributes
                         #with_docs
                     }
                 });
@@ -426,9 +430,11 @@ fn generate_impls(reflect_enum: &ReflectEnum, ref_index: &Ident, ref_name: &Iden
                     #[cfg(not(feature = "documentation"))]
                     let with_docs: Option<proc_macro2::TokenStream> = None;
 
+                    let with_attributes = field.attrs.metadata.with_attributes(bevy_reflect_path);
                     let field_ty = &field.data.ty;
                     quote! {
                         #bevy_reflect_path::NamedField::new::<#field_ty>(#field_name)
+                        #with_attributes
                         #with_docs
                     }
                 });
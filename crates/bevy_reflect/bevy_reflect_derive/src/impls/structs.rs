@@ -51,20 +51,25 @@ pub(crate) fn impl_struct(reflect_struct: &ReflectStruct) -> TokenStream {
             }
         });
 
+    let field_attributes = reflect_struct
+        .active_fields()
+        .map(|field| field.attrs.metadata.with_attributes(bevy_reflect_path))
+        .collect::<Vec<_>>();
+
     #[cfg(feature = "documentation")]
     let field_generator = {
         let docs = reflect_struct
             .active_fields()
             .map(|field| quote::ToTokens::to_token_stream(&field.doc));
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names).with_docs(#docs) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) #field_attributes .with_docs(#docs) ,)*
         }
     };
 
     #[cfg(not(feature = "documentation"))]
     let field_generator = {
         quote! {
-            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) ,)*
+            #(#bevy_reflect_path::NamedField::new::<#field_types>(#field_names) #field_attributes ,)*
         }
     };
 
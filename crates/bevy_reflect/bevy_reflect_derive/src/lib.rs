@@ -4,11 +4,12 @@
 //! types to easily implement `Reflect` along with other `bevy_reflect` traits,
 //! such as `Struct`, `GetTypeRegistration`, and more— all with a single derive!
 //!
-//! Some other noteworthy exports include the derive macros for [`FromReflect`] and
-//! [`TypeUuid`], as well as the [`reflect_trait`] attribute macro.
+//! Some other noteworthy exports include the derive macros for [`FromReflect`],
+//! [`TypePath`] and [`TypeUuid`], as well as the [`reflect_trait`] attribute macro.
 //!
 //! [`Reflect`]: crate::derive_reflect
 //! [`FromReflect`]: crate::derive_from_reflect
+//! [`TypePath`]: crate::derive_type_path
 //! [`TypeUuid`]: crate::derive_type_uuid
 //! [`reflect_trait`]: macro@reflect_trait
 
@@ -25,6 +26,7 @@ mod impls;
 mod reflect_value;
 mod registration;
 mod trait_reflection;
+mod type_path;
 mod type_uuid;
 mod utility;
 
@@ -47,14 +49,32 @@ pub fn derive_reflect(input: TokenStream) -> TokenStream {
         Err(err) => return err.into_compile_error().into(),
     };
 
-    match derive_data {
+    let meta = match &derive_data {
+        ReflectDerive::Struct(struct_data)
+        | ReflectDerive::TupleStruct(struct_data)
+        | ReflectDerive::UnitStruct(struct_data) => struct_data.meta(),
+        ReflectDerive::Enum(enum_data) => enum_data.meta(),
+        ReflectDerive::Value(meta) => meta,
+    };
+    let type_path_impl = (!meta.traits().skip_type_path()).then(|| {
+        type_path::impl_type_path(meta.type_name(), meta.generics(), meta.bevy_reflect_path())
+    });
+
+    let reflect_impl: proc_macro2::TokenStream = match &derive_data {
         ReflectDerive::Struct(struct_data) | ReflectDerive::UnitStruct(struct_data) => {
-            impls::impl_struct(&struct_data)
+            impls::impl_struct(struct_data)
         }
-        ReflectDerive::TupleStruct(struct_data) => impls::impl_tuple_struct(&struct_data),
-        ReflectDerive::Enum(meta) => impls::impl_enum(&meta),
-        ReflectDerive::Value(meta) => impls::impl_value(&meta),
+        ReflectDerive::TupleStruct(struct_data) => impls::impl_tuple_struct(struct_data),
+        ReflectDerive::Enum(meta) => impls::impl_enum(meta),
+        ReflectDerive::Value(meta) => impls::impl_value(meta),
     }
+    .into();
+
+    TokenStream::from(quote! {
+        #reflect_impl
+
+        #type_path_impl
+    })
 }
 
 /// Derives the `FromReflect` trait.
@@ -83,6 +103,22 @@ pub fn derive_from_reflect(input: TokenStream) -> TokenStream {
     }
 }
 
+/// Derives the `TypePath` trait, giving the type a stable path made of its module path and name.
+///
+/// This is already implemented by the `Reflect` derive, and is meant for types which are
+/// only used as type parameters of reflected types.
+#[proc_macro_derive(TypePath)]
+pub fn derive_type_path(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_reflect_path = utility::get_bevy_reflect_path();
+
+    TokenStream::from(type_path::impl_type_path(
+        &ast.ident,
+        &ast.generics,
+        &bevy_reflect_path,
+    ))
+}
+
 // From https://github.com/randomPoison/type-uuid
 #[proc_macro_derive(TypeUuid, attributes(uuid))]
 pub fn derive_type_uuid(input: TokenStream) -> TokenStream {
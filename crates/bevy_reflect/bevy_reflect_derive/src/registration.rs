@@ -1,5 +1,7 @@
 //! Contains code related specifically to Bevy's type registration.
 
+use crate::container_attributes::ReflectTraits;
+use crate::type_path::add_type_path_bounds;
 use bit_set::BitSet;
 use proc_macro2::Ident;
 use quote::quote;
@@ -9,11 +11,20 @@ use syn::{Generics, Path};
 pub(crate) fn impl_get_type_registration(
     type_name: &Ident,
     bevy_reflect_path: &Path,
-    registration_data: &[Ident],
+    traits: &ReflectTraits,
     generics: &Generics,
     serialization_denylist: Option<&BitSet<u32>>,
 ) -> proc_macro2::TokenStream {
+    // Registering a type requires its `TypePath`, which in turn requires the type path of its
+    // type parameters unless it is implemented manually.
+    let generics = if traits.skip_type_path() {
+        generics.clone()
+    } else {
+        add_type_path_bounds(generics, bevy_reflect_path)
+    };
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let registration_data = traits.idents();
+    let methods = traits.methods();
     let serialization_data = serialization_denylist.map(|denylist| {
         let denylist = denylist.into_iter();
         quote! {
@@ -21,6 +32,7 @@ pub(crate) fn impl_get_type_registration(
             registration.insert::<#bevy_reflect_path::serde::SerializationData>(#bevy_reflect_path::serde::SerializationData::new(ignored_indices));
         }
     });
+    let method_names = methods.iter().map(|method| method.to_string());
 
     quote! {
         #[allow(unused_mut)]
@@ -30,6 +42,7 @@ pub(crate) fn impl_get_type_registration(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<#type_name #ty_generics>::from_type());
                 #serialization_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<#type_name #ty_generics>::from_type());)*
+                #(registration.add_method(#bevy_reflect_path::ReflectFunction::new(#method_names, <#type_name #ty_generics>::#methods));)*
                 registration
             }
         }
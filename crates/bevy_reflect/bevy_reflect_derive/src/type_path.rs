@@ -0,0 +1,94 @@
+//! Contains code related to the `TypePath` trait.
+
+use proc_macro2::Ident;
+use quote::quote;
+use syn::{GenericParam, Generics, Path};
+
+/// Creates the `TypePath` impl for the given type.
+///
+/// The module path comes from `module_path!` at the definition of the type. Type parameters
+/// must implement `TypePath` themselves, const parameters are written with their value and
+/// lifetimes are left out.
+pub(crate) fn impl_type_path(
+    type_name: &Ident,
+    generics: &Generics,
+    bevy_reflect_path: &Path,
+) -> proc_macro2::TokenStream {
+    let type_path_generics = add_type_path_bounds(generics, bevy_reflect_path);
+    let (impl_generics, ty_generics, where_clause) = type_path_generics.split_for_impl();
+    let name = type_name.to_string();
+
+    let is_generic =
+        generics.type_params().next().is_some() || generics.const_params().next().is_some();
+
+    if !is_generic {
+        return quote! {
+            impl #impl_generics #bevy_reflect_path::TypePath for #type_name #ty_generics #where_clause {
+                fn type_path() -> &'static str {
+                    concat!(module_path!(), "::", #name)
+                }
+
+                fn short_type_path() -> &'static str {
+                    #name
+                }
+            }
+        };
+    }
+
+    let param_paths = |method: Ident| {
+        generics.params.iter().filter_map(move |param| match param {
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                Some(quote!(<#ident as #bevy_reflect_path::TypePath>::#method().to_string()))
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                Some(quote!(#ident.to_string()))
+            }
+            GenericParam::Lifetime(..) => None,
+        })
+    };
+    let long_params = param_paths(Ident::new("type_path", type_name.span()));
+    let short_params = param_paths(Ident::new("short_type_path", type_name.span()));
+
+    quote! {
+        impl #impl_generics #bevy_reflect_path::TypePath for #type_name #ty_generics #where_clause {
+            fn type_path() -> &'static str {
+                static CELL: #bevy_reflect_path::utility::GenericTypePathCell = #bevy_reflect_path::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    format!(
+                        "{}::{}<{}>",
+                        module_path!(),
+                        #name,
+                        [#(#long_params),*].join(", ")
+                    )
+                })
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: #bevy_reflect_path::utility::GenericTypePathCell = #bevy_reflect_path::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    format!("{}<{}>", #name, [#(#short_params),*].join(", "))
+                })
+            }
+        }
+    }
+}
+
+/// Returns a copy of `generics` requiring every type parameter to implement `TypePath`.
+pub(crate) fn add_type_path_bounds(generics: &Generics, bevy_reflect_path: &Path) -> Generics {
+    let mut generics = generics.clone();
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    if !params.is_empty() {
+        let where_clause = generics.make_where_clause();
+        for param in params {
+            where_clause
+                .predicates
+                .push(syn::parse_quote!(#param: #bevy_reflect_path::TypePath));
+        }
+    }
+    generics
+}
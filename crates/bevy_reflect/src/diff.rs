@@ -0,0 +1,612 @@
+use crate::{GetPath, Reflect, ReflectMut, ReflectPathError, ReflectRef};
+use std::fmt;
+
+/// A single change of a [`ReflectDiff`], addressed by a [path] relative to the diffed value.
+///
+/// [path]: GetPath
+pub enum ReflectChange {
+    /// The value at `path` is replaced by `value`.
+    ///
+    /// Unlike with [`Reflect::apply`], the keys of maps and the elements of lists missing from
+    /// `value` are removed.
+    Set {
+        path: String,
+        value: Box<dyn Reflect>,
+    },
+    /// `value` is inserted at `key` in the [`Map`] at `path`, replacing any previous value.
+    Insert {
+        path: String,
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    },
+    /// `key` is removed from the [`Map`] at `path`.
+    Remove { path: String, key: Box<dyn Reflect> },
+    /// `value` is pushed at the end of the [`List`](crate::List) at `path`.
+    Push {
+        path: String,
+        value: Box<dyn Reflect>,
+    },
+    /// Elements are popped from the [`List`](crate::List) at `path` until it has `len` elements.
+    Truncate { path: String, len: usize },
+}
+
+impl ReflectChange {
+    /// Returns the path of the value this change applies to.
+    pub fn path(&self) -> &str {
+        match self {
+            ReflectChange::Set { path, .. }
+            | ReflectChange::Insert { path, .. }
+            | ReflectChange::Remove { path, .. }
+            | ReflectChange::Push { path, .. }
+            | ReflectChange::Truncate { path, .. } => path,
+        }
+    }
+
+    /// Applies this change to `target`.
+    pub fn apply<'p>(&'p self, target: &mut dyn Reflect) -> Result<(), ReflectPathError<'p>> {
+        match self {
+            ReflectChange::Set { path, value } => replace(target.path_mut(path)?, value.as_ref()),
+            ReflectChange::Insert { path, key, value } => {
+                match target.path_mut(path)?.reflect_mut() {
+                    ReflectMut::Map(map) => {
+                        map.insert_boxed(key.clone_value(), value.clone_value());
+                    }
+                    _ => return Err(ReflectPathError::ExpectedMap { index: path.len() }),
+                }
+            }
+            ReflectChange::Remove { path, key } => match target.path_mut(path)?.reflect_mut() {
+                ReflectMut::Map(map) => {
+                    map.remove(key.as_ref());
+                }
+                _ => return Err(ReflectPathError::ExpectedMap { index: path.len() }),
+            },
+            ReflectChange::Push { path, value } => match target.path_mut(path)?.reflect_mut() {
+                ReflectMut::List(list) => list.push(value.clone_value()),
+                _ => return Err(ReflectPathError::ExpectedList { index: path.len() }),
+            },
+            ReflectChange::Truncate { path, len } => match target.path_mut(path)?.reflect_mut() {
+                ReflectMut::List(list) => {
+                    while list.len() > *len {
+                        list.pop();
+                    }
+                }
+                _ => return Err(ReflectPathError::ExpectedList { index: path.len() }),
+            },
+        }
+        Ok(())
+    }
+}
+
+impl Clone for ReflectChange {
+    fn clone(&self) -> Self {
+        match self {
+            ReflectChange::Set { path, value } => ReflectChange::Set {
+                path: path.clone(),
+                value: value.clone_value(),
+            },
+            ReflectChange::Insert { path, key, value } => ReflectChange::Insert {
+                path: path.clone(),
+                key: key.clone_value(),
+                value: value.clone_value(),
+            },
+            ReflectChange::Remove { path, key } => ReflectChange::Remove {
+                path: path.clone(),
+                key: key.clone_value(),
+            },
+            ReflectChange::Push { path, value } => ReflectChange::Push {
+                path: path.clone(),
+                value: value.clone_value(),
+            },
+            ReflectChange::Truncate { path, len } => ReflectChange::Truncate {
+                path: path.clone(),
+                len: *len,
+            },
+        }
+    }
+}
+
+impl fmt::Debug for ReflectChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReflectChange::Set { path, value } => f
+                .debug_struct("Set")
+                .field("path", path)
+                .field("value", &value.as_ref())
+                .finish(),
+            ReflectChange::Insert { path, key, value } => f
+                .debug_struct("Insert")
+                .field("path", path)
+                .field("key", &key.as_ref())
+                .field("value", &value.as_ref())
+                .finish(),
+            ReflectChange::Remove { path, key } => f
+                .debug_struct("Remove")
+                .field("path", path)
+                .field("key", &key.as_ref())
+                .finish(),
+            ReflectChange::Push { path, value } => f
+                .debug_struct("Push")
+                .field("path", path)
+                .field("value", &value.as_ref())
+                .finish(),
+            ReflectChange::Truncate { path, len } => f
+                .debug_struct("Truncate")
+                .field("path", path)
+                .field("len", len)
+                .finish(),
+        }
+    }
+}
+
+/// The structural difference between two reflected values.
+///
+/// A diff lists the values that changed from `old` to `new`, addressed by [path]. Applying it
+/// to a value equal to `old` turns it into a value equal to `new`, which makes it suitable for
+/// undo/redo stacks, network deltas or override files. It can be serialized with
+/// [`ReflectDiffSerializer`](crate::serde::ReflectDiffSerializer).
+///
+/// Structs, tuple structs, tuples, arrays and lists are compared field by field, and maps key by
+/// key. Enums can't be addressed by path, so they are replaced as a whole when they differ, as
+/// are the values of maps.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, ReflectDiff};
+/// #[derive(Reflect, Clone, PartialEq, Debug)]
+/// struct Player {
+///     name: String,
+///     health: f32,
+///     items: Vec<u32>,
+/// }
+///
+/// let old = Player { name: "Ferris".to_string(), health: 100.0, items: vec![1, 2] };
+/// let new = Player { name: "Ferris".to_string(), health: 80.0, items: vec![1] };
+///
+/// let diff = ReflectDiff::new(&old, &new);
+/// assert_eq!(diff.len(), 2);
+///
+/// let mut value = old.clone();
+/// diff.apply(&mut value).unwrap();
+/// assert_eq!(value, new);
+/// ```
+///
+/// [path]: crate::GetPath
+#[derive(Clone, Debug, Default)]
+pub struct ReflectDiff {
+    changes: Vec<ReflectChange>,
+}
+
+impl ReflectDiff {
+    /// Computes the changes turning `old` into `new`.
+    pub fn new(old: &dyn Reflect, new: &dyn Reflect) -> Self {
+        let mut diff = ReflectDiff::default();
+        diff.diff(&mut String::new(), old, new);
+        diff
+    }
+
+    /// Creates a diff from a list of changes.
+    pub fn from_changes(changes: Vec<ReflectChange>) -> Self {
+        ReflectDiff { changes }
+    }
+
+    /// Returns the changes of this diff, in the order they are applied.
+    pub fn changes(&self) -> &[ReflectChange] {
+        &self.changes
+    }
+
+    /// Returns the number of changes of this diff.
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns `true` if the diffed values were equal.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Applies the changes of this diff to `target`.
+    ///
+    /// Changes applied before an error are kept.
+    pub fn apply(&self, target: &mut dyn Reflect) -> Result<(), ReflectPathError<'_>> {
+        for change in &self.changes {
+            change.apply(target)?;
+        }
+        Ok(())
+    }
+
+    fn set(&mut self, path: &str, new: &dyn Reflect) {
+        self.changes.push(ReflectChange::Set {
+            path: path.to_string(),
+            value: new.clone_value(),
+        });
+    }
+
+    /// Appends the changes between `old` and `new` to this diff, `path` being the path of both.
+    fn diff(&mut self, path: &mut String, old: &dyn Reflect, new: &dyn Reflect) {
+        if old.type_name() != new.type_name() {
+            self.set(path, new);
+            return;
+        }
+        match (old.reflect_ref(), new.reflect_ref()) {
+            (ReflectRef::Struct(old_struct), ReflectRef::Struct(new_struct)) => {
+                for (index, new_field) in new_struct.iter_fields().enumerate() {
+                    let name = new_struct.name_at(index).unwrap();
+                    match old_struct.field(name) {
+                        Some(old_field) => {
+                            let len = path.len();
+                            if !path.is_empty() {
+                                path.push('.');
+                            }
+                            path.push_str(name);
+                            self.diff(path, old_field, new_field);
+                            path.truncate(len);
+                        }
+                        None => {
+                            self.set(path, new);
+                            return;
+                        }
+                    }
+                }
+            }
+            (ReflectRef::TupleStruct(old_struct), ReflectRef::TupleStruct(new_struct)) => {
+                if old_struct.field_len() != new_struct.field_len() {
+                    self.set(path, new);
+                    return;
+                }
+                for (index, (old_field, new_field)) in old_struct
+                    .iter_fields()
+                    .zip(new_struct.iter_fields())
+                    .enumerate()
+                {
+                    self.diff_field(path, index, old_field, new_field);
+                }
+            }
+            (ReflectRef::Tuple(old_tuple), ReflectRef::Tuple(new_tuple)) => {
+                if old_tuple.field_len() != new_tuple.field_len() {
+                    self.set(path, new);
+                    return;
+                }
+                for (index, (old_field, new_field)) in old_tuple
+                    .iter_fields()
+                    .zip(new_tuple.iter_fields())
+                    .enumerate()
+                {
+                    self.diff_field(path, index, old_field, new_field);
+                }
+            }
+            (ReflectRef::Array(old_array), ReflectRef::Array(new_array)) => {
+                if old_array.len() != new_array.len() {
+                    self.set(path, new);
+                    return;
+                }
+                for (index, (old_item, new_item)) in
+                    old_array.iter().zip(new_array.iter()).enumerate()
+                {
+                    self.diff_item(path, index, old_item, new_item);
+                }
+            }
+            (ReflectRef::List(old_list), ReflectRef::List(new_list)) => {
+                for (index, (old_item, new_item)) in
+                    old_list.iter().zip(new_list.iter()).enumerate()
+                {
+                    self.diff_item(path, index, old_item, new_item);
+                }
+                if new_list.len() < old_list.len() {
+                    self.changes.push(ReflectChange::Truncate {
+                        path: path.clone(),
+                        len: new_list.len(),
+                    });
+                }
+                for new_item in new_list.iter().skip(old_list.len()) {
+                    self.changes.push(ReflectChange::Push {
+                        path: path.clone(),
+                        value: new_item.clone_value(),
+                    });
+                }
+            }
+            (ReflectRef::Map(old_map), ReflectRef::Map(new_map)) => {
+                for (key, _) in old_map.iter() {
+                    if new_map.get(key).is_none() {
+                        self.changes.push(ReflectChange::Remove {
+                            path: path.clone(),
+                            key: key.clone_value(),
+                        });
+                    }
+                }
+                for (key, new_value) in new_map.iter() {
+                    let unchanged = old_map
+                        .get(key)
+                        .and_then(|old_value| old_value.reflect_partial_eq(new_value));
+                    if unchanged != Some(true) {
+                        self.changes.push(ReflectChange::Insert {
+                            path: path.clone(),
+                            key: key.clone_value(),
+                            value: new_value.clone_value(),
+                        });
+                    }
+                }
+            }
+            _ => {
+                if old.reflect_partial_eq(new) != Some(true) {
+                    self.set(path, new);
+                }
+            }
+        }
+    }
+
+    fn diff_field(
+        &mut self,
+        path: &mut String,
+        index: usize,
+        old: &dyn Reflect,
+        new: &dyn Reflect,
+    ) {
+        let len = path.len();
+        if !path.is_empty() {
+            path.push('.');
+        }
+        path.push_str(&index.to_string());
+        self.diff(path, old, new);
+        path.truncate(len);
+    }
+
+    fn diff_item(&mut self, path: &mut String, index: usize, old: &dyn Reflect, new: &dyn Reflect) {
+        let len = path.len();
+        path.push('[');
+        path.push_str(&index.to_string());
+        path.push(']');
+        self.diff(path, old, new);
+        path.truncate(len);
+    }
+}
+
+/// Makes `target` equal to `value`.
+///
+/// [`Reflect::apply`] leaves the keys of maps and the elements of lists that `value` does not
+/// have in place, so they are removed here, in nested values too.
+fn replace(target: &mut dyn Reflect, value: &dyn Reflect) {
+    let replaced = match (target.reflect_mut(), value.reflect_ref()) {
+        (ReflectMut::Struct(target), ReflectRef::Struct(value)) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field_mut(value.name_at(index).unwrap()) {
+                    replace(target_field, field);
+                }
+            }
+            true
+        }
+        (ReflectMut::TupleStruct(target), ReflectRef::TupleStruct(value)) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field_mut(index) {
+                    replace(target_field, field);
+                }
+            }
+            true
+        }
+        (ReflectMut::Tuple(target), ReflectRef::Tuple(value)) => {
+            for (index, field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field_mut(index) {
+                    replace(target_field, field);
+                }
+            }
+            true
+        }
+        (ReflectMut::Array(target), ReflectRef::Array(value)) => {
+            for (index, item) in value.iter().enumerate() {
+                if let Some(target_item) = target.get_mut(index) {
+                    replace(target_item, item);
+                }
+            }
+            true
+        }
+        (ReflectMut::List(target), ReflectRef::List(value)) => {
+            while target.len() > value.len() {
+                target.pop();
+            }
+            for (index, item) in value.iter().enumerate() {
+                match target.get_mut(index) {
+                    Some(target_item) => replace(target_item, item),
+                    None => target.push(item.clone_value()),
+                }
+            }
+            true
+        }
+        (ReflectMut::Map(target), ReflectRef::Map(value)) => {
+            let removed: Vec<_> = target
+                .iter()
+                .filter(|(key, _)| value.get(*key).is_none())
+                .map(|(key, _)| key.clone_value())
+                .collect();
+            for key in removed {
+                target.remove(key.as_ref());
+            }
+            for (key, item) in value.iter() {
+                match target.get_mut(key) {
+                    Some(target_item) => replace(target_item, item),
+                    None => {
+                        target.insert_boxed(key.clone_value(), item.clone_value());
+                    }
+                }
+            }
+            true
+        }
+        // Switching variants builds a new value, but the fields of the same variant are applied.
+        (ReflectMut::Enum(target), ReflectRef::Enum(value))
+            if target.variant_name() == value.variant_name() =>
+        {
+            for (index, field) in value.iter_fields().enumerate() {
+                if let Some(target_field) = target.field_at_mut(index) {
+                    replace(target_field, field.value());
+                }
+            }
+            true
+        }
+        _ => false,
+    };
+    if !replaced {
+        target.apply(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::FromReflect;
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, FromReflect, Clone, PartialEq, Debug)]
+    enum Shape {
+        Circle(f32),
+        Square { side: f32 },
+    }
+
+    #[derive(Reflect, FromReflect, Clone, PartialEq, Debug)]
+    struct Inner {
+        x: u32,
+        pair: (u8, String),
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Outer {
+        inner: Inner,
+        list: Vec<Inner>,
+        array: [u16; 3],
+        shape: Shape,
+    }
+
+    fn outer() -> Outer {
+        Outer {
+            inner: Inner {
+                x: 1,
+                pair: (2, "a".to_string()),
+            },
+            list: vec![Inner {
+                x: 3,
+                pair: (4, "b".to_string()),
+            }],
+            array: [5, 6, 7],
+            shape: Shape::Circle(1.0),
+        }
+    }
+
+    #[test]
+    fn diff_nested() {
+        let old = outer();
+        assert!(ReflectDiff::new(&old, &old).is_empty());
+
+        let mut new = outer();
+        new.inner.pair.1 = "c".to_string();
+        new.list[0].x = 8;
+        new.array[2] = 9;
+        new.shape = Shape::Square { side: 2.0 };
+
+        let diff = ReflectDiff::new(&old, &new);
+        let paths: Vec<_> = diff.changes().iter().map(ReflectChange::path).collect();
+        assert_eq!(paths, ["inner.pair.1", "list[0].x", "array[2]", "shape"]);
+
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+
+    #[test]
+    fn diff_list_length() {
+        let old = outer();
+        let mut new = outer();
+        new.list.push(new.inner.clone());
+        new.list.push(new.inner.clone());
+
+        let grow = ReflectDiff::new(&old, &new);
+        assert_eq!(grow.len(), 2);
+        let mut value = old.clone();
+        grow.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        let shrink = ReflectDiff::new(&new, &old);
+        assert!(matches!(
+            shrink.changes(),
+            [ReflectChange::Truncate { len: 1, .. }]
+        ));
+        shrink.apply(&mut value).unwrap();
+        assert_eq!(value, old);
+    }
+
+    #[derive(Reflect, FromReflect, Clone, PartialEq, Debug)]
+    enum Loot {
+        Empty,
+        Chest(HashMap<String, u32>),
+    }
+
+    #[derive(Reflect, Clone, PartialEq, Debug)]
+    struct Inventory {
+        items: HashMap<String, u32>,
+        loot: Loot,
+    }
+
+    #[test]
+    fn diff_map() {
+        let items = |entries: &[(&str, u32)]| {
+            entries
+                .iter()
+                .map(|&(name, count)| (name.to_string(), count))
+                .collect::<HashMap<_, _>>()
+        };
+        let old = Inventory {
+            items: items(&[("apple", 1), ("sword", 1), ("coin", 5)]),
+            loot: Loot::Chest(items(&[("gem", 1), ("key", 1)])),
+        };
+        let new = Inventory {
+            items: items(&[("apple", 1), ("coin", 7), ("shield", 1)]),
+            loot: Loot::Chest(items(&[("gem", 2)])),
+        };
+
+        let diff = ReflectDiff::new(&old, &new);
+        let mut changes: Vec<_> = diff
+            .changes()
+            .iter()
+            .map(|change| match change {
+                ReflectChange::Insert { path, key, .. } => {
+                    format!("insert {path} {}", key.downcast_ref::<String>().unwrap())
+                }
+                ReflectChange::Remove { path, key } => {
+                    format!("remove {path} {}", key.downcast_ref::<String>().unwrap())
+                }
+                change => format!("set {}", change.path()),
+            })
+            .collect();
+        changes.sort();
+        assert_eq!(
+            changes,
+            [
+                "insert items coin",
+                "insert items shield",
+                "remove items sword",
+                "set loot"
+            ]
+        );
+
+        // Removed keys are gone, including in the maps of replaced enums.
+        let mut value = old.clone();
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+
+        let mut value = new.clone();
+        ReflectDiff::new(&new, &old).apply(&mut value).unwrap();
+        assert_eq!(value, old);
+
+        let mut value = new.clone();
+        value.loot = Loot::Empty;
+        ReflectDiff::new(&new, &old).apply(&mut value).unwrap();
+        assert_eq!(value, old);
+    }
+
+    #[test]
+    fn diff_root_value() {
+        let diff = ReflectDiff::new(&1usize, &2usize);
+        assert_eq!(diff.changes()[0].path(), "");
+
+        let mut value = 1usize;
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, 2);
+    }
+}
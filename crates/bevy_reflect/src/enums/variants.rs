@@ -1,4 +1,4 @@
-use crate::{NamedField, UnnamedField};
+use crate::{FieldAttributes, NamedField, UnnamedField};
 use bevy_utils::HashMap;
 use std::slice::Iter;
 
@@ -73,6 +73,15 @@ impl VariantInfo {
         }
     }
 
+    /// The attributes of the underlying variant.
+    pub fn attributes(&self) -> &FieldAttributes {
+        match self {
+            Self::Struct(info) => info.attributes(),
+            Self::Tuple(info) => info.attributes(),
+            Self::Unit(info) => info.attributes(),
+        }
+    }
+
     /// The docstring of the underlying variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&str> {
@@ -91,6 +100,7 @@ pub struct StructVariantInfo {
     fields: Box<[NamedField]>,
     field_names: Box<[&'static str]>,
     field_indices: HashMap<&'static str, usize>,
+    attributes: FieldAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -105,11 +115,17 @@ impl StructVariantInfo {
             fields: fields.to_vec().into_boxed_slice(),
             field_names,
             field_indices,
+            attributes: FieldAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the attributes of this variant.
+    pub fn with_attributes(self, attributes: FieldAttributes) -> Self {
+        Self { attributes, ..self }
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -121,6 +137,11 @@ impl StructVariantInfo {
         self.name
     }
 
+    /// The attributes of this variant.
+    pub fn attributes(&self) -> &FieldAttributes {
+        &self.attributes
+    }
+
     /// A slice containing the names of all fields in order.
     pub fn field_names(&self) -> &[&'static str] {
         &self.field_names
@@ -173,6 +194,7 @@ impl StructVariantInfo {
 pub struct TupleVariantInfo {
     name: &'static str,
     fields: Box<[UnnamedField]>,
+    attributes: FieldAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -183,11 +205,17 @@ impl TupleVariantInfo {
         Self {
             name,
             fields: fields.to_vec().into_boxed_slice(),
+            attributes: FieldAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the attributes of this variant.
+    pub fn with_attributes(self, attributes: FieldAttributes) -> Self {
+        Self { attributes, ..self }
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -199,6 +227,11 @@ impl TupleVariantInfo {
         self.name
     }
 
+    /// The attributes of this variant.
+    pub fn attributes(&self) -> &FieldAttributes {
+        &self.attributes
+    }
+
     /// Get the field at the given index.
     pub fn field_at(&self, index: usize) -> Option<&UnnamedField> {
         self.fields.get(index)
@@ -225,6 +258,7 @@ impl TupleVariantInfo {
 #[derive(Clone, Debug)]
 pub struct UnitVariantInfo {
     name: &'static str,
+    attributes: FieldAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -234,11 +268,17 @@ impl UnitVariantInfo {
     pub fn new(name: &'static str) -> Self {
         Self {
             name,
+            attributes: FieldAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the attributes of this variant.
+    pub fn with_attributes(self, attributes: FieldAttributes) -> Self {
+        Self { attributes, ..self }
+    }
+
     /// Sets the docstring for this variant.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -250,6 +290,11 @@ impl UnitVariantInfo {
         self.name
     }
 
+    /// The attributes of this variant.
+    pub fn attributes(&self) -> &FieldAttributes {
+        &self.attributes
+    }
+
     /// The docstring of this variant, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -1,5 +1,9 @@
 use crate::Reflect;
-use std::any::{Any, TypeId};
+use bevy_utils::HashMap;
+use std::{
+    any::{Any, TypeId},
+    sync::Arc,
+};
 
 /// The named field of a reflected struct.
 #[derive(Clone, Debug)]
@@ -7,6 +11,7 @@ pub struct NamedField {
     name: &'static str,
     type_name: &'static str,
     type_id: TypeId,
+    attributes: FieldAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -18,11 +23,17 @@ impl NamedField {
             name,
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            attributes: FieldAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the attributes of this field.
+    pub fn with_attributes(self, attributes: FieldAttributes) -> Self {
+        Self { attributes, ..self }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -51,6 +62,11 @@ impl NamedField {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// The attributes of this field.
+    pub fn attributes(&self) -> &FieldAttributes {
+        &self.attributes
+    }
+
     /// The docstring of this field, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
@@ -64,6 +80,7 @@ pub struct UnnamedField {
     index: usize,
     type_name: &'static str,
     type_id: TypeId,
+    attributes: FieldAttributes,
     #[cfg(feature = "documentation")]
     docs: Option<&'static str>,
 }
@@ -74,11 +91,17 @@ impl UnnamedField {
             index,
             type_name: std::any::type_name::<T>(),
             type_id: TypeId::of::<T>(),
+            attributes: FieldAttributes::default(),
             #[cfg(feature = "documentation")]
             docs: None,
         }
     }
 
+    /// Sets the attributes of this field.
+    pub fn with_attributes(self, attributes: FieldAttributes) -> Self {
+        Self { attributes, ..self }
+    }
+
     /// Sets the docstring for this field.
     #[cfg(feature = "documentation")]
     pub fn with_docs(self, docs: Option<&'static str>) -> Self {
@@ -107,9 +130,195 @@ impl UnnamedField {
         TypeId::of::<T>() == self.type_id
     }
 
+    /// The attributes of this field.
+    pub fn attributes(&self) -> &FieldAttributes {
+        &self.attributes
+    }
+
     /// The docstring of this field, if any.
     #[cfg(feature = "documentation")]
     pub fn docs(&self) -> Option<&'static str> {
         self.docs
     }
 }
+
+/// Metadata declared on a reflected field or enum variant, meant for inspectors and editors.
+///
+/// When deriving [`Reflect`], the attributes are declared with `#[reflect(...)]`:
+/// - `range = "0.0..=1.0"` sets the [minimum](Self::min) and [maximum](Self::max) value of a
+///   numeric field. Either bound may be omitted, as in `"0.."` or `"..=100"`.
+/// - `step = 0.1` sets the [step](Self::step) by which a numeric field should be changed.
+/// - `tooltip = "..."` sets a [tooltip](Self::tooltip) describing the field.
+/// - `read_only` marks the field as [read-only](Self::is_read_only).
+/// - `hidden` marks the field as [hidden](Self::is_hidden).
+/// - `@expr` stores the value of `expr`, of any [`Reflect`] type, as a
+///   [custom attribute](Self::custom_attribute). Each type can be stored once per field.
+///
+/// These attributes are not enforced: they only describe how the field should be edited.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// #[derive(Reflect)]
+/// struct Light {
+///     #[reflect(range = "0.0..=1.0", step = 0.05, tooltip = "Fraction of the full brightness")]
+///     intensity: f32,
+///     #[reflect(read_only)]
+///     id: u32,
+/// }
+///
+/// let TypeInfo::Struct(info) = Light::type_info() else { unreachable!() };
+/// let intensity = info.field("intensity").unwrap().attributes();
+/// assert_eq!(intensity.min(), Some(0.0));
+/// assert_eq!(intensity.max(), Some(1.0));
+/// assert_eq!(intensity.tooltip(), Some("Fraction of the full brightness"));
+/// assert!(info.field("id").unwrap().attributes().is_read_only());
+/// ```
+///
+/// Custom attributes let tools attach their own metadata:
+/// ```
+/// # use bevy_reflect::{Reflect, Typed, TypeInfo};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// enum Unit {
+///     Kilograms,
+///     Meters,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct Body {
+///     #[reflect(@Unit::Kilograms)]
+///     mass: f32,
+/// }
+///
+/// let TypeInfo::Struct(info) = Body::type_info() else { unreachable!() };
+/// let mass = info.field("mass").unwrap().attributes();
+/// assert_eq!(mass.custom_attribute::<Unit>(), Some(&Unit::Kilograms));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FieldAttributes {
+    min: Option<f64>,
+    max: Option<f64>,
+    step: Option<f64>,
+    tooltip: Option<&'static str>,
+    read_only: bool,
+    hidden: bool,
+    custom: HashMap<TypeId, Arc<dyn Reflect>>,
+}
+
+impl PartialEq for FieldAttributes {
+    fn eq(&self, other: &Self) -> bool {
+        self.min == other.min
+            && self.max == other.max
+            && self.step == other.step
+            && self.tooltip == other.tooltip
+            && self.read_only == other.read_only
+            && self.hidden == other.hidden
+            && self.custom.len() == other.custom.len()
+            && self.custom.iter().all(|(type_id, value)| {
+                other.custom.get(type_id).map_or(false, |other| {
+                    value.reflect_partial_eq(&**other).unwrap_or(false)
+                })
+            })
+    }
+}
+
+impl FieldAttributes {
+    /// Sets the minimum value of the field.
+    pub fn with_min(self, min: f64) -> Self {
+        Self {
+            min: Some(min),
+            ..self
+        }
+    }
+
+    /// Sets the maximum value of the field.
+    pub fn with_max(self, max: f64) -> Self {
+        Self {
+            max: Some(max),
+            ..self
+        }
+    }
+
+    /// Sets the step by which the field should be changed.
+    pub fn with_step(self, step: f64) -> Self {
+        Self {
+            step: Some(step),
+            ..self
+        }
+    }
+
+    /// Sets the tooltip of the field.
+    pub fn with_tooltip(self, tooltip: &'static str) -> Self {
+        Self {
+            tooltip: Some(tooltip),
+            ..self
+        }
+    }
+
+    /// Sets whether the field is read-only.
+    pub fn with_read_only(self, read_only: bool) -> Self {
+        Self { read_only, ..self }
+    }
+
+    /// Sets whether the field is hidden.
+    pub fn with_hidden(self, hidden: bool) -> Self {
+        Self { hidden, ..self }
+    }
+
+    /// Adds a custom attribute, replacing the one of the same type if any.
+    pub fn with_custom_attribute<T: Reflect>(mut self, value: T) -> Self {
+        self.custom.insert(TypeId::of::<T>(), Arc::new(value));
+        self
+    }
+
+    /// The minimum value of the field, if any.
+    pub fn min(&self) -> Option<f64> {
+        self.min
+    }
+
+    /// The maximum value of the field, if any.
+    pub fn max(&self) -> Option<f64> {
+        self.max
+    }
+
+    /// The step by which the field should be changed, if any.
+    pub fn step(&self) -> Option<f64> {
+        self.step
+    }
+
+    /// The tooltip of the field, if any.
+    pub fn tooltip(&self) -> Option<&'static str> {
+        self.tooltip
+    }
+
+    /// Returns `true` if the field should not be edited.
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Returns `true` if the field should not be displayed.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+
+    /// The custom attribute of type `T`, if any.
+    pub fn custom_attribute<T: Reflect>(&self) -> Option<&T> {
+        self.custom_attribute_by_id(TypeId::of::<T>())?
+            .downcast_ref()
+    }
+
+    /// The custom attribute with the given [`TypeId`], if any.
+    pub fn custom_attribute_by_id(&self, type_id: TypeId) -> Option<&dyn Reflect> {
+        self.custom.get(&type_id).map(|value| &**value)
+    }
+
+    /// Returns `true` if the field has a custom attribute of type `T`.
+    pub fn has_custom_attribute<T: Reflect>(&self) -> bool {
+        self.custom.contains_key(&TypeId::of::<T>())
+    }
+
+    /// An iterator over the custom attributes of the field, in no particular order.
+    pub fn custom_attributes(&self) -> impl Iterator<Item = &dyn Reflect> {
+        self.custom.values().map(|value| &**value)
+    }
+}
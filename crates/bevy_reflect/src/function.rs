@@ -0,0 +1,667 @@
+use crate::{FromReflect, Reflect};
+use bevy_utils::HashMap;
+use std::{
+    any::{Any, TypeId},
+    borrow::Cow,
+    fmt,
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// An error returned when calling a [`ReflectFunction`].
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum FunctionError {
+    #[error("expected {expected} arguments but received {received}")]
+    ArgCount { expected: usize, received: usize },
+    #[error("expected argument {index} to be of type `{expected}` but received `{received}`")]
+    InvalidArg {
+        index: usize,
+        expected: &'static str,
+        received: String,
+    },
+    #[error("expected a receiver of type `{expected}` but received `{received}`")]
+    InvalidReceiver {
+        expected: &'static str,
+        received: String,
+    },
+    #[error("the method `{name}` requires a receiver")]
+    MissingReceiver { name: String },
+    #[error("the function `{name}` doesn't take a receiver")]
+    UnexpectedReceiver { name: String },
+    #[error("no method named `{name}` was registered")]
+    NotFound { name: String },
+}
+
+/// The arguments of a call to a [`ReflectFunction`].
+///
+/// Arguments are passed by value and converted to the parameter types of the function with
+/// [`FromReflect`], so dynamic values such as a [`DynamicStruct`](crate::DynamicStruct) are
+/// accepted in place of the concrete type they represent.
+#[derive(Default)]
+pub struct ArgList(Vec<Box<dyn Reflect>>);
+
+impl ArgList {
+    /// Creates an empty argument list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends an argument to the list.
+    pub fn with_arg<T: Reflect>(self, arg: T) -> Self {
+        self.with_boxed_arg(Box::new(arg))
+    }
+
+    /// Appends a boxed argument to the list.
+    pub fn with_boxed_arg(mut self, arg: Box<dyn Reflect>) -> Self {
+        self.0.push(arg);
+        self
+    }
+
+    /// Appends a boxed argument to the list.
+    pub fn push(&mut self, arg: Box<dyn Reflect>) {
+        self.0.push(arg);
+    }
+
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the list has no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<Box<dyn Reflect>>> for ArgList {
+    fn from(args: Vec<Box<dyn Reflect>>) -> Self {
+        ArgList(args)
+    }
+}
+
+impl fmt::Debug for ArgList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|arg| arg.as_ref()))
+            .finish()
+    }
+}
+
+/// Information about an argument of a [`ReflectFunction`].
+#[derive(Clone, Debug)]
+pub struct ArgInfo {
+    index: usize,
+    type_name: &'static str,
+    type_id: TypeId,
+}
+
+impl ArgInfo {
+    /// Create a new [`ArgInfo`].
+    pub fn new<T: Reflect>(index: usize) -> Self {
+        Self {
+            index,
+            type_name: std::any::type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    /// Returns the index of the argument, not counting the receiver.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The [type name] of the argument.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the argument.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Check if the given type matches the argument type.
+    pub fn is<T: Any>(&self) -> bool {
+        TypeId::of::<T>() == self.type_id
+    }
+}
+
+/// Information about the receiver of a method.
+#[derive(Clone, Debug)]
+pub struct ReceiverInfo {
+    type_name: &'static str,
+    type_id: TypeId,
+    mutable: bool,
+}
+
+impl ReceiverInfo {
+    /// The [type name] of the receiver.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The [`TypeId`] of the receiver.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns `true` if the method takes `&mut self`.
+    pub fn is_mutable(&self) -> bool {
+        self.mutable
+    }
+}
+
+/// Compile-time information about a [`ReflectFunction`].
+#[derive(Clone, Debug)]
+pub struct FunctionInfo {
+    name: Cow<'static, str>,
+    receiver: Option<ReceiverInfo>,
+    args: Box<[ArgInfo]>,
+    return_type_name: &'static str,
+}
+
+impl FunctionInfo {
+    /// The name of the function.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The receiver of the function, if it is a method.
+    pub fn receiver(&self) -> Option<&ReceiverInfo> {
+        self.receiver.as_ref()
+    }
+
+    /// The arguments of the function, not including the receiver.
+    pub fn args(&self) -> &[ArgInfo] {
+        &self.args
+    }
+
+    /// The [type name] of the value returned by the function.
+    ///
+    /// [type name]: std::any::type_name
+    pub fn return_type_name(&self) -> &'static str {
+        self.return_type_name
+    }
+}
+
+type BoxedFunction = Arc<
+    dyn Fn(Option<&mut dyn Reflect>, ArgList) -> Result<Box<dyn Reflect>, FunctionError>
+        + Send
+        + Sync,
+>;
+
+/// A function or method which can be called with reflected arguments.
+///
+/// It is created from any function or closure whose arguments implement [`FromReflect`] and
+/// whose return value implements [`Reflect`], with [`ReflectFunction::new`]. A function whose
+/// first parameter is `&T` or `&mut T`, with `T: Reflect`, can also be used as a method, in
+/// which case the receiver is passed to [`call_method`](Self::call_method). Functions returning
+/// references are not supported.
+///
+/// Methods of a type can be registered in its [`TypeRegistration`] with
+/// [`TypeRegistration::add_method`], or with `#[reflect(methods(...))]` when deriving
+/// [`Reflect`]. They are then available through the [`ReflectMethods`] type data.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{ArgList, Reflect, ReflectFunction};
+/// #[derive(Reflect)]
+/// struct Counter(u32);
+///
+/// impl Counter {
+///     fn add(&mut self, amount: u32) -> u32 {
+///         self.0 += amount;
+///         self.0
+///     }
+/// }
+///
+/// let add = ReflectFunction::new("add", Counter::add);
+/// let mut counter = Counter(1);
+/// let result = add.call_method(&mut counter, ArgList::new().with_arg(2u32)).unwrap();
+/// assert_eq!(result.downcast_ref::<u32>(), Some(&3));
+/// assert_eq!(counter.0, 3);
+///
+/// let sum = ReflectFunction::new("sum", |a: f32, b: f32| a + b);
+/// let result = sum.call(ArgList::new().with_arg(1.0f32).with_arg(2.0f32)).unwrap();
+/// assert_eq!(result.downcast_ref::<f32>(), Some(&3.0));
+/// ```
+///
+/// [`TypeRegistration`]: crate::TypeRegistration
+/// [`TypeRegistration::add_method`]: crate::TypeRegistration::add_method
+#[derive(Clone)]
+pub struct ReflectFunction {
+    info: Arc<FunctionInfo>,
+    func: BoxedFunction,
+}
+
+impl ReflectFunction {
+    /// Creates a [`ReflectFunction`] with the given name.
+    pub fn new<Marker>(
+        name: impl Into<Cow<'static, str>>,
+        function: impl IntoReflectFunction<Marker>,
+    ) -> Self {
+        function.into_reflect_function(name.into())
+    }
+
+    /// Returns information about the parameters of this function.
+    pub fn info(&self) -> &FunctionInfo {
+        &self.info
+    }
+
+    /// Returns the name of this function.
+    pub fn name(&self) -> &str {
+        self.info.name()
+    }
+
+    /// Calls this function, which must not take a receiver.
+    pub fn call(&self, args: ArgList) -> Result<Box<dyn Reflect>, FunctionError> {
+        if self.info.receiver.is_some() {
+            return Err(FunctionError::MissingReceiver {
+                name: self.info.name.to_string(),
+            });
+        }
+        (self.func)(None, args)
+    }
+
+    /// Calls this method on `receiver`.
+    pub fn call_method(
+        &self,
+        receiver: &mut dyn Reflect,
+        args: ArgList,
+    ) -> Result<Box<dyn Reflect>, FunctionError> {
+        if self.info.receiver.is_none() {
+            return Err(FunctionError::UnexpectedReceiver {
+                name: self.info.name.to_string(),
+            });
+        }
+        (self.func)(Some(receiver), args)
+    }
+}
+
+impl fmt::Debug for ReflectFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReflectFunction")
+            .field("info", &self.info)
+            .finish()
+    }
+}
+
+/// Conversion into a [`ReflectFunction`].
+///
+/// This is implemented for functions and closures of up to 8 arguments, the `Marker` type
+/// telling apart functions from methods taking `&T` or `&mut T` as their first parameter.
+pub trait IntoReflectFunction<Marker>: Send + Sync + 'static {
+    /// Converts this function into a [`ReflectFunction`] with the given name.
+    fn into_reflect_function(self, name: Cow<'static, str>) -> ReflectFunction;
+}
+
+#[doc(hidden)]
+pub struct FunctionMarker;
+#[doc(hidden)]
+pub struct MethodMarker;
+#[doc(hidden)]
+pub struct MethodMutMarker;
+
+fn take_args<const N: usize>(args: ArgList) -> Result<[Box<dyn Reflect>; N], FunctionError> {
+    let received = args.len();
+    args.0.try_into().map_err(|_| FunctionError::ArgCount {
+        expected: N,
+        received,
+    })
+}
+
+fn from_arg<T: FromReflect>(index: usize, arg: Box<dyn Reflect>) -> Result<T, FunctionError> {
+    match arg.take::<T>() {
+        Ok(value) => Ok(value),
+        Err(arg) => T::from_reflect(arg.as_ref()).ok_or_else(|| FunctionError::InvalidArg {
+            index,
+            expected: std::any::type_name::<T>(),
+            received: arg.type_name().to_string(),
+        }),
+    }
+}
+
+fn receiver_info<T: Reflect>(mutable: bool) -> Option<ReceiverInfo> {
+    Some(ReceiverInfo {
+        type_name: std::any::type_name::<T>(),
+        type_id: TypeId::of::<T>(),
+        mutable,
+    })
+}
+
+fn downcast_receiver<T: Reflect>(
+    receiver: Option<&mut dyn Reflect>,
+) -> Result<&mut T, FunctionError> {
+    match receiver {
+        Some(receiver) => {
+            let received = receiver.type_name().to_string();
+            receiver
+                .downcast_mut::<T>()
+                .ok_or(FunctionError::InvalidReceiver {
+                    expected: std::any::type_name::<T>(),
+                    received,
+                })
+        }
+        None => Err(FunctionError::MissingReceiver {
+            name: std::any::type_name::<T>().to_string(),
+        }),
+    }
+}
+
+macro_rules! impl_into_reflect_function {
+    ($($index: tt: $arg: ident),*) => {
+        impl<F, R, $($arg,)*> IntoReflectFunction<(FunctionMarker, fn($($arg,)*) -> R)> for F
+        where
+            F: Fn($($arg,)*) -> R + Send + Sync + 'static,
+            R: Reflect,
+            $($arg: FromReflect,)*
+        {
+            #[allow(non_snake_case, unused_variables)]
+            fn into_reflect_function(self, name: Cow<'static, str>) -> ReflectFunction {
+                ReflectFunction {
+                    info: Arc::new(FunctionInfo {
+                        name,
+                        receiver: None,
+                        args: Box::new([$(ArgInfo::new::<$arg>($index),)*]),
+                        return_type_name: std::any::type_name::<R>(),
+                    }),
+                    func: Arc::new(move |_receiver, args| {
+                        let [$($arg,)*] = take_args(args)?;
+                        Ok(Box::new(self($(from_arg::<$arg>($index, $arg)?,)*)))
+                    }),
+                }
+            }
+        }
+
+        impl<F, T, R, $($arg,)*> IntoReflectFunction<(MethodMarker, T, fn($($arg,)*) -> R)> for F
+        where
+            F: Fn(&T, $($arg,)*) -> R + Send + Sync + 'static,
+            T: Reflect,
+            R: Reflect,
+            $($arg: FromReflect,)*
+        {
+            #[allow(non_snake_case, unused_variables)]
+            fn into_reflect_function(self, name: Cow<'static, str>) -> ReflectFunction {
+                ReflectFunction {
+                    info: Arc::new(FunctionInfo {
+                        name,
+                        receiver: receiver_info::<T>(false),
+                        args: Box::new([$(ArgInfo::new::<$arg>($index),)*]),
+                        return_type_name: std::any::type_name::<R>(),
+                    }),
+                    func: Arc::new(move |receiver, args| {
+                        let receiver = downcast_receiver::<T>(receiver)?;
+                        let [$($arg,)*] = take_args(args)?;
+                        Ok(Box::new(self(&*receiver, $(from_arg::<$arg>($index, $arg)?,)*)))
+                    }),
+                }
+            }
+        }
+
+        impl<F, T, R, $($arg,)*> IntoReflectFunction<(MethodMutMarker, T, fn($($arg,)*) -> R)> for F
+        where
+            F: Fn(&mut T, $($arg,)*) -> R + Send + Sync + 'static,
+            T: Reflect,
+            R: Reflect,
+            $($arg: FromReflect,)*
+        {
+            #[allow(non_snake_case, unused_variables)]
+            fn into_reflect_function(self, name: Cow<'static, str>) -> ReflectFunction {
+                ReflectFunction {
+                    info: Arc::new(FunctionInfo {
+                        name,
+                        receiver: receiver_info::<T>(true),
+                        args: Box::new([$(ArgInfo::new::<$arg>($index),)*]),
+                        return_type_name: std::any::type_name::<R>(),
+                    }),
+                    func: Arc::new(move |receiver, args| {
+                        let receiver = downcast_receiver::<T>(receiver)?;
+                        let [$($arg,)*] = take_args(args)?;
+                        Ok(Box::new(self(receiver, $(from_arg::<$arg>($index, $arg)?,)*)))
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_into_reflect_function! {}
+impl_into_reflect_function! {0: A}
+impl_into_reflect_function! {0: A, 1: B}
+impl_into_reflect_function! {0: A, 1: B, 2: C}
+impl_into_reflect_function! {0: A, 1: B, 2: C, 3: D}
+impl_into_reflect_function! {0: A, 1: B, 2: C, 3: D, 4: E}
+impl_into_reflect_function! {0: A, 1: B, 2: C, 3: D, 4: E, 5: G}
+impl_into_reflect_function! {0: A, 1: B, 2: C, 3: D, 4: E, 5: G, 6: H}
+impl_into_reflect_function! {0: A, 1: B, 2: C, 3: D, 4: E, 5: G, 6: H, 7: I}
+
+/// Type data holding the reflected methods of a type, by name.
+///
+/// Methods are added with [`TypeRegistration::add_method`], or with
+/// `#[reflect(methods(...))]` when deriving [`Reflect`]:
+///
+/// ```
+/// # use bevy_reflect::{ArgList, GetTypeRegistration, Reflect, ReflectMethods};
+/// #[derive(Reflect, Default)]
+/// #[reflect(methods(reset))]
+/// struct Timer {
+///     elapsed: f32,
+/// }
+///
+/// impl Timer {
+///     fn reset(&mut self) {
+///         self.elapsed = 0.0;
+///     }
+/// }
+///
+/// let registration = Timer::get_type_registration();
+/// let methods = registration.data::<ReflectMethods>().unwrap();
+///
+/// let mut timer = Timer { elapsed: 2.0 };
+/// methods.call("reset", &mut timer, ArgList::new()).unwrap();
+/// assert_eq!(timer.elapsed, 0.0);
+/// ```
+///
+/// [`TypeRegistration::add_method`]: crate::TypeRegistration::add_method
+#[derive(Clone, Debug, Default)]
+pub struct ReflectMethods {
+    methods: HashMap<Cow<'static, str>, ReflectFunction>,
+}
+
+impl ReflectMethods {
+    /// Adds a method, replacing any method with the same name.
+    pub fn insert(&mut self, method: ReflectFunction) {
+        self.methods.insert(method.info.name.clone(), method);
+    }
+
+    /// Adds a method with the given name, replacing any method with the same name.
+    pub fn with_method<Marker>(
+        mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: impl IntoReflectFunction<Marker>,
+    ) -> Self {
+        self.insert(ReflectFunction::new(name, method));
+        self
+    }
+
+    /// Returns the method with the given name.
+    pub fn get(&self, name: &str) -> Option<&ReflectFunction> {
+        self.methods.get(name)
+    }
+
+    /// Calls the method with the given name on `receiver`.
+    pub fn call(
+        &self,
+        name: &str,
+        receiver: &mut dyn Reflect,
+        args: ArgList,
+    ) -> Result<Box<dyn Reflect>, FunctionError> {
+        self.get(name)
+            .ok_or_else(|| FunctionError::NotFound {
+                name: name.to_string(),
+            })?
+            .call_method(receiver, args)
+    }
+
+    /// Returns an iterator over the methods.
+    pub fn iter(&self) -> impl Iterator<Item = &ReflectFunction> {
+        self.methods.values()
+    }
+
+    /// Returns the number of methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if there are no methods.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::{DynamicStruct, TypeRegistry};
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq, Clone)]
+    struct Vec2 {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    #[reflect(methods(translate, length_squared))]
+    struct Transform {
+        translation: Vec2,
+    }
+
+    impl Transform {
+        fn translate(&mut self, offset: Vec2, scale: f32) {
+            self.translation.x += offset.x * scale;
+            self.translation.y += offset.y * scale;
+        }
+
+        fn length_squared(&self) -> f32 {
+            self.translation.x * self.translation.x + self.translation.y * self.translation.y
+        }
+    }
+
+    fn transform() -> Transform {
+        Transform {
+            translation: Vec2 { x: 1.0, y: 2.0 },
+        }
+    }
+
+    #[test]
+    fn call_function() {
+        let function = ReflectFunction::new("scale", |value: Vec2, scale: f32| Vec2 {
+            x: value.x * scale,
+            y: value.y * scale,
+        });
+        let info = function.info();
+        assert!(info.receiver().is_none());
+        assert!(info.args()[0].is::<Vec2>());
+        assert_eq!(info.return_type_name(), std::any::type_name::<Vec2>());
+
+        let mut dynamic = DynamicStruct::default();
+        dynamic.insert("x", 1.0f32);
+        dynamic.insert("y", 2.0f32);
+        let result = function
+            .call(ArgList::new().with_arg(dynamic).with_arg(2.0f32))
+            .unwrap();
+        assert_eq!(result.take::<Vec2>().unwrap(), Vec2 { x: 2.0, y: 4.0 });
+    }
+
+    #[test]
+    fn call_method() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Transform>();
+        let methods = registry
+            .get(TypeId::of::<Transform>())
+            .unwrap()
+            .data::<ReflectMethods>()
+            .unwrap();
+        assert_eq!(methods.len(), 2);
+        assert!(methods
+            .get("translate")
+            .unwrap()
+            .info()
+            .receiver()
+            .unwrap()
+            .is_mutable());
+
+        let mut value = transform();
+        let args = ArgList::new()
+            .with_arg(Vec2 { x: 1.0, y: 1.0 })
+            .with_arg(2.0f32);
+        methods.call("translate", &mut value, args).unwrap();
+        assert_eq!(value.translation, Vec2 { x: 3.0, y: 4.0 });
+
+        let length = methods
+            .call("length_squared", &mut value, ArgList::new())
+            .unwrap();
+        assert_eq!(length.take::<f32>().unwrap(), 25.0);
+    }
+
+    #[test]
+    fn add_method() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Vec2>();
+        registry
+            .get_mut(TypeId::of::<Vec2>())
+            .unwrap()
+            .add_method(ReflectFunction::new("swap", |value: &mut Vec2| {
+                std::mem::swap(&mut value.x, &mut value.y);
+            }));
+
+        let methods = registry
+            .get(TypeId::of::<Vec2>())
+            .unwrap()
+            .data::<ReflectMethods>()
+            .unwrap();
+        let mut value = Vec2 { x: 1.0, y: 2.0 };
+        methods.call("swap", &mut value, ArgList::new()).unwrap();
+        assert_eq!(value, Vec2 { x: 2.0, y: 1.0 });
+    }
+
+    #[test]
+    fn invalid_calls() {
+        let translate = ReflectFunction::new("translate", Transform::translate);
+        let mut value = transform();
+
+        assert_eq!(
+            translate
+                .call_method(&mut value, ArgList::new().with_arg(1.0f32))
+                .unwrap_err(),
+            FunctionError::ArgCount {
+                expected: 2,
+                received: 1
+            }
+        );
+        assert_eq!(
+            translate
+                .call_method(&mut value, ArgList::new().with_arg(1u8).with_arg(1.0f32))
+                .unwrap_err(),
+            FunctionError::InvalidArg {
+                index: 0,
+                expected: std::any::type_name::<Vec2>(),
+                received: "u8".to_string(),
+            }
+        );
+        assert!(matches!(
+            translate.call_method(&mut 1.0f32, ArgList::new()),
+            Err(FunctionError::InvalidReceiver { .. })
+        ));
+        assert!(matches!(
+            translate.call(ArgList::new()),
+            Err(FunctionError::MissingReceiver { .. })
+        ));
+        assert_eq!(value, transform());
+    }
+}
@@ -1,6 +1,7 @@
 use crate as bevy_reflect;
 use crate::prelude::ReflectDefault;
 use crate::reflect::Reflect;
+use crate::type_path::impl_type_path;
 use crate::{ReflectDeserialize, ReflectSerialize};
 use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_struct, impl_reflect_value};
 use glam::*;
@@ -239,3 +240,36 @@ impl_from_reflect_value!(DQuat);
 impl_reflect_value!(EulerRot(Debug, Default));
 impl_reflect_value!(BVec3A(Debug, Default));
 impl_reflect_value!(BVec4A(Debug, Default));
+
+impl_type_path!(IVec2 in "glam");
+impl_type_path!(IVec3 in "glam");
+impl_type_path!(IVec4 in "glam");
+impl_type_path!(UVec2 in "glam");
+impl_type_path!(UVec3 in "glam");
+impl_type_path!(UVec4 in "glam");
+impl_type_path!(Vec2 in "glam");
+impl_type_path!(Vec3 in "glam");
+impl_type_path!(Vec3A in "glam");
+impl_type_path!(Vec4 in "glam");
+impl_type_path!(BVec2 in "glam");
+impl_type_path!(BVec3 in "glam");
+impl_type_path!(BVec4 in "glam");
+impl_type_path!(DVec2 in "glam");
+impl_type_path!(DVec3 in "glam");
+impl_type_path!(DVec4 in "glam");
+impl_type_path!(Mat2 in "glam");
+impl_type_path!(Mat3 in "glam");
+impl_type_path!(Mat3A in "glam");
+impl_type_path!(Mat4 in "glam");
+impl_type_path!(DMat2 in "glam");
+impl_type_path!(DMat3 in "glam");
+impl_type_path!(DMat4 in "glam");
+impl_type_path!(Affine2 in "glam");
+impl_type_path!(Affine3A in "glam");
+impl_type_path!(DAffine2 in "glam");
+impl_type_path!(DAffine3 in "glam");
+impl_type_path!(Quat in "glam");
+impl_type_path!(DQuat in "glam");
+impl_type_path!(EulerRot in "glam");
+impl_type_path!(BVec3A in "glam");
+impl_type_path!(BVec4A in "glam");
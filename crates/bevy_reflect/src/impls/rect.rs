@@ -1,6 +1,7 @@
 use crate as bevy_reflect;
 use crate::prelude::ReflectDefault;
 use crate::reflect::Reflect;
+use crate::type_path::impl_type_path;
 use crate::{ReflectDeserialize, ReflectSerialize};
 use bevy_math::{Rect, Vec2};
 use bevy_reflect_derive::impl_reflect_struct;
@@ -12,3 +13,5 @@ impl_reflect_struct!(
         max: Vec2,
     }
 );
+
+impl_type_path!(Rect in "bevy_math");
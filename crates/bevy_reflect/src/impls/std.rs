@@ -4,11 +4,12 @@ use crate::{
     map_apply, map_partial_eq, Array, ArrayInfo, ArrayIter, DynamicEnum, DynamicMap, Enum,
     EnumInfo, FromReflect, FromType, GetTypeRegistration, List, ListInfo, Map, MapInfo, MapIter,
     Reflect, ReflectDeserialize, ReflectMut, ReflectRef, ReflectSerialize, TupleVariantInfo,
-    TypeInfo, TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo, VariantFieldIter,
-    VariantInfo, VariantType,
+    TypeInfo, TypePath, TypeRegistration, Typed, UnitVariantInfo, UnnamedField, ValueInfo,
+    VariantFieldIter, VariantInfo, VariantType,
 };
 
-use crate::utility::{GenericTypeInfoCell, NonGenericTypeInfoCell};
+use crate::type_path::impl_type_path;
+use crate::utility::{GenericTypeInfoCell, GenericTypePathCell, NonGenericTypeInfoCell};
 use bevy_reflect_derive::{impl_from_reflect_value, impl_reflect_value};
 use bevy_utils::{Duration, Instant};
 use bevy_utils::{HashMap, HashSet};
@@ -135,6 +136,53 @@ impl_reflect_value!(NonZeroI8(Debug, Hash, PartialEq, Serialize, Deserialize));
 #[cfg(any(unix, windows))]
 impl_reflect_value!(OsString(Debug, Hash, PartialEq, Serialize, Deserialize));
 
+impl_type_path!(bool);
+impl_type_path!(char);
+impl_type_path!(u8);
+impl_type_path!(u16);
+impl_type_path!(u32);
+impl_type_path!(u64);
+impl_type_path!(u128);
+impl_type_path!(usize);
+impl_type_path!(i8);
+impl_type_path!(i16);
+impl_type_path!(i32);
+impl_type_path!(i64);
+impl_type_path!(i128);
+impl_type_path!(isize);
+impl_type_path!(f32);
+impl_type_path!(f64);
+impl_type_path!(String in "alloc::string");
+impl_type_path!(PathBuf in "std::path");
+impl_type_path!(Result<T, E> in "core::result");
+impl_type_path!(HashSet<T> in "bevy_utils");
+impl_type_path!(Range<T> in "core::ops");
+impl_type_path!(RangeInclusive<T> in "core::ops");
+impl_type_path!(RangeFrom<T> in "core::ops");
+impl_type_path!(RangeTo<T> in "core::ops");
+impl_type_path!(RangeToInclusive<T> in "core::ops");
+impl_type_path!(RangeFull in "core::ops");
+impl_type_path!(Duration in "core::time");
+// `Instant` comes from a different crate on wasm.
+impl_type_path!(Instant in "bevy_utils");
+impl_type_path!(NonZeroI128 in "core::num");
+impl_type_path!(NonZeroU128 in "core::num");
+impl_type_path!(NonZeroIsize in "core::num");
+impl_type_path!(NonZeroUsize in "core::num");
+impl_type_path!(NonZeroI64 in "core::num");
+impl_type_path!(NonZeroU64 in "core::num");
+impl_type_path!(NonZeroU32 in "core::num");
+impl_type_path!(NonZeroI32 in "core::num");
+impl_type_path!(NonZeroI16 in "core::num");
+impl_type_path!(NonZeroU16 in "core::num");
+impl_type_path!(NonZeroU8 in "core::num");
+impl_type_path!(NonZeroI8 in "core::num");
+#[cfg(any(unix, windows))]
+impl_type_path!(OsString in "std::ffi");
+impl_type_path!(Vec<T> in "alloc::vec");
+impl_type_path!(HashMap<K, V> in "bevy_utils");
+impl_type_path!(Option<T> in "core::option");
+
 impl_from_reflect_value!(bool);
 impl_from_reflect_value!(char);
 impl_from_reflect_value!(u8);
@@ -298,7 +346,7 @@ impl<T: FromReflect> Typed for Vec<T> {
     }
 }
 
-impl<T: FromReflect> GetTypeRegistration for Vec<T> {
+impl<T: FromReflect + TypePath> GetTypeRegistration for Vec<T> {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Vec<T>>();
         registration.insert::<ReflectFromPtr>(FromType::<Vec<T>>::from_type());
@@ -394,6 +442,14 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> Map for HashMap<K, V> {
         self.insert(key, value)
             .map(|old_value| Box::new(old_value) as Box<dyn Reflect>)
     }
+
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let value = match key.downcast_ref::<K>() {
+            Some(key) => HashMap::remove(self, key),
+            None => HashMap::remove(self, &K::from_reflect(key)?),
+        };
+        value.map(|value| Box::new(value) as Box<dyn Reflect>)
+    }
 }
 
 impl<K: FromReflect + Eq + Hash, V: FromReflect> Reflect for HashMap<K, V> {
@@ -469,8 +525,8 @@ impl<K: FromReflect + Eq + Hash, V: FromReflect> Typed for HashMap<K, V> {
 
 impl<K, V> GetTypeRegistration for HashMap<K, V>
 where
-    K: FromReflect + Eq + Hash,
-    V: FromReflect,
+    K: FromReflect + TypePath + Eq + Hash,
+    V: FromReflect + TypePath,
 {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<HashMap<K, V>>();
@@ -638,7 +694,7 @@ impl<T: Reflect, const N: usize> Typed for [T; N] {
 macro_rules! impl_array_get_type_registration {
     ($($N:expr)+) => {
         $(
-            impl<T: Reflect > GetTypeRegistration for [T; $N] {
+            impl<T: Reflect + TypePath> GetTypeRegistration for [T; $N] {
                 fn get_type_registration() -> TypeRegistration {
                     TypeRegistration::of::<[T; $N]>()
                 }
@@ -647,6 +703,18 @@ macro_rules! impl_array_get_type_registration {
     };
 }
 
+impl<T: TypePath, const N: usize> TypePath for [T; N] {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::type_path()))
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("[{}; {N}]", T::short_type_path()))
+    }
+}
+
 impl_array_get_type_registration! {
      0  1  2  3  4  5  6  7  8  9
     10 11 12 13 14 15 16 17 18 19
@@ -734,7 +802,7 @@ impl Reflect for Cow<'static, str> {
     }
 }
 
-impl<T: FromReflect> GetTypeRegistration for Option<T> {
+impl<T: FromReflect + TypePath> GetTypeRegistration for Option<T> {
     fn get_type_registration() -> TypeRegistration {
         TypeRegistration::of::<Option<T>>()
     }
@@ -987,6 +1055,16 @@ impl Typed for Cow<'static, str> {
     }
 }
 
+impl TypePath for Cow<'static, str> {
+    fn type_path() -> &'static str {
+        "alloc::borrow::Cow<str>"
+    }
+
+    fn short_type_path() -> &'static str {
+        "Cow<str>"
+    }
+}
+
 impl GetTypeRegistration for Cow<'static, str> {
     fn get_type_registration() -> TypeRegistration {
         let mut registration = TypeRegistration::of::<Cow<'static, str>>();
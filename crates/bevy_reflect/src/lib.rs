@@ -1,7 +1,9 @@
 #![doc = include_str!("../README.md")]
 
 mod array;
+mod diff;
 mod fields;
+mod function;
 mod list;
 mod map;
 mod path;
@@ -10,6 +12,7 @@ mod struct_trait;
 mod tuple;
 mod tuple_struct;
 mod type_info;
+mod type_path;
 mod type_registry;
 mod type_uuid;
 mod impls {
@@ -45,8 +48,10 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
+pub use function::*;
 pub use impls::*;
 pub use list::*;
 pub use map::*;
@@ -56,6 +61,7 @@ pub use struct_trait::*;
 pub use tuple::*;
 pub use tuple_struct::*;
 pub use type_info::*;
+pub use type_path::*;
 pub use type_registry::*;
 pub use type_uuid::*;
 
@@ -835,6 +841,151 @@ mod tests {
         assert!(info.is::<MyDynamic>());
     }
 
+    #[test]
+    fn reflect_field_attributes() {
+        const MAX_SPEED: f32 = 10.0;
+
+        #[derive(Reflect)]
+        struct SomeStruct {
+            #[reflect(range = "0.0..=MAX_SPEED", step = 0.5, tooltip = "Units per second")]
+            speed: f32,
+            #[reflect(range = "1..", read_only)]
+            id: u32,
+            #[reflect(hidden)]
+            cache: Vec<u8>,
+            plain: bool,
+        }
+
+        #[derive(Reflect)]
+        struct SomeTupleStruct(#[reflect(range = "..=-1")] i32);
+
+        #[derive(Reflect)]
+        enum SomeEnum {
+            #[reflect(hidden)]
+            A,
+            B(#[reflect(read_only)] usize),
+            C {
+                #[reflect(tooltip = "Name")]
+                name: String,
+            },
+        }
+
+        let TypeInfo::Struct(info) = SomeStruct::type_info() else {
+            panic!("expected struct info");
+        };
+        assert_eq!(
+            info.field("speed").unwrap().attributes(),
+            &FieldAttributes::default()
+                .with_min(0.0)
+                .with_max(10.0)
+                .with_step(0.5)
+                .with_tooltip("Units per second")
+        );
+        let id = info.field("id").unwrap().attributes();
+        assert_eq!(id.min(), Some(1.0));
+        assert_eq!(id.max(), None);
+        assert!(id.is_read_only());
+        assert!(info.field("cache").unwrap().attributes().is_hidden());
+        assert_eq!(
+            info.field("plain").unwrap().attributes(),
+            &FieldAttributes::default()
+        );
+
+        let TypeInfo::TupleStruct(info) = SomeTupleStruct::type_info() else {
+            panic!("expected tuple struct info");
+        };
+        assert_eq!(info.field_at(0).unwrap().attributes().max(), Some(-1.0));
+
+        let TypeInfo::Enum(info) = SomeEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        assert!(info.variant("A").unwrap().attributes().is_hidden());
+        let Some(VariantInfo::Tuple(variant)) = info.variant("B") else {
+            panic!("expected tuple variant");
+        };
+        assert!(!variant.attributes().is_hidden());
+        assert!(variant.field_at(0).unwrap().attributes().is_read_only());
+        let Some(VariantInfo::Struct(variant)) = info.variant("C") else {
+            panic!("expected struct variant");
+        };
+        assert_eq!(
+            variant.field("name").unwrap().attributes().tooltip(),
+            Some("Name")
+        );
+    }
+
+    #[test]
+    fn reflect_custom_field_attributes() {
+        #[derive(Reflect, Debug, PartialEq)]
+        enum Unit {
+            Kilograms,
+            Meters,
+        }
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Widget {
+            name: String,
+        }
+
+        #[derive(Reflect)]
+        struct SomeStruct {
+            #[reflect(range = "0.0..")]
+            #[reflect(@Unit::Kilograms)]
+            #[reflect(@Widget { name: String::from("slider") })]
+            mass: f32,
+            plain: f32,
+        }
+
+        #[derive(Reflect)]
+        enum SomeEnum {
+            #[reflect(@Unit::Meters)]
+            A,
+        }
+
+        let TypeInfo::Struct(info) = SomeStruct::type_info() else {
+            panic!("expected struct info");
+        };
+        let mass = info.field("mass").unwrap().attributes();
+        assert_eq!(mass.min(), Some(0.0));
+        assert_eq!(mass.custom_attribute::<Unit>(), Some(&Unit::Kilograms));
+        assert!(mass.has_custom_attribute::<Widget>());
+        assert_eq!(mass.custom_attributes().count(), 2);
+        assert_eq!(
+            mass,
+            &FieldAttributes::default()
+                .with_min(0.0)
+                .with_custom_attribute(Widget {
+                    name: String::from("slider")
+                })
+                .with_custom_attribute(Unit::Kilograms)
+        );
+        assert_ne!(
+            mass,
+            &FieldAttributes::default()
+                .with_min(0.0)
+                .with_custom_attribute(Unit::Kilograms)
+        );
+        assert_eq!(
+            info.field("plain")
+                .unwrap()
+                .attributes()
+                .custom_attribute::<Unit>(),
+            None
+        );
+
+        let TypeInfo::Enum(info) = SomeEnum::type_info() else {
+            panic!("expected enum info");
+        };
+        assert_eq!(
+            info.variant("A")
+                .unwrap()
+                .attributes()
+                .custom_attribute_by_id(std::any::TypeId::of::<Unit>())
+                .and_then(|value| value.downcast_ref::<Unit>()),
+            Some(&Unit::Meters)
+        );
+    }
+
     #[cfg(feature = "documentation")]
     mod docstrings {
         use super::*;
@@ -906,7 +1057,7 @@ mod tests {
             let info = <SomeEnum as Typed>::type_info();
             assert_eq!(Some(" Some enum."), info.docs());
 
-            #[derive(Clone)]
+            #[derive(Clone, TypePath)]
             struct SomePrimitive;
             impl_reflect_value!(
                 /// Some primitive for which we have attributed custom documentation.
@@ -1179,7 +1330,7 @@ bevy_reflect::tests::should_reflect_debug::Test {
             let output = to_string_pretty(&ser, config).unwrap();
             let expected = r#"
 {
-    "glam::f32::vec3::Vec3": (
+    "glam::Vec3": (
         x: 12.0,
         y: 3.0,
         z: -6.9,
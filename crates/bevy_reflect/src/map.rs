@@ -57,6 +57,12 @@ pub trait Map: Reflect {
         key: Box<dyn Reflect>,
         value: Box<dyn Reflect>,
     ) -> Option<Box<dyn Reflect>>;
+
+    /// Removes an entry from the map.
+    ///
+    /// If the map did not have this key present, `None` is returned.
+    /// If the map did have this key present, the removed value is returned.
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>>;
 }
 
 /// A container for compile-time map info.
@@ -246,6 +252,20 @@ impl Map for DynamicMap {
         }
     }
 
+    fn remove(&mut self, key: &dyn Reflect) -> Option<Box<dyn Reflect>> {
+        let index = self
+            .indices
+            .remove(&key.reflect_hash().expect(HASH_ERROR))?;
+        let (_key, value) = self.values.remove(index);
+        // Keeps the order of the other entries.
+        for other in self.indices.values_mut() {
+            if *other > index {
+                *other -= 1;
+            }
+        }
+        Some(value)
+    }
+
     fn drain(self: Box<Self>) -> Vec<(Box<dyn Reflect>, Box<dyn Reflect>)> {
         self.values
     }
@@ -452,7 +472,7 @@ pub fn map_apply<M: Map>(a: &mut M, b: &dyn Reflect) {
 
 #[cfg(test)]
 mod tests {
-    use super::DynamicMap;
+    use super::{DynamicMap, Map};
 
     #[test]
     fn test_into_iter() {
@@ -473,4 +493,24 @@ mod tests {
             assert_eq!(expected[index], value);
         }
     }
+
+    #[test]
+    fn remove_keeps_order() {
+        let mut map = DynamicMap::default();
+        map.insert(0usize, "foo".to_string());
+        map.insert(1usize, "bar".to_string());
+        map.insert(2usize, "baz".to_string());
+
+        let removed = map.remove(&1usize).unwrap().take::<String>().unwrap();
+        assert_eq!(removed, "bar");
+        assert!(map.remove(&1usize).is_none());
+        assert_eq!(map.len(), 2);
+
+        let keys: Vec<_> = map
+            .iter()
+            .map(|(key, _)| *key.downcast_ref::<usize>().unwrap())
+            .collect();
+        assert_eq!(keys, [0, 2]);
+        assert!(map.get(&2usize).is_some());
+    }
 }
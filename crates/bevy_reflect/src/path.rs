@@ -25,6 +25,8 @@ pub enum ReflectPathError<'a> {
     ExpectedStruct { index: usize },
     #[error("expected a list, but found a different reflect value")]
     ExpectedList { index: usize },
+    #[error("expected a map, but found a different reflect value")]
+    ExpectedMap { index: usize },
     #[error("failed to parse a usize")]
     IndexParseError(#[from] ParseIntError),
     #[error("failed to downcast to the path result to the given type")]
@@ -280,6 +282,15 @@ fn read_field<'r, 'p>(
                 },
             )?)
         }
+        ReflectRef::Tuple(reflect_tuple) => {
+            let tuple_index = field.parse::<usize>()?;
+            Ok(reflect_tuple.field(tuple_index).ok_or(
+                ReflectPathError::InvalidTupleStructIndex {
+                    index: current_index,
+                    tuple_struct_index: tuple_index,
+                },
+            )?)
+        }
         _ => Err(ReflectPathError::ExpectedStruct {
             index: current_index,
         }),
@@ -309,6 +320,15 @@ fn read_field_mut<'r, 'p>(
                 },
             )?)
         }
+        ReflectMut::Tuple(reflect_tuple) => {
+            let tuple_index = field.parse::<usize>()?;
+            Ok(reflect_tuple.field_mut(tuple_index).ok_or(
+                ReflectPathError::InvalidTupleStructIndex {
+                    index: current_index,
+                    tuple_struct_index: tuple_index,
+                },
+            )?)
+        }
         _ => Err(ReflectPathError::ExpectedStruct {
             index: current_index,
         }),
@@ -482,4 +502,14 @@ mod tests {
             Err(ReflectPathError::IndexParseError(_))
         ));
     }
+
+    #[test]
+    fn reflect_path_tuple() {
+        let mut value = (1usize, (2.0f32, vec![3u8]));
+        assert_eq!(*value.get_path::<usize>("0").unwrap(), 1);
+        assert_eq!(*value.get_path::<u8>("1.1[0]").unwrap(), 3);
+
+        *value.get_path_mut::<f32>(".1.0").unwrap() = 4.0;
+        assert_eq!(value.1 .0, 4.0);
+    }
 }
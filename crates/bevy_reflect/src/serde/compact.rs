@@ -0,0 +1,400 @@
+use crate::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    Reflect, TypeInfo, TypeRegistry, VariantInfo,
+};
+use bevy_utils::HashMap;
+use serde::{
+    de::{DeserializeSeed, Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Serialize,
+};
+use std::{
+    any::TypeId,
+    fmt::{self, Formatter},
+};
+
+/// Stable indices for the types of a [`TypeRegistry`], along with a fingerprint of their shape.
+///
+/// Types are indexed in the order of their [type path], so two registries containing the same
+/// types assign them the same indices, whatever the order they were registered in. The
+/// fingerprint hashes the path and the [`TypeInfo`] of every type, including field names and
+/// types, enum variants and item types. It changes whenever a type is added, removed or has its
+/// layout changed, which lets [`CompactReflectDeserializer`] reject data written with another
+/// schema instead of misreading it.
+///
+/// Since type paths are stable across compiler versions, so are the indices and the fingerprint.
+/// Field types missing from the registry are hashed with their [type name], which isn't.
+///
+/// [type path]: crate::TypePath
+/// [type name]: std::any::type_name
+pub struct RegistrySchema {
+    type_paths: Vec<&'static str>,
+    indices: HashMap<&'static str, u32>,
+    fingerprint: u64,
+}
+
+impl RegistrySchema {
+    /// Builds the schema of the types currently registered in `registry`.
+    pub fn new(registry: &TypeRegistry) -> Self {
+        let mut registrations: Vec<_> = registry.iter().collect();
+        registrations.sort_by_key(|registration| registration.type_path());
+
+        let mut hasher = FnvHasher::default();
+        let mut type_paths = Vec::with_capacity(registrations.len());
+        let mut indices = HashMap::default();
+        for (index, registration) in registrations.into_iter().enumerate() {
+            let type_path = registration.type_path();
+            hasher.write_str(type_path);
+            hash_type_info(&mut hasher, registry, registration.type_info());
+            type_paths.push(type_path);
+            indices.insert(type_path, index as u32);
+        }
+
+        RegistrySchema {
+            type_paths,
+            indices,
+            fingerprint: hasher.0,
+        }
+    }
+
+    /// Returns the fingerprint of the registered types.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Returns the index of the type with the given [type path].
+    ///
+    /// [type path]: crate::TypePath
+    pub fn index_of(&self, type_path: &str) -> Option<u32> {
+        self.indices.get(type_path).copied()
+    }
+
+    /// Returns the [type path] of the type at `index`.
+    ///
+    /// [type path]: crate::TypePath
+    pub fn type_path_at(&self, index: u32) -> Option<&'static str> {
+        self.type_paths.get(index as usize).copied()
+    }
+
+    /// Returns the number of indexed types.
+    pub fn len(&self) -> usize {
+        self.type_paths.len()
+    }
+
+    /// Returns `true` if no type is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.type_paths.is_empty()
+    }
+}
+
+/// 64-bit FNV-1a, used for the fingerprint since it must not depend on the platform or on a
+/// random seed.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_u64(value.len() as u64);
+        self.write(value.as_bytes());
+    }
+
+    /// Writes the type path of a type, or its type name if it isn't registered.
+    fn write_type(&mut self, registry: &TypeRegistry, type_id: TypeId, type_name: &str) {
+        match registry.get(type_id) {
+            Some(registration) => self.write_str(registration.type_path()),
+            None => self.write_str(type_name),
+        }
+    }
+}
+
+fn hash_type_info(hasher: &mut FnvHasher, registry: &TypeRegistry, type_info: &TypeInfo) {
+    match type_info {
+        TypeInfo::Struct(info) => {
+            hasher.write_str("struct");
+            hasher.write_u64(info.field_len() as u64);
+            for field in info.iter() {
+                hasher.write_str(field.name());
+                hasher.write_type(registry, field.type_id(), field.type_name());
+            }
+        }
+        TypeInfo::TupleStruct(info) => {
+            hasher.write_str("tuple_struct");
+            hasher.write_u64(info.field_len() as u64);
+            for field in info.iter() {
+                hasher.write_type(registry, field.type_id(), field.type_name());
+            }
+        }
+        TypeInfo::Tuple(info) => {
+            hasher.write_str("tuple");
+            hasher.write_u64(info.field_len() as u64);
+            for field in info.iter() {
+                hasher.write_type(registry, field.type_id(), field.type_name());
+            }
+        }
+        TypeInfo::List(info) => {
+            hasher.write_str("list");
+            hasher.write_type(registry, info.item_type_id(), info.item_type_name());
+        }
+        TypeInfo::Array(info) => {
+            hasher.write_str("array");
+            hasher.write_type(registry, info.item_type_id(), info.item_type_name());
+            hasher.write_u64(info.capacity() as u64);
+        }
+        TypeInfo::Map(info) => {
+            hasher.write_str("map");
+            hasher.write_type(registry, info.key_type_id(), info.key_type_name());
+            hasher.write_type(registry, info.value_type_id(), info.value_type_name());
+        }
+        TypeInfo::Enum(info) => {
+            hasher.write_str("enum");
+            hasher.write_u64(info.variant_len() as u64);
+            for variant in info.iter() {
+                hasher.write_str(variant.name());
+                match variant {
+                    VariantInfo::Struct(variant) => {
+                        hasher.write_u64(variant.field_len() as u64);
+                        for field in variant.iter() {
+                            hasher.write_str(field.name());
+                            hasher.write_type(registry, field.type_id(), field.type_name());
+                        }
+                    }
+                    VariantInfo::Tuple(variant) => {
+                        hasher.write_u64(variant.field_len() as u64);
+                        for field in variant.iter() {
+                            hasher.write_type(registry, field.type_id(), field.type_name());
+                        }
+                    }
+                    VariantInfo::Unit(_) => hasher.write_u64(0),
+                }
+            }
+        }
+        TypeInfo::Value(_) => hasher.write_str("value"),
+        TypeInfo::Dynamic(_) => hasher.write_str("dynamic"),
+    }
+}
+
+/// A compact serializer for reflected types, meant for non-self-describing binary formats.
+///
+/// Where [`ReflectSerializer`](crate::serde::ReflectSerializer) writes the full type path of the
+/// value, this serializer writes a tuple of:
+/// 1. the [fingerprint](RegistrySchema::fingerprint) of the schema,
+/// 2. the [index](RegistrySchema::index_of) of the type in the schema,
+/// 3. the value, serialized with [`TypedReflectSerializer`].
+///
+/// The data can be read back with [`CompactReflectDeserializer`], using a schema with the same
+/// fingerprint.
+pub struct CompactReflectSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+    pub schema: &'a RegistrySchema,
+}
+
+impl<'a> CompactReflectSerializer<'a> {
+    pub fn new(
+        value: &'a dyn Reflect,
+        registry: &'a TypeRegistry,
+        schema: &'a RegistrySchema,
+    ) -> Self {
+        CompactReflectSerializer {
+            value,
+            registry,
+            schema,
+        }
+    }
+}
+
+impl<'a> Serialize for CompactReflectSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let type_path = self
+            .registry
+            .get_with_value(self.value)
+            .map_or(self.value.type_name(), |registration| {
+                registration.type_path()
+            });
+        let index = self.schema.index_of(type_path).ok_or_else(|| {
+            serde::ser::Error::custom(format_args!(
+                "type `{type_path}` is not part of the registry schema"
+            ))
+        })?;
+        let mut state = serializer.serialize_tuple(3)?;
+        state.serialize_element(&self.schema.fingerprint)?;
+        state.serialize_element(&index)?;
+        state.serialize_element(&TypedReflectSerializer::new(self.value, self.registry))?;
+        state.end()
+    }
+}
+
+/// A deserializer for values serialized with [`CompactReflectSerializer`].
+///
+/// Deserialization fails if the fingerprint of the data doesn't match the one of `schema`.
+/// Like [`TypedReflectDeserializer`], this returns the dynamic equivalent of non-value types.
+pub struct CompactReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    schema: &'a RegistrySchema,
+}
+
+impl<'a> CompactReflectDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry, schema: &'a RegistrySchema) -> Self {
+        Self { registry, schema }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(3, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for CompactReflectDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("(fingerprint, type index, value) tuple")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let fingerprint: u64 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        if fingerprint != self.schema.fingerprint {
+            return Err(Error::custom(format_args!(
+                "schema fingerprint mismatch: expected {:016x}, found {fingerprint:016x}",
+                self.schema.fingerprint
+            )));
+        }
+        let index: u32 = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        let registration = self
+            .schema
+            .type_path_at(index)
+            .and_then(|type_path| self.registry.get_with_type_path(type_path))
+            .ok_or_else(|| Error::custom(format_args!("no type found at index {index}")))?;
+        seq.next_element_seed(TypedReflectDeserializer::new(registration, self.registry))?
+            .ok_or_else(|| Error::invalid_length(2, &self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_reflect,
+        serde::{
+            CompactReflectDeserializer, CompactReflectSerializer, ReflectSerializer, RegistrySchema,
+        },
+        FromReflect, Reflect, TypePath, TypeRegistry,
+    };
+    use bincode::Options;
+
+    #[derive(Reflect, FromReflect, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        position: (f32, f32),
+        inventory: Vec<u32>,
+    }
+
+    #[derive(Reflect)]
+    struct Unrelated(u8);
+
+    fn get_registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<String>();
+        registry.register::<f32>();
+        registry.register::<u32>();
+        registry.register::<(f32, f32)>();
+        registry.register::<Vec<u32>>();
+        registry
+    }
+
+    #[test]
+    fn compact_roundtrip() {
+        let registry = get_registry();
+        let schema = RegistrySchema::new(&registry);
+        let input = Player {
+            name: "Ferris".to_string(),
+            position: (1.0, 2.0),
+            inventory: vec![3, 4],
+        };
+
+        let serializer = CompactReflectSerializer::new(&input, &registry, &schema);
+        let bytes = bincode::serialize(&serializer).unwrap();
+        let full = bincode::serialize(&ReflectSerializer::new(&input, &registry)).unwrap();
+        assert!(bytes.len() < full.len());
+
+        let value = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(CompactReflectDeserializer::new(&registry, &schema), &bytes)
+            .unwrap();
+        assert_eq!(Player::from_reflect(value.as_ref()).unwrap(), input);
+    }
+
+    #[test]
+    fn schema_is_independent_of_registration_order() {
+        let mut registry = TypeRegistry::empty();
+        registry.register::<u32>();
+        registry.register::<Player>();
+        let mut reversed = TypeRegistry::empty();
+        reversed.register::<Player>();
+        reversed.register::<u32>();
+
+        let schema = RegistrySchema::new(&registry);
+        let reversed = RegistrySchema::new(&reversed);
+        assert_eq!(schema.fingerprint(), reversed.fingerprint());
+        assert_eq!(
+            schema.index_of(Player::type_path()),
+            reversed.index_of(Player::type_path())
+        );
+    }
+
+    #[test]
+    fn fingerprint_mismatch() {
+        let registry = get_registry();
+        let schema = RegistrySchema::new(&registry);
+        let serializer = CompactReflectSerializer::new(&1u32, &registry, &schema);
+        let bytes = bincode::serialize(&serializer).unwrap();
+
+        let mut other_registry = get_registry();
+        other_registry.register::<Unrelated>();
+        let other_schema = RegistrySchema::new(&other_registry);
+        assert_ne!(schema.fingerprint(), other_schema.fingerprint());
+
+        let result = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(
+                CompactReflectDeserializer::new(&other_registry, &other_schema),
+                &bytes,
+            );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("schema fingerprint mismatch"));
+    }
+}
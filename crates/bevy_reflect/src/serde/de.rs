@@ -223,7 +223,7 @@ impl<'de> Visitor<'de> for U32Visitor {
 ///
 /// Because the type isn't known ahead of time, the serialized data must take the form of
 /// a map containing the following entries (in order):
-/// 1. `type`: The _full_ [type name]
+/// 1. `type`: The _full_ [type path], or the full [type name] for data written before type paths
 /// 2. `value`: The serialized value of the reflected type
 ///
 /// If the type is already known and the [`TypeInfo`] for it can be retrieved,
@@ -233,6 +233,7 @@ impl<'de> Visitor<'de> for U32Visitor {
 /// [`DynamicStruct`]: crate::DynamicStruct
 /// [`DynamicList`]: crate::DynamicList
 /// [`FromReflect`]: crate::FromReflect
+/// [type path]: crate::TypePath
 /// [type name]: std::any::type_name
 pub struct UntypedReflectDeserializer<'a> {
     registry: &'a TypeRegistry,
@@ -272,13 +273,18 @@ impl<'a, 'de> Visitor<'de> for UntypedReflectDeserializerVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
-        let type_name = map
+        let type_path = map
             .next_key::<String>()?
             .ok_or_else(|| Error::invalid_length(0, &"at least one entry"))?;
 
-        let registration = self.registry.get_with_name(&type_name).ok_or_else(|| {
-            Error::custom(format_args!("No registration found for `{type_name}`"))
-        })?;
+        // Data written before type paths were used is keyed by type name
+        let registration = self
+            .registry
+            .get_with_type_path(&type_path)
+            .or_else(|| self.registry.get_with_name(&type_path))
+            .ok_or_else(|| {
+                Error::custom(format_args!("No registration found for `{type_path}`"))
+            })?;
         let value = map.next_value_seed(TypedReflectDeserializer {
             registration,
             registry: self.registry,
@@ -998,7 +1004,7 @@ mod tests {
 
     use crate as bevy_reflect;
     use crate::serde::{TypedReflectDeserializer, UntypedReflectDeserializer};
-    use crate::{DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypeRegistry};
+    use crate::{DynamicEnum, FromReflect, Reflect, ReflectDeserialize, TypePath, TypeRegistry};
 
     #[derive(Reflect, FromReflect, Debug, PartialEq)]
     struct MyStruct {
@@ -1169,6 +1175,25 @@ mod tests {
         assert_eq!(1.23, output);
     }
 
+    #[test]
+    fn should_deserialize_type_path_or_name() {
+        let registry = get_registry();
+        let type_path = <HashMap<u8, usize>>::type_path();
+        let type_name = std::any::type_name::<HashMap<u8, usize>>();
+        assert_ne!(type_path, type_name);
+
+        for key in [type_path, type_name] {
+            let input = format!(r#"{{ "{key}": {{ 1: 2 }} }}"#);
+            let reflect_deserializer = UntypedReflectDeserializer::new(&registry);
+            let mut ron_deserializer = ron::de::Deserializer::from_str(&input).unwrap();
+            let dynamic_output = reflect_deserializer
+                .deserialize(&mut ron_deserializer)
+                .unwrap();
+            let output = <HashMap<u8, usize>>::from_reflect(dynamic_output.as_ref()).unwrap();
+            assert_eq!(output.get(&1), Some(&2));
+        }
+    }
+
     #[test]
     fn should_deserialized_typed() {
         #[derive(Reflect, FromReflect, Debug, PartialEq)]
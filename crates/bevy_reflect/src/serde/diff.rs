@@ -0,0 +1,349 @@
+use crate::{
+    serde::{ReflectSerializer, UntypedReflectDeserializer},
+    Reflect, ReflectChange, ReflectDiff, TypeRegistry,
+};
+use serde::{
+    de::{DeserializeSeed, Error, SeqAccess, Visitor},
+    ser::{SerializeSeq, SerializeTuple},
+    Serialize,
+};
+use std::fmt::{self, Formatter};
+
+const SET: &str = "set";
+const INSERT: &str = "insert";
+const REMOVE: &str = "remove";
+const PUSH: &str = "push";
+const TRUNCATE: &str = "truncate";
+
+/// A serializer for [`ReflectDiff`].
+///
+/// The diff is serialized as a sequence of `(operation, path, payload)` tuples, where the
+/// operation is one of `set`, `insert`, `remove`, `push` or `truncate`. The payload of `set` and
+/// `push` is the value serialized with [`ReflectSerializer`], the payload of `remove` is the key
+/// serialized the same way, the payload of `insert` is a `(key, value)` tuple of both, and the
+/// payload of `truncate` is the new length.
+pub struct ReflectDiffSerializer<'a> {
+    pub diff: &'a ReflectDiff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        ReflectDiffSerializer { diff, registry }
+    }
+}
+
+impl<'a> Serialize for ReflectDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.diff.len()))?;
+        for change in self.diff.changes() {
+            state.serialize_element(&ReflectChangeSerializer {
+                change,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct ReflectChangeSerializer<'a> {
+    change: &'a ReflectChange,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ReflectChangeSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_tuple(3)?;
+        match self.change {
+            ReflectChange::Set { path, value } => {
+                state.serialize_element(SET)?;
+                state.serialize_element(path)?;
+                state.serialize_element(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+            }
+            ReflectChange::Insert { path, key, value } => {
+                state.serialize_element(INSERT)?;
+                state.serialize_element(path)?;
+                state.serialize_element(&(
+                    ReflectSerializer::new(key.as_ref(), self.registry),
+                    ReflectSerializer::new(value.as_ref(), self.registry),
+                ))?;
+            }
+            ReflectChange::Remove { path, key } => {
+                state.serialize_element(REMOVE)?;
+                state.serialize_element(path)?;
+                state.serialize_element(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+            }
+            ReflectChange::Push { path, value } => {
+                state.serialize_element(PUSH)?;
+                state.serialize_element(path)?;
+                state.serialize_element(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+            }
+            ReflectChange::Truncate { path, len } => {
+                state.serialize_element(TRUNCATE)?;
+                state.serialize_element(path)?;
+                state.serialize_element(len)?;
+            }
+        }
+        state.end()
+    }
+}
+
+/// A deserializer for [`ReflectDiff`]s serialized with [`ReflectDiffSerializer`].
+///
+/// The values of the diff are deserialized with [`UntypedReflectDeserializer`], so their types
+/// must be registered in the [`TypeRegistry`].
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'a> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ReflectDiffVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct ReflectDiffVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ReflectDiffVisitor<'a> {
+    type Value = ReflectDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence of reflect changes")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut changes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(change) = seq.next_element_seed(ReflectChangeDeserializer {
+            registry: self.registry,
+        })? {
+            changes.push(change);
+        }
+        Ok(ReflectDiff::from_changes(changes))
+    }
+}
+
+struct ReflectChangeDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectChangeDeserializer<'a> {
+    type Value = ReflectChange;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(3, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ReflectChangeDeserializer<'a> {
+    type Value = ReflectChange;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("(operation, path, payload) tuple")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let operation: String = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let path: String = seq
+            .next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        let change = match operation.as_str() {
+            SET | REMOVE | PUSH => {
+                let value = seq
+                    .next_element_seed(UntypedReflectDeserializer::new(self.registry))?
+                    .ok_or_else(|| Error::invalid_length(2, &self))?;
+                match operation.as_str() {
+                    SET => ReflectChange::Set { path, value },
+                    REMOVE => ReflectChange::Remove { path, key: value },
+                    _ => ReflectChange::Push { path, value },
+                }
+            }
+            INSERT => {
+                let (key, value) = seq
+                    .next_element_seed(MapEntryDeserializer {
+                        registry: self.registry,
+                    })?
+                    .ok_or_else(|| Error::invalid_length(2, &self))?;
+                ReflectChange::Insert { path, key, value }
+            }
+            TRUNCATE => {
+                let len = seq
+                    .next_element()?
+                    .ok_or_else(|| Error::invalid_length(2, &self))?;
+                ReflectChange::Truncate { path, len }
+            }
+            operation => {
+                return Err(Error::unknown_variant(
+                    operation,
+                    &[SET, INSERT, REMOVE, PUSH, TRUNCATE],
+                ));
+            }
+        };
+        Ok(change)
+    }
+}
+
+/// Deserializes the `(key, value)` payload of an `insert` change.
+struct MapEntryDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MapEntryDeserializer<'a> {
+    type Value = (Box<dyn Reflect>, Box<dyn Reflect>);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapEntryDeserializer<'a> {
+    type Value = (Box<dyn Reflect>, Box<dyn Reflect>);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("(key, value) tuple")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let key = seq
+            .next_element_seed(UntypedReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let value = seq
+            .next_element_seed(UntypedReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+        Ok((key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_reflect,
+        serde::{ReflectDiffDeserializer, ReflectDiffSerializer},
+        FromReflect, Reflect, ReflectDiff, TypeRegistry,
+    };
+    use bevy_utils::HashMap;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, FromReflect, Clone, PartialEq, Debug)]
+    struct Item {
+        id: u32,
+        tags: Vec<String>,
+    }
+
+    #[derive(Reflect, FromReflect, Clone, PartialEq, Debug)]
+    struct Inventory {
+        gold: u64,
+        items: Vec<Item>,
+    }
+
+    #[test]
+    fn diff_roundtrip() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<u32>();
+        registry.register::<u64>();
+        registry.register::<String>();
+        registry.register::<Vec<String>>();
+        registry.register::<Item>();
+
+        let old = Inventory {
+            gold: 10,
+            items: vec![Item {
+                id: 1,
+                tags: vec!["sword".to_string(), "rusty".to_string()],
+            }],
+        };
+        let new = Inventory {
+            gold: 5,
+            items: vec![
+                Item {
+                    id: 1,
+                    tags: vec!["sword".to_string()],
+                },
+                Item {
+                    id: 2,
+                    tags: Vec::new(),
+                },
+            ],
+        };
+        let diff = ReflectDiff::new(&old, &new);
+
+        let serializer = ReflectDiffSerializer::new(&diff, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"[("set","gold",{"u64":5}),("truncate","items[0].tags",1),("push","items",{"bevy_reflect::serde::diff::tests::Item":(id:2,tags:[])})]"#;
+        assert_eq!(expected, output);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let diff = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut value = old;
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+
+    #[test]
+    fn diff_map_roundtrip() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<u32>();
+        registry.register::<String>();
+
+        let mut old = HashMap::default();
+        old.insert("apple".to_string(), 1u32);
+        old.insert("sword".to_string(), 1u32);
+        let mut new = HashMap::default();
+        new.insert("apple".to_string(), 3u32);
+        let diff = ReflectDiff::new(&old, &new);
+
+        let serializer = ReflectDiffSerializer::new(&diff, &registry);
+        let output = ron::ser::to_string(&serializer).unwrap();
+        let expected = r#"[("remove","",{"alloc::string::String":"sword"}),("insert","",({"alloc::string::String":"apple"},{"u32":3}))]"#;
+        assert_eq!(expected, output);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let diff = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut value = old;
+        diff.apply(&mut value).unwrap();
+        assert_eq!(value, new);
+    }
+}
@@ -0,0 +1,506 @@
+use crate::{
+    serde::SerializationData, ReflectSerialize, TypeInfo, TypeRegistration, TypeRegistry,
+    VariantInfo,
+};
+use serde::{
+    ser::{SerializeMap, SerializeSeq},
+    Serialize,
+};
+use std::{borrow::Cow, fmt::Write};
+
+/// A serializer emitting the [JSON Schema] of the types of a [`TypeRegistry`].
+///
+/// The schema describes the exact form produced by [`ReflectSerializer`] when serializing to
+/// JSON: a map with a single entry from the [type path] of the value to the value itself. The
+/// schema of every registered type is also available under `$defs`, keyed by type path, so
+/// external tools can validate values whose type is already known, as produced by
+/// [`TypedReflectSerializer`].
+///
+/// Types registering [`ReflectSerialize`] are serialized with their own [`Serialize`]
+/// implementation, whatever their kind. The schema of primitives and strings is known, while
+/// other types registering it, like the vectors of `glam`, are described with an empty schema
+/// accepting any value.
+///
+/// # Example
+/// ```
+/// # use bevy_reflect::{Reflect, TypePath, TypeRegistry, serde::JsonSchemaSerializer};
+/// #[derive(Reflect)]
+/// struct Player {
+///     name: String,
+///     health: f32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Player>();
+///
+/// let schema = serde_json::to_value(JsonSchemaSerializer::new(&registry)).unwrap();
+/// let player = &schema["$defs"][Player::type_path()];
+/// assert_eq!(player["required"], serde_json::json!(["name", "health"]));
+/// ```
+///
+/// [JSON Schema]: https://json-schema.org/draft/2020-12/json-schema-core.html
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [`TypedReflectSerializer`]: crate::serde::TypedReflectSerializer
+/// [`ReflectSerialize`]: crate::ReflectSerialize
+/// [type path]: crate::TypePath
+pub struct JsonSchemaSerializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> JsonSchemaSerializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        JsonSchemaSerializer { registry }
+    }
+
+    fn schema(&self) -> Schema {
+        let mut registrations: Vec<_> = self.registry.iter().collect();
+        registrations.sort_by_key(|registration| registration.type_path());
+
+        let properties = registrations
+            .iter()
+            .map(|registration| {
+                (
+                    registration.type_path(),
+                    self.reference(registration.type_name()),
+                )
+            })
+            .collect();
+        let definitions = registrations
+            .iter()
+            .map(|registration| (registration.type_path(), self.definition(registration)))
+            .collect();
+
+        Schema::Object(vec![
+            (
+                "$schema",
+                Schema::str("https://json-schema.org/draft/2020-12/schema"),
+            ),
+            ("type", Schema::str("object")),
+            ("properties", Schema::Object(properties)),
+            ("additionalProperties", Schema::Bool(false)),
+            ("minProperties", Schema::Integer(1)),
+            ("maxProperties", Schema::Integer(1)),
+            ("$defs", Schema::Object(definitions)),
+        ])
+    }
+
+    /// Returns the schema of the registered type.
+    fn definition(&self, registration: &TypeRegistration) -> Schema {
+        let type_info = registration.type_info();
+        let mut schema = match type_info {
+            TypeInfo::Value(info) => {
+                primitive(info.type_name()).unwrap_or_else(|| Schema::Object(Vec::new()))
+            }
+            // The custom `Serialize` implementation is used instead of the reflected fields.
+            _ if registration.data::<ReflectSerialize>().is_some() => Schema::Object(Vec::new()),
+            TypeInfo::Struct(info) => {
+                let serialization_data = registration.data::<SerializationData>();
+                let fields: Vec<_> = info
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| !is_ignored(serialization_data, *index))
+                    .map(|(_, field)| field)
+                    .collect();
+                let properties = fields
+                    .iter()
+                    .map(|field| {
+                        #[allow(unused_mut)]
+                        let mut schema = self.reference(field.type_name());
+                        #[cfg(feature = "documentation")]
+                        if let Some(docs) = field.docs() {
+                            schema = Schema::Object(vec![
+                                ("allOf", Schema::Array(vec![schema])),
+                                ("description", Schema::str(docs.trim())),
+                            ]);
+                        }
+                        (field.name(), schema)
+                    })
+                    .collect();
+                let required = fields
+                    .iter()
+                    .map(|field| Schema::str(field.name()))
+                    .collect();
+                object(properties, required)
+            }
+            TypeInfo::TupleStruct(info) => {
+                let serialization_data = registration.data::<SerializationData>();
+                tuple(
+                    info.iter()
+                        .enumerate()
+                        .filter(|(index, _)| !is_ignored(serialization_data, *index))
+                        .map(|(_, field)| self.reference(field.type_name()))
+                        .collect(),
+                )
+            }
+            TypeInfo::Tuple(info) => tuple(
+                info.iter()
+                    .map(|field| self.reference(field.type_name()))
+                    .collect(),
+            ),
+            TypeInfo::List(info) => Schema::Object(vec![
+                ("type", Schema::str("array")),
+                ("items", self.reference(info.item_type_name())),
+            ]),
+            TypeInfo::Array(info) => Schema::Object(vec![
+                ("type", Schema::str("array")),
+                ("items", self.reference(info.item_type_name())),
+                ("minItems", Schema::Integer(info.capacity() as i64)),
+                ("maxItems", Schema::Integer(info.capacity() as i64)),
+            ]),
+            TypeInfo::Map(info) => Schema::Object(vec![
+                ("type", Schema::str("object")),
+                (
+                    "additionalProperties",
+                    self.reference(info.value_type_name()),
+                ),
+            ]),
+            TypeInfo::Enum(info) if info.type_name().starts_with("core::option::Option") => {
+                let some = match info.variant("Some") {
+                    Some(VariantInfo::Tuple(variant)) => variant
+                        .field_at(0)
+                        .map(|field| self.reference(field.type_name())),
+                    _ => None,
+                };
+                Schema::Object(vec![(
+                    "oneOf",
+                    Schema::Array(vec![
+                        Schema::Object(vec![("type", Schema::str("null"))]),
+                        some.unwrap_or_else(|| Schema::Object(Vec::new())),
+                    ]),
+                )])
+            }
+            TypeInfo::Enum(info) => Schema::Object(vec![(
+                "oneOf",
+                Schema::Array(info.iter().map(|variant| self.variant(variant)).collect()),
+            )]),
+            TypeInfo::Dynamic(_) => Schema::Object(Vec::new()),
+        };
+
+        #[cfg(feature = "documentation")]
+        if let (Some(docs), Schema::Object(entries)) = (type_info.docs(), &mut schema) {
+            entries.push(("description", Schema::str(docs.trim())));
+        }
+        if let Schema::Object(entries) = &mut schema {
+            entries.insert(
+                0,
+                ("title", Schema::str(registration.short_name().to_string())),
+            );
+        }
+        schema
+    }
+
+    /// Returns the schema of an enum variant, externally tagged like serde does by default.
+    fn variant(&self, variant: &VariantInfo) -> Schema {
+        let value = match variant {
+            VariantInfo::Unit(variant) => {
+                return Schema::Object(vec![("const", Schema::str(variant.name()))]);
+            }
+            VariantInfo::Tuple(variant) if variant.field_len() == 1 => {
+                self.reference(variant.field_at(0).unwrap().type_name())
+            }
+            VariantInfo::Tuple(variant) => tuple(
+                variant
+                    .iter()
+                    .map(|field| self.reference(field.type_name()))
+                    .collect(),
+            ),
+            VariantInfo::Struct(variant) => object(
+                variant
+                    .iter()
+                    .map(|field| (field.name(), self.reference(field.type_name())))
+                    .collect(),
+                variant
+                    .iter()
+                    .map(|field| Schema::str(field.name()))
+                    .collect(),
+            ),
+        };
+        object(
+            vec![(variant.name(), value)],
+            vec![Schema::str(variant.name())],
+        )
+    }
+
+    /// Returns a reference to the definition of the given type, or its inlined schema if it
+    /// isn't registered.
+    fn reference(&self, type_name: &'static str) -> Schema {
+        if let Some(registration) = self.registry.get_with_name(type_name) {
+            Schema::Object(vec![(
+                "$ref",
+                Schema::String(definition_ref(registration.type_path()).into()),
+            )])
+        } else {
+            primitive(type_name).unwrap_or_else(|| Schema::Object(Vec::new()))
+        }
+    }
+}
+
+impl<'a> Serialize for JsonSchemaSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.schema().serialize(serializer)
+    }
+}
+
+/// A JSON value of a schema.
+enum Schema {
+    Bool(bool),
+    Integer(i64),
+    String(Cow<'static, str>),
+    Array(Vec<Schema>),
+    Object(Vec<(&'static str, Schema)>),
+}
+
+impl Schema {
+    fn str(value: impl Into<Cow<'static, str>>) -> Self {
+        Schema::String(value.into())
+    }
+}
+
+impl Serialize for Schema {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Schema::Bool(value) => serializer.serialize_bool(*value),
+            Schema::Integer(value) => serializer.serialize_i64(*value),
+            Schema::String(value) => serializer.serialize_str(value),
+            Schema::Array(values) => {
+                let mut state = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    state.serialize_element(value)?;
+                }
+                state.end()
+            }
+            Schema::Object(entries) => {
+                let mut state = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    state.serialize_entry(key, value)?;
+                }
+                state.end()
+            }
+        }
+    }
+}
+
+fn is_ignored(serialization_data: Option<&SerializationData>, index: usize) -> bool {
+    serialization_data
+        .map(|data| data.is_ignored_field(index))
+        .unwrap_or(false)
+}
+
+fn object(properties: Vec<(&'static str, Schema)>, required: Vec<Schema>) -> Schema {
+    Schema::Object(vec![
+        ("type", Schema::str("object")),
+        ("properties", Schema::Object(properties)),
+        ("required", Schema::Array(required)),
+        ("additionalProperties", Schema::Bool(false)),
+    ])
+}
+
+fn tuple(items: Vec<Schema>) -> Schema {
+    let len = items.len() as i64;
+    Schema::Object(vec![
+        ("type", Schema::str("array")),
+        ("prefixItems", Schema::Array(items)),
+        ("minItems", Schema::Integer(len)),
+        ("maxItems", Schema::Integer(len)),
+    ])
+}
+
+/// Returns the schema of the primitive with the given type name, if it is one.
+fn primitive(type_name: &str) -> Option<Schema> {
+    let integer = |minimum: i64, maximum: Option<i64>| {
+        let mut entries = vec![
+            ("type", Schema::str("integer")),
+            ("minimum", Schema::Integer(minimum)),
+        ];
+        if let Some(maximum) = maximum {
+            entries.push(("maximum", Schema::Integer(maximum)));
+        }
+        Schema::Object(entries)
+    };
+    let schema = match type_name {
+        "bool" => Schema::Object(vec![("type", Schema::str("boolean"))]),
+        "u8" => integer(0, Some(u8::MAX.into())),
+        "u16" => integer(0, Some(u16::MAX.into())),
+        "u32" => integer(0, Some(u32::MAX.into())),
+        "u64" | "u128" | "usize" => integer(0, None),
+        "i8" => integer(i8::MIN.into(), Some(i8::MAX.into())),
+        "i16" => integer(i16::MIN.into(), Some(i16::MAX.into())),
+        "i32" => integer(i32::MIN.into(), Some(i32::MAX.into())),
+        "i64" | "i128" | "isize" => Schema::Object(vec![("type", Schema::str("integer"))]),
+        "f32" | "f64" => Schema::Object(vec![("type", Schema::str("number"))]),
+        "char" => Schema::Object(vec![
+            ("type", Schema::str("string")),
+            ("minLength", Schema::Integer(1)),
+            ("maxLength", Schema::Integer(1)),
+        ]),
+        "alloc::string::String" | "alloc::borrow::Cow<str>" | "&str" => {
+            Schema::Object(vec![("type", Schema::str("string"))])
+        }
+        _ => return None,
+    };
+    Some(schema)
+}
+
+/// Returns the URI fragment pointing to the definition of the given type.
+fn definition_ref(type_path: &str) -> String {
+    let mut reference = String::from("#/$defs/");
+    for byte in type_path.bytes() {
+        match byte {
+            // JSON pointer escapes, see RFC 6901.
+            b'~' => reference.push_str("~0"),
+            b'/' => reference.push_str("~1"),
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b':' => {
+                reference.push(byte as char);
+            }
+            _ => write!(reference, "%{byte:02X}").unwrap(),
+        }
+    }
+    reference
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_reflect, serde::JsonSchemaSerializer, FromReflect, Reflect, TypePath,
+        TypeRegistry,
+    };
+    use serde_json::{json, Value};
+
+    #[derive(Reflect)]
+    struct Player {
+        name: String,
+        position: (f32, f32),
+        #[reflect(skip_serializing)]
+        cached: u32,
+        state: State,
+        target: Option<u32>,
+        inventory: Vec<Item>,
+    }
+
+    #[derive(Reflect)]
+    enum State {
+        Idle,
+        Walking(f32),
+        Attacking { target: u32 },
+    }
+
+    #[derive(Reflect, FromReflect)]
+    struct Item(u8);
+
+    fn schema() -> Value {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<State>();
+        registry.register::<Item>();
+        registry.register::<String>();
+        registry.register::<(f32, f32)>();
+        registry.register::<Option<u32>>();
+        registry.register::<Vec<Item>>();
+        serde_json::to_value(JsonSchemaSerializer::new(&registry)).unwrap()
+    }
+
+    fn definition<'a>(schema: &'a Value, type_path: &str) -> &'a Value {
+        &schema["$defs"][type_path]
+    }
+
+    #[test]
+    fn root_schema() {
+        let schema = schema();
+        let type_path = Player::type_path();
+        assert_eq!(schema["maxProperties"], 1);
+        assert_eq!(
+            schema["properties"][type_path]["$ref"],
+            "#/$defs/bevy_reflect::serde::json_schema::tests::Player"
+        );
+        assert_eq!(
+            schema["properties"]["(f32, f32)"]["$ref"],
+            "#/$defs/%28f32%2C%20f32%29"
+        );
+    }
+
+    #[test]
+    fn struct_schema() {
+        let schema = schema();
+        let player = definition(&schema, Player::type_path());
+        assert_eq!(player["type"], "object");
+        assert_eq!(
+            player["required"],
+            json!(["name", "position", "state", "target", "inventory"])
+        );
+        assert_eq!(player["properties"].get("cached"), None);
+        assert_eq!(
+            player["properties"]["name"]["$ref"],
+            "#/$defs/alloc::string::String"
+        );
+
+        let item = definition(&schema, Item::type_path());
+        assert_eq!(item["prefixItems"], json!([{"$ref": "#/$defs/u8"}]));
+        assert_eq!(item["maxItems"], 1);
+
+        let inventory = definition(&schema, <Vec<Item>>::type_path());
+        assert_eq!(inventory["type"], "array");
+    }
+
+    #[test]
+    fn enum_schema() {
+        let schema = schema();
+        let state = definition(&schema, State::type_path());
+        assert_eq!(state["oneOf"][0], json!({"const": "Idle"}));
+        assert_eq!(
+            state["oneOf"][1]["properties"]["Walking"],
+            json!({"$ref": "#/$defs/f32"})
+        );
+        assert_eq!(
+            state["oneOf"][2]["properties"]["Attacking"]["required"],
+            json!(["target"])
+        );
+
+        let option = definition(&schema, <Option<u32>>::type_path());
+        assert_eq!(
+            option["oneOf"],
+            json!([{"type": "null"}, {"$ref": "#/$defs/u32"}])
+        );
+        assert_eq!(
+            definition(&schema, "u32"),
+            &json!({"title": "u32", "type": "integer", "minimum": 0, "maximum": u32::MAX})
+        );
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn serialize_data_schema() {
+        use crate::ReflectSerialize;
+        use glam::{Quat, Vec3};
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<Vec3>();
+        registry.register::<Quat>();
+        let schema = serde_json::to_value(JsonSchemaSerializer::new(&registry)).unwrap();
+        assert_eq!(
+            definition(&schema, Vec3::type_path())["required"],
+            json!(["x", "y", "z"])
+        );
+
+        // `glam` serializes its vectors as arrays rather than structs.
+        registry.register_type_data::<Vec3, ReflectSerialize>();
+        let schema = serde_json::to_value(JsonSchemaSerializer::new(&registry)).unwrap();
+        assert_eq!(
+            definition(&schema, Vec3::type_path()),
+            &json!({"title": "Vec3"})
+        );
+        assert_eq!(
+            definition(&schema, Quat::type_path()),
+            &json!({"title": "Quat"})
+        );
+        assert_eq!(
+            serde_json::to_value(Vec3::new(1.0, 2.0, 3.0)).unwrap(),
+            json!([1.0, 2.0, 3.0])
+        );
+    }
+}
@@ -1,8 +1,14 @@
+mod compact;
 mod de;
+mod diff;
+mod json_schema;
 mod ser;
 mod type_data;
 
+pub use compact::*;
 pub use de::*;
+pub use diff::*;
+pub use json_schema::*;
 pub use ser::*;
 pub use type_data::*;
 
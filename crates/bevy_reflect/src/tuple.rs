@@ -1,7 +1,7 @@
 use crate::utility::NonGenericTypeInfoCell;
 use crate::{
     DynamicInfo, FromReflect, GetTypeRegistration, Reflect, ReflectMut, ReflectOwned, ReflectRef,
-    TypeInfo, TypeRegistration, Typed, UnnamedField,
+    TypeInfo, TypePath, TypeRegistration, Typed, UnnamedField,
 };
 use std::any::{Any, TypeId};
 use std::fmt::{Debug, Formatter};
@@ -450,6 +450,14 @@ pub fn tuple_debug(dyn_tuple: &dyn Tuple, f: &mut std::fmt::Formatter<'_>) -> st
     debug.finish()
 }
 
+/// Writes the path of a tuple type from the paths of its fields, like `(u32, f32)` or `(u32,)`.
+fn tuple_path(fields: &[&str]) -> String {
+    match fields {
+        [field] => format!("({field},)"),
+        fields => format!("({})", fields.join(", ")),
+    }
+}
+
 macro_rules! impl_reflect_tuple {
     {$($index:tt : $name:tt),*} => {
         impl<$($name: Reflect),*> Tuple for ($($name,)*) {
@@ -580,7 +588,19 @@ macro_rules! impl_reflect_tuple {
             }
         }
 
-        impl<$($name: Reflect + Typed),*> GetTypeRegistration for ($($name,)*) {
+        impl<$($name: TypePath),*> TypePath for ($($name,)*) {
+            fn type_path() -> &'static str {
+                static CELL: $crate::utility::GenericTypePathCell = $crate::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| tuple_path(&[$($name::type_path()),*]))
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: $crate::utility::GenericTypePathCell = $crate::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| tuple_path(&[$($name::short_type_path()),*]))
+            }
+        }
+
+        impl<$($name: Reflect + Typed + TypePath),*> GetTypeRegistration for ($($name,)*) {
             fn get_type_registration() -> TypeRegistration {
                 TypeRegistration::of::<($($name,)*)>()
             }
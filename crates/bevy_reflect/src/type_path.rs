@@ -0,0 +1,106 @@
+/// A static accessor to stable, crate-qualified type paths.
+///
+/// Unlike [`std::any::type_name`], whose output is explicitly not guaranteed to be stable
+/// across compiler versions, type paths are fully defined by this trait's implementations.
+/// This makes them suitable to identify types in saved data, and the [registry] and
+/// serializers use them for this purpose.
+///
+/// A type path is made of the path of the module defining the type, as returned by
+/// [`module_path!`], and of the [short type path] of the type. Generic parameters are
+/// written with their own type path, so `Vec<Foo>` becomes `alloc::vec::Vec<my_crate::Foo>`.
+///
+/// This trait is implemented by `#[derive(Reflect)]`, which requires type parameters to
+/// implement it too. Types that only need a type path, such as the parameters of a generic
+/// reflected type, can use `#[derive(TypePath)]` instead. The derive can be disabled with
+/// `#[reflect(type_path = false)]`, leaving a manual implementation to the type.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypePath};
+/// mod shapes {
+/// #   use bevy_reflect::Reflect;
+///     #[derive(Reflect)]
+///     pub struct Circle<T: Reflect> {
+///         pub radius: T,
+///     }
+/// }
+///
+/// # let module = module_path!();
+/// assert_eq!(
+///     <shapes::Circle<f32>>::type_path(),
+///     format!("{module}::shapes::Circle<f32>"),
+/// );
+/// assert_eq!(<shapes::Circle<f32>>::short_type_path(), "Circle<f32>");
+/// ```
+///
+/// [registry]: crate::TypeRegistry
+/// [short type path]: TypePath::short_type_path
+pub trait TypePath: 'static {
+    /// Returns the fully qualified path of this type, like `my_crate::foo::Bar<u32>`.
+    fn type_path() -> &'static str;
+
+    /// Returns the path of this type without its module, like `Bar<u32>`.
+    ///
+    /// Generic parameters are also written with their short type path.
+    fn short_type_path() -> &'static str;
+}
+
+/// Implements [`TypePath`] for a foreign type, given the module it is defined in.
+///
+/// ```ignore
+/// impl_type_path!(u32);
+/// impl_type_path!(String in "alloc::string");
+/// impl_type_path!(Vec<T> in "alloc::vec");
+/// ```
+macro_rules! impl_type_path {
+    ($name:ident) => {
+        impl $crate::TypePath for $name {
+            fn type_path() -> &'static str {
+                stringify!($name)
+            }
+
+            fn short_type_path() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+    ($name:ident in $module:literal) => {
+        impl $crate::TypePath for $name {
+            fn type_path() -> &'static str {
+                concat!($module, "::", stringify!($name))
+            }
+
+            fn short_type_path() -> &'static str {
+                stringify!($name)
+            }
+        }
+    };
+    ($name:ident<$($param:ident),+> in $module:literal) => {
+        impl<$($param: $crate::TypePath),+> $crate::TypePath for $name<$($param),+> {
+            fn type_path() -> &'static str {
+                static CELL: $crate::utility::GenericTypePathCell =
+                    $crate::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    format!(
+                        concat!($module, "::", stringify!($name), "<{}>"),
+                        [$(<$param as $crate::TypePath>::type_path()),+].join(", ")
+                    )
+                })
+            }
+
+            fn short_type_path() -> &'static str {
+                static CELL: $crate::utility::GenericTypePathCell =
+                    $crate::utility::GenericTypePathCell::new();
+                CELL.get_or_insert::<Self, _>(|| {
+                    format!(
+                        concat!(stringify!($name), "<{}>"),
+                        [$(<$param as $crate::TypePath>::short_type_path()),+].join(", ")
+                    )
+                })
+            }
+        }
+    };
+}
+
+pub(crate) use impl_type_path;
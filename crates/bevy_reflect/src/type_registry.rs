@@ -1,6 +1,8 @@
-use crate::{serde::Serializable, Reflect, TypeInfo, Typed};
+use crate::{
+    serde::Serializable, Reflect, ReflectFunction, ReflectMethods, TypeInfo, TypePath, Typed,
+};
 use bevy_ptr::{Ptr, PtrMut};
-use bevy_utils::{HashMap, HashSet};
+use bevy_utils::{tracing::warn, HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use parking_lot::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 use serde::Deserialize;
@@ -12,6 +14,10 @@ pub struct TypeRegistry {
     short_name_to_id: HashMap<String, TypeId>,
     full_name_to_id: HashMap<String, TypeId>,
     ambiguous_names: HashSet<String>,
+    type_path_to_id: HashMap<&'static str, TypeId>,
+    ambiguous_type_paths: HashSet<&'static str>,
+    short_type_path_to_id: HashMap<&'static str, TypeId>,
+    ambiguous_short_type_paths: HashSet<&'static str>,
 }
 
 // TODO:  remove this wrapper once we migrate to Atelier Assets and the Scene AssetLoader doesn't
@@ -49,6 +55,10 @@ impl TypeRegistry {
             short_name_to_id: Default::default(),
             full_name_to_id: Default::default(),
             ambiguous_names: Default::default(),
+            type_path_to_id: Default::default(),
+            ambiguous_type_paths: Default::default(),
+            short_type_path_to_id: Default::default(),
+            ambiguous_short_type_paths: Default::default(),
         }
     }
 
@@ -102,8 +112,38 @@ impl TypeRegistry {
             self.short_name_to_id
                 .insert(short_name, registration.type_id());
         }
+        let short_type_path = registration.short_type_path();
+        if self.short_type_path_to_id.contains_key(short_type_path)
+            || self.ambiguous_short_type_paths.contains(short_type_path)
+        {
+            self.short_type_path_to_id.remove(short_type_path);
+            self.ambiguous_short_type_paths.insert(short_type_path);
+        } else {
+            self.short_type_path_to_id
+                .insert(short_type_path, registration.type_id());
+        }
         self.full_name_to_id
             .insert(registration.type_name().to_string(), registration.type_id());
+        let type_path = registration.type_path();
+        if let Some(other) = self.type_path_to_id.remove(type_path) {
+            // Two distinct types claim the same type path, so neither can be looked up by it.
+            warn!(
+                "types `{}` and `{}` have the same type path `{}`, which is now ambiguous",
+                self.registrations[&other].type_name(),
+                registration.type_name(),
+                type_path,
+            );
+            self.ambiguous_type_paths.insert(type_path);
+        } else if self.ambiguous_type_paths.contains(type_path) {
+            warn!(
+                "type `{}` has the ambiguous type path `{}`",
+                registration.type_name(),
+                type_path,
+            );
+        } else {
+            self.type_path_to_id
+                .insert(type_path, registration.type_id());
+        }
         self.registrations
             .insert(registration.type_id(), registration);
     }
@@ -176,6 +216,56 @@ impl TypeRegistry {
             .and_then(move |id| self.get_mut(id))
     }
 
+    /// Returns a reference to the [`TypeRegistration`] of the type with the
+    /// given [type path].
+    ///
+    /// If the type path is ambiguous, because several registered types share
+    /// it, or if no type with the given type path has been registered, returns
+    /// `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_with_type_path(&self, type_path: &str) -> Option<&TypeRegistration> {
+        self.type_path_to_id
+            .get(type_path)
+            .and_then(|id| self.get(*id))
+    }
+
+    /// Returns a mutable reference to the [`TypeRegistration`] of the type with
+    /// the given [type path].
+    ///
+    /// If the type path is ambiguous, or if no type with the given type path
+    /// has been registered, returns `None`.
+    ///
+    /// [type path]: TypePath::type_path
+    pub fn get_with_type_path_mut(&mut self, type_path: &str) -> Option<&mut TypeRegistration> {
+        self.type_path_to_id
+            .get(type_path)
+            .cloned()
+            .and_then(move |id| self.get_mut(id))
+    }
+
+    /// Returns a reference to the [`TypeRegistration`] of the type with
+    /// the given [short type path].
+    ///
+    /// If the short type path is ambiguous, or if no type with the given short
+    /// type path has been registered, returns `None`.
+    ///
+    /// [short type path]: TypePath::short_type_path
+    pub fn get_with_short_type_path(&self, short_type_path: &str) -> Option<&TypeRegistration> {
+        self.short_type_path_to_id
+            .get(short_type_path)
+            .and_then(|id| self.registrations.get(id))
+    }
+
+    /// Returns the [`TypeRegistration`] of the type of `value`.
+    ///
+    /// For dynamic types, such as [`DynamicStruct`](crate::DynamicStruct), this is
+    /// the registration of the type they represent.
+    pub fn get_with_value(&self, value: &dyn Reflect) -> Option<&TypeRegistration> {
+        self.get(value.type_id())
+            .or_else(|| self.get_with_name(value.type_name()))
+    }
+
     /// Returns a reference to the [`TypeRegistration`] of the type with
     /// the given short name.
     ///
@@ -259,7 +349,7 @@ impl TypeRegistryArc {
 
 /// A record of data about a type.
 ///
-/// This contains the [`TypeInfo`] of the type, as well as its [short name].
+/// This contains the [`TypeInfo`] of the type, as well as its [short name] and [type path].
 ///
 /// For each trait specified by the [`#[reflect(_)]`][0] attribute of
 /// [`#[derive(Reflect)]`][1] on the registered type, this record also contains
@@ -267,11 +357,14 @@ impl TypeRegistryArc {
 /// this type to trait objects of the relevant trait.
 ///
 /// [short name]: bevy_utils::get_short_name
+/// [type path]: TypePath
 /// [`TypeInfo`]: crate::TypeInfo
 /// [0]: crate::Reflect
 /// [1]: crate::Reflect
 pub struct TypeRegistration {
     short_name: String,
+    type_path: &'static str,
+    short_type_path: &'static str,
     data: HashMap<TypeId, Box<dyn TypeData>>,
     type_info: &'static TypeInfo,
 }
@@ -280,6 +373,7 @@ impl Debug for TypeRegistration {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("TypeRegistration")
             .field("short_name", &self.short_name)
+            .field("type_path", &self.type_path)
             .field("type_info", &self.type_info)
             .finish()
     }
@@ -326,12 +420,29 @@ impl TypeRegistration {
         self.data.insert(TypeId::of::<T>(), Box::new(data));
     }
 
+    /// Adds a reflected method to this registration's [`ReflectMethods`] type data,
+    /// inserting it if needed.
+    ///
+    /// If a method with the same name was previously added, it is replaced.
+    pub fn add_method(&mut self, method: ReflectFunction) {
+        match self.data_mut::<ReflectMethods>() {
+            Some(methods) => methods.insert(method),
+            None => {
+                let mut methods = ReflectMethods::default();
+                methods.insert(method);
+                self.insert(methods);
+            }
+        }
+    }
+
     /// Creates type registration information for `T`.
-    pub fn of<T: Reflect + Typed>() -> Self {
+    pub fn of<T: Reflect + Typed + TypePath>() -> Self {
         let type_name = std::any::type_name::<T>();
         Self {
             data: HashMap::default(),
             short_name: bevy_utils::get_short_name(type_name),
+            type_path: T::type_path(),
+            short_type_path: T::short_type_path(),
             type_info: T::type_info(),
         }
     }
@@ -349,6 +460,24 @@ impl TypeRegistration {
     pub fn type_name(&self) -> &'static str {
         self.type_info.type_name()
     }
+
+    /// Returns the [type path] of the type.
+    ///
+    /// Unlike the [type name], it is stable across compiler versions, and
+    /// identifies the type in serialized data.
+    ///
+    /// [type path]: TypePath::type_path
+    /// [type name]: Self::type_name
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+
+    /// Returns the [short type path] of the type.
+    ///
+    /// [short type path]: TypePath::short_type_path
+    pub fn short_type_path(&self) -> &'static str {
+        self.short_type_path
+    }
 }
 
 impl Clone for TypeRegistration {
@@ -361,6 +490,8 @@ impl Clone for TypeRegistration {
         TypeRegistration {
             data,
             short_name: self.short_name.clone(),
+            type_path: self.type_path,
+            short_type_path: self.short_type_path,
             type_info: self.type_info,
         }
     }
@@ -536,7 +667,7 @@ impl<T: Reflect> FromType<T> for ReflectFromPtr {
 mod test {
     use std::ptr::NonNull;
 
-    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration};
+    use crate::{GetTypeRegistration, ReflectFromPtr, TypeRegistration, TypeRegistry};
     use bevy_ptr::{Ptr, PtrMut};
     use bevy_utils::HashMap;
 
@@ -619,4 +750,99 @@ mod test {
             "Option<HashMap<Option<String>, (String, Option<String>)>>"
         );
     }
+
+    #[test]
+    fn test_type_path_registration() {
+        mod a {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            pub struct Foo(pub u32);
+        }
+        mod b {
+            use crate as bevy_reflect;
+            use crate::Reflect;
+
+            #[derive(Reflect)]
+            pub struct Foo(pub Option<Vec<String>>);
+        }
+
+        let registration = TypeRegistration::of::<Option<HashMap<u32, (String, [f32; 2])>>>();
+        assert_eq!(
+            registration.type_path(),
+            "core::option::Option<bevy_utils::HashMap<u32, (alloc::string::String, [f32; 2])>>"
+        );
+        assert_eq!(
+            registration.short_type_path(),
+            "Option<HashMap<u32, (String, [f32; 2])>>"
+        );
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<a::Foo>();
+        registry.register::<b::Foo>();
+
+        let a_path = concat!(module_path!(), "::a::Foo");
+        assert_eq!(
+            registry.get_with_type_path(a_path).unwrap().type_id(),
+            std::any::TypeId::of::<a::Foo>()
+        );
+        assert_eq!(
+            registry.get_with_value(&b::Foo(None)).unwrap().type_path(),
+            concat!(module_path!(), "::b::Foo")
+        );
+        assert!(registry.get_with_short_type_path("Foo").is_none());
+        assert!(registry.get_with_short_type_path("u32").is_some());
+    }
+
+    #[test]
+    fn test_ambiguous_type_path() {
+        mod a {
+            use crate as bevy_reflect;
+            use crate::{Reflect, TypePath};
+
+            #[derive(Reflect)]
+            #[reflect(type_path = false)]
+            pub struct Foo;
+
+            impl TypePath for Foo {
+                fn type_path() -> &'static str {
+                    "foo::Foo"
+                }
+
+                fn short_type_path() -> &'static str {
+                    "Foo"
+                }
+            }
+        }
+        mod b {
+            use crate as bevy_reflect;
+            use crate::{Reflect, TypePath};
+
+            #[derive(Reflect)]
+            #[reflect(type_path = false)]
+            pub struct Foo;
+
+            impl TypePath for Foo {
+                fn type_path() -> &'static str {
+                    "foo::Foo"
+                }
+
+                fn short_type_path() -> &'static str {
+                    "Foo"
+                }
+            }
+        }
+
+        let mut registry = TypeRegistry::default();
+        registry.register::<a::Foo>();
+        assert!(registry.get_with_type_path("foo::Foo").is_some());
+
+        registry.register::<b::Foo>();
+        assert!(registry.get_with_type_path("foo::Foo").is_none());
+        assert!(registry.get_with_type_path_mut("foo::Foo").is_none());
+        // Both types are still registered.
+        assert!(registry.get(std::any::TypeId::of::<a::Foo>()).is_some());
+        assert!(registry.get(std::any::TypeId::of::<b::Foo>()).is_some());
+    }
 }
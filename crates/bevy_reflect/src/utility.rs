@@ -147,3 +147,64 @@ impl GenericTypeInfoCell {
         })
     }
 }
+
+/// A container for the [type paths] of generic types, allowing them to be stored statically.
+///
+/// Non-generic types can return a `&'static str` built with [`concat!`] directly.
+///
+/// ## Example
+///
+/// ```
+/// use bevy_reflect::TypePath;
+/// use bevy_reflect::utility::GenericTypePathCell;
+///
+/// struct Foo<T>(T);
+///
+/// impl<T: TypePath> TypePath for Foo<T> {
+///   fn type_path() -> &'static str {
+///     static CELL: GenericTypePathCell = GenericTypePathCell::new();
+///     CELL.get_or_insert::<Self, _>(|| format!("my_crate::Foo<{}>", T::type_path()))
+///   }
+///
+///   fn short_type_path() -> &'static str {
+///     static CELL: GenericTypePathCell = GenericTypePathCell::new();
+///     CELL.get_or_insert::<Self, _>(|| format!("Foo<{}>", T::short_type_path()))
+///   }
+/// }
+///
+/// assert_eq!(Foo::<u32>::type_path(), "my_crate::Foo<u32>");
+/// ```
+///
+/// [type paths]: crate::TypePath
+pub struct GenericTypePathCell(OnceBox<RwLock<HashMap<TypeId, &'static str>>>);
+
+impl GenericTypePathCell {
+    /// Initialize a [`GenericTypePathCell`] for generic types.
+    pub const fn new() -> Self {
+        Self(OnceBox::new())
+    }
+
+    /// Returns the type path stored in the cell for the given type `T`.
+    ///
+    /// If there is no type path found, a new one will be generated from the given function.
+    pub fn get_or_insert<T, F>(&self, f: F) -> &str
+    where
+        T: Any + ?Sized,
+        F: FnOnce() -> String,
+    {
+        let type_id = TypeId::of::<T>();
+        let mapping = self.0.get_or_init(Box::default);
+        if let Some(path) = mapping.read().get(&type_id) {
+            return path;
+        }
+
+        // The path is built before taking the lock, since it usually contains the paths of
+        // type parameters, which may be stored in this same cell.
+        let path = f();
+        mapping
+            .write()
+            .entry(type_id)
+            // Leaked for the same reason as in `GenericTypeInfoCell::get_or_insert`.
+            .or_insert_with(|| Box::leak(path.into_boxed_str()))
+    }
+}
@@ -0,0 +1,407 @@
+//! Inspection and editing of a running app by external tools, such as editors.
+//!
+//! The [`RemotePlugin`] opens a local TCP socket speaking [JSON-RPC 2.0], with one message
+//! per line. Components are identified by their [type path] (or by their short type path when
+//! it is unambiguous), their values are written with the reflection [serializers], and
+//! entities are identified by the bits of [`Entity::to_bits`](bevy_ecs::entity::Entity::to_bits).
+//! Only components registered with `#[reflect(Component)]` are visible.
+//!
+//! | Method              | Params                                         | Result                             |
+//! |---------------------|------------------------------------------------|------------------------------------|
+//! | `list_entities`     |                                                | `[entity]`                         |
+//! | `list_components`   | `{ entity }`                                   | `[type path]`                      |
+//! | `get_components`    | `{ entity, components: [type path] }`          | `{ type path: value }`             |
+//! | `set_components`    | `{ entity, components: { type path: value } }` | `null`                             |
+//! | `insert_components` | `{ entity, components: { type path: value } }` | `null`                             |
+//! | `remove_components` | `{ entity, components: [type path] }`          | `null`                             |
+//! | `query`             | `{ components, with?, without? }`              | `[{ entity, components: { .. } }]` |
+//! | `spawn_scene`       | `{ path }`                                     | `{ entities: [entity] }`           |
+//!
+//! `set_components` only applies values to components the entity already has, while
+//! `insert_components` adds or replaces them. `query` returns the entities that have all of
+//! `components` and `with`, and none of `without`. `spawn_scene` loads a [`DynamicScene`]
+//! through the [`AssetServer`] and answers once its entities are spawned.
+//!
+//! ```text
+//! --> {"jsonrpc": "2.0", "id": 1, "method": "get_components", "params": {"entity": 0, "components": ["Health"]}}
+//! <-- {"jsonrpc":"2.0","id":1,"result":{"my_game::Health":{"current":10.0}}}
+//! ```
+//!
+//! Requests are answered at the end of each frame. The socket gives full access to the world,
+//! so it should not be exposed outside of the local machine.
+//!
+//! [JSON-RPC 2.0]: https://www.jsonrpc.org/specification
+//! [type path]: bevy_reflect::TypePath
+//! [serializers]: bevy_reflect::serde
+//! [`DynamicScene`]: bevy_scene::DynamicScene
+//! [`AssetServer`]: bevy_asset::AssetServer
+
+mod methods;
+mod server;
+
+pub use methods::*;
+pub use server::*;
+
+use bevy_app::{App, CoreStage, Plugin};
+use bevy_utils::tracing::error;
+use std::net::{Ipv4Addr, SocketAddr};
+
+/// The port the [`RemotePlugin`] listens on by default.
+pub const DEFAULT_PORT: u16 = 15702;
+
+/// Opens a [`RemoteServer`] and answers its requests against the world.
+///
+/// This plugin isn't part of the default plugins. Spawning scenes also requires the
+/// `ScenePlugin`.
+pub struct RemotePlugin {
+    /// The address to listen on, `127.0.0.1:15702` by default.
+    pub address: SocketAddr,
+}
+
+impl Default for RemotePlugin {
+    fn default() -> Self {
+        Self {
+            address: SocketAddr::from((Ipv4Addr::LOCALHOST, DEFAULT_PORT)),
+        }
+    }
+}
+
+impl Plugin for RemotePlugin {
+    fn build(&self, app: &mut App) {
+        match RemoteServer::bind(self.address) {
+            Ok(server) => {
+                app.insert_resource(server)
+                    .init_resource::<PendingScenes>()
+                    .add_system_to_stage(CoreStage::Last, process_remote_requests);
+            }
+            Err(err) => error!(
+                "Failed to open the remote socket on {}. {}",
+                self.address, err
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RemotePlugin, RemoteServer};
+    use bevy_app::App;
+    use bevy_asset::{AssetPlugin, AssetServer, FileAssetIo};
+    use bevy_ecs::{prelude::*, reflect::ReflectComponent};
+    use bevy_reflect::Reflect;
+    use bevy_scene::ScenePlugin;
+    use bevy_tasks::IoTaskPool;
+    use serde_json::{json, Value};
+    use std::{
+        io::{BufRead, BufReader, ErrorKind, Write},
+        net::{SocketAddr, TcpStream},
+        time::Duration,
+    };
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: f32,
+        max: f32,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Name(String);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Enemy;
+
+    /// Stands in for an external tool, updating the app while waiting for each response.
+    struct Client {
+        reader: BufReader<TcpStream>,
+        next_id: u64,
+    }
+
+    impl Client {
+        fn connect(app: &App) -> Self {
+            let address = app.world.resource::<RemoteServer>().local_addr();
+            let stream = TcpStream::connect(address).unwrap();
+            stream
+                .set_read_timeout(Some(Duration::from_millis(5)))
+                .unwrap();
+            Self {
+                reader: BufReader::new(stream),
+                next_id: 0,
+            }
+        }
+
+        fn send(&mut self, app: &mut App, message: &str) -> Value {
+            let stream = self.reader.get_mut();
+            stream.write_all(message.as_bytes()).unwrap();
+            stream.write_all(b"\n").unwrap();
+
+            let mut line = String::new();
+            for _ in 0..1000 {
+                app.update();
+                match self.reader.read_line(&mut line) {
+                    Ok(_) if line.ends_with('\n') => return serde_json::from_str(&line).unwrap(),
+                    Ok(_) => {}
+                    Err(err)
+                        if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                    Err(err) => panic!("{}", err),
+                }
+            }
+            panic!("no response to {}", message);
+        }
+
+        fn call(&mut self, app: &mut App, method: &str, params: Value) -> Result<Value, Value> {
+            self.next_id += 1;
+            let request = json!({
+                "jsonrpc": "2.0",
+                "id": self.next_id,
+                "method": method,
+                "params": params,
+            });
+            let mut response = self.send(app, &request.to_string());
+            assert_eq!(response["id"], self.next_id);
+            match response.get_mut("result") {
+                Some(result) => Ok(result.take()),
+                None => Err(response["error"].take()),
+            }
+        }
+    }
+
+    fn remote_app() -> App {
+        let mut app = App::new();
+        app.add_plugin(RemotePlugin {
+            address: SocketAddr::from(([127, 0, 0, 1], 0)),
+        })
+        // Registered by the `CorePlugin` in a full app.
+        .register_type::<f32>()
+        .register_type::<String>()
+        .register_type::<Health>()
+        .register_type::<Name>()
+        .register_type::<Enemy>();
+        app
+    }
+
+    #[test]
+    fn get_set_insert_remove() {
+        let mut app = remote_app();
+        let entity = app
+            .world
+            .spawn((
+                Health {
+                    current: 5.0,
+                    max: 10.0,
+                },
+                Enemy,
+            ))
+            .id();
+        let id = entity.to_bits();
+        let mut client = Client::connect(&app);
+
+        assert_eq!(
+            client.call(&mut app, "list_entities", Value::Null),
+            Ok(json!([id]))
+        );
+        assert_eq!(
+            client.call(&mut app, "list_components", json!({ "entity": id })),
+            Ok(json!([
+                "bevy_remote::tests::Enemy",
+                "bevy_remote::tests::Health"
+            ]))
+        );
+        assert_eq!(
+            client.call(
+                &mut app,
+                "get_components",
+                json!({ "entity": id, "components": ["Health"] })
+            ),
+            Ok(json!({ "bevy_remote::tests::Health": { "current": 5.0, "max": 10.0 } }))
+        );
+
+        client
+            .call(
+                &mut app,
+                "set_components",
+                json!({ "entity": id, "components": { "Health": { "current": 7.0, "max": 10.0 } } }),
+            )
+            .unwrap();
+        assert_eq!(app.world.get::<Health>(entity).unwrap().current, 7.0);
+
+        let error = client
+            .call(
+                &mut app,
+                "set_components",
+                json!({ "entity": id, "components": { "Name": ["Goblin"] } }),
+            )
+            .unwrap_err();
+        assert_eq!(error["code"], -32003);
+        assert!(app.world.get::<Name>(entity).is_none());
+
+        client
+            .call(
+                &mut app,
+                "insert_components",
+                json!({ "entity": id, "components": { "bevy_remote::tests::Name": ["Goblin"] } }),
+            )
+            .unwrap();
+        assert_eq!(
+            app.world.get::<Name>(entity),
+            Some(&Name("Goblin".to_string()))
+        );
+
+        client
+            .call(
+                &mut app,
+                "remove_components",
+                json!({ "entity": id, "components": ["Enemy"] }),
+            )
+            .unwrap();
+        assert!(app.world.get::<Enemy>(entity).is_none());
+    }
+
+    #[test]
+    fn query_components() {
+        let mut app = remote_app();
+        let goblin = app
+            .world
+            .spawn((
+                Health {
+                    current: 5.0,
+                    max: 10.0,
+                },
+                Name("Goblin".to_string()),
+                Enemy,
+            ))
+            .id();
+        let hero = app
+            .world
+            .spawn((
+                Health {
+                    current: 20.0,
+                    max: 20.0,
+                },
+                Name("Hero".to_string()),
+            ))
+            .id();
+        app.world.spawn(Enemy);
+        let mut client = Client::connect(&app);
+
+        assert_eq!(
+            client.call(
+                &mut app,
+                "query",
+                json!({ "components": ["Name"], "with": ["Health"], "without": ["Enemy"] })
+            ),
+            Ok(json!([{
+                "entity": hero.to_bits(),
+                "components": { "bevy_remote::tests::Name": ["Hero"] },
+            }]))
+        );
+        assert_eq!(
+            client.call(
+                &mut app,
+                "query",
+                json!({ "components": ["Health"], "with": ["Enemy"] })
+            ),
+            Ok(json!([{
+                "entity": goblin.to_bits(),
+                "components": { "bevy_remote::tests::Health": { "current": 5.0, "max": 10.0 } },
+            }]))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let mut app = remote_app();
+        let mut client = Client::connect(&app);
+
+        let response = client.send(&mut app, "{ not json");
+        assert_eq!(response["error"]["code"], -32700);
+        let response = client.send(
+            &mut app,
+            r#"{"jsonrpc": "1.0", "id": 3, "method": "query"}"#,
+        );
+        assert_eq!(response["id"], 3);
+        assert_eq!(response["error"]["code"], -32600);
+
+        let error = client.call(&mut app, "despawn_world", Value::Null);
+        assert_eq!(error.unwrap_err()["code"], -32601);
+        let error = client.call(&mut app, "get_components", json!({ "entity": "zero" }));
+        assert_eq!(error.unwrap_err()["code"], -32602);
+        let error = client.call(
+            &mut app,
+            "get_components",
+            json!({ "entity": 42, "components": [] }),
+        );
+        assert_eq!(error.unwrap_err()["code"], -32001);
+
+        let id = app.world.spawn_empty().id().to_bits();
+        let error = client.call(
+            &mut app,
+            "insert_components",
+            json!({ "entity": id, "components": { "Unknown": 1 } }),
+        );
+        assert_eq!(error.unwrap_err()["code"], -32002);
+        let error = client.call(
+            &mut app,
+            "insert_components",
+            json!({ "entity": id, "components": { "Health": { "current": "full" } } }),
+        );
+        assert_eq!(error.unwrap_err()["code"], -32004);
+    }
+
+    #[test]
+    fn spawn_scene_file() {
+        let asset_dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            asset_dir.path().join("goblins.scn.ron"),
+            r#"(
+                entities: {
+                    0: (
+                        components: {
+                            "bevy_remote::tests::Name": ("Goblin"),
+                        },
+                    ),
+                    1: (
+                        components: {
+                            "bevy_remote::tests::Name": ("Goblin chief"),
+                            "bevy_remote::tests::Enemy": (),
+                        },
+                    ),
+                },
+            )"#,
+        )
+        .unwrap();
+
+        IoTaskPool::init(Default::default);
+        let mut app = remote_app();
+        app.insert_resource(AssetServer::new(FileAssetIo::new(asset_dir.path(), false)))
+            .add_plugin(AssetPlugin::default())
+            .add_plugin(ScenePlugin);
+        let mut client = Client::connect(&app);
+
+        let result = client
+            .call(
+                &mut app,
+                "spawn_scene",
+                json!({ "path": "goblins.scn.ron" }),
+            )
+            .unwrap();
+        let entities = result["entities"].as_array().unwrap();
+        assert_eq!(entities.len(), 2);
+        let mut names = app.world.query::<&Name>();
+        let mut names = names
+            .iter(&app.world)
+            .map(|name| name.0.as_str())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, ["Goblin", "Goblin chief"]);
+
+        let error = client.call(
+            &mut app,
+            "spawn_scene",
+            json!({ "path": "missing.scn.ron" }),
+        );
+        assert_eq!(error.unwrap_err()["code"], -32005);
+    }
+}
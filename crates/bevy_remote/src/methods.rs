@@ -0,0 +1,403 @@
+use crate::RemoteServer;
+use bevy_app::AppTypeRegistry;
+use bevy_asset::{AssetServer, Handle, LoadState};
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    reflect::ReflectComponent,
+    system::Resource,
+    world::{Mut, World},
+};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    Reflect, TypeRegistration, TypeRegistry,
+};
+use bevy_scene::{DynamicScene, InstanceId, SceneSpawner};
+use crossbeam_channel::Sender;
+use serde::{de::DeserializeOwned, de::DeserializeSeed, Deserialize};
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+
+/// An error answering a remote request, sent to the client as a JSON-RPC error object.
+#[derive(Error, Debug)]
+pub enum RemoteError {
+    #[error("parse error: {0}")]
+    Parse(serde_json::Error),
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("method `{0}` not found")]
+    MethodNotFound(String),
+    #[error("invalid params: {0}")]
+    InvalidParams(serde_json::Error),
+    #[error("entity {0} does not exist")]
+    NoSuchEntity(u64),
+    #[error(
+        "`{0}` is not a registered component. consider adding `#[reflect(Component)]` to your type"
+    )]
+    UnregisteredComponent(String),
+    #[error("entity {entity} does not have the component `{type_path}`")]
+    MissingComponent { entity: u64, type_path: String },
+    #[error("invalid value for `{type_path}`: {error}")]
+    InvalidValue {
+        type_path: String,
+        error: serde_json::Error,
+    },
+    #[error("failed to serialize `{type_path}`: {error}")]
+    Serialization {
+        type_path: String,
+        error: serde_json::Error,
+    },
+    #[error("spawning scenes requires the `ScenePlugin`")]
+    ScenesUnavailable,
+    #[error("failed to load the scene `{0}`")]
+    SceneLoad(String),
+}
+
+impl RemoteError {
+    /// Returns the JSON-RPC error code of this error.
+    ///
+    /// Protocol errors use the codes reserved by the JSON-RPC specification, and errors
+    /// raised by the methods use codes from -32001 downwards.
+    pub fn code(&self) -> i64 {
+        match self {
+            RemoteError::Parse(_) => -32700,
+            RemoteError::InvalidRequest(_) => -32600,
+            RemoteError::MethodNotFound(_) => -32601,
+            RemoteError::InvalidParams(_) => -32602,
+            RemoteError::Serialization { .. } => -32603,
+            RemoteError::NoSuchEntity(_) => -32001,
+            RemoteError::UnregisteredComponent(_) => -32002,
+            RemoteError::MissingComponent { .. } => -32003,
+            RemoteError::InvalidValue { .. } => -32004,
+            RemoteError::ScenesUnavailable | RemoteError::SceneLoad(_) => -32005,
+        }
+    }
+}
+
+/// Scenes spawned by `spawn_scene`, answered once their instance is ready.
+#[derive(Resource, Default)]
+pub(crate) struct PendingScenes(Vec<PendingScene>);
+
+struct PendingScene {
+    path: String,
+    handle: Handle<DynamicScene>,
+    instance: InstanceId,
+    respond: Sender<Result<Value, RemoteError>>,
+}
+
+#[derive(Deserialize)]
+struct EntityParams {
+    entity: u64,
+}
+
+#[derive(Deserialize)]
+struct ComponentsParams {
+    entity: u64,
+    components: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ComponentValuesParams {
+    entity: u64,
+    components: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct QueryParams {
+    components: Vec<String>,
+    #[serde(default)]
+    with: Vec<String>,
+    #[serde(default)]
+    without: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct SpawnSceneParams {
+    path: String,
+}
+
+/// Answers the requests received by the [`RemoteServer`].
+pub fn process_remote_requests(world: &mut World) {
+    let requests = world
+        .resource::<RemoteServer>()
+        .receive()
+        .collect::<Vec<_>>();
+    for request in requests {
+        let params = request.params;
+        let result = match request.method.as_str() {
+            "list_entities" => Ok(list_entities(world)),
+            "list_components" => parse(params).and_then(|params| list_components(world, params)),
+            "get_components" => parse(params).and_then(|params| get_components(world, params)),
+            "set_components" => parse(params).and_then(|params| set_components(world, params)),
+            "insert_components" => {
+                parse(params).and_then(|params| insert_components(world, params))
+            }
+            "remove_components" => {
+                parse(params).and_then(|params| remove_components(world, params))
+            }
+            "query" => parse(params).and_then(|params| query(world, params)),
+            "spawn_scene" => match parse(params).and_then(|params| spawn_scene(world, params)) {
+                Ok((path, handle, instance)) => {
+                    world.resource_mut::<PendingScenes>().0.push(PendingScene {
+                        path,
+                        handle,
+                        instance,
+                        respond: request.respond,
+                    });
+                    continue;
+                }
+                Err(err) => Err(err),
+            },
+            _ => Err(RemoteError::MethodNotFound(request.method)),
+        };
+        // The client may have disconnected in the meantime.
+        let _ = request.respond.send(result);
+    }
+
+    answer_spawned_scenes(world);
+}
+
+fn parse<T: DeserializeOwned>(params: Value) -> Result<T, RemoteError> {
+    serde_json::from_value(params).map_err(RemoteError::InvalidParams)
+}
+
+fn list_entities(world: &World) -> Value {
+    let mut entities = world
+        .iter_entities()
+        .map(Entity::to_bits)
+        .collect::<Vec<_>>();
+    entities.sort_unstable();
+    json!(entities)
+}
+
+fn list_components(world: &World, params: EntityParams) -> Result<Value, RemoteError> {
+    let entity = get_entity(world, params.entity)?;
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut type_paths = world
+        .entity(entity)
+        .archetype()
+        .components()
+        .filter_map(|component_id| world.components().get_info(component_id)?.type_id())
+        .filter_map(|type_id| registry.get(type_id))
+        .filter(|registration| registration.data::<ReflectComponent>().is_some())
+        .map(TypeRegistration::type_path)
+        .collect::<Vec<_>>();
+    type_paths.sort_unstable();
+    Ok(json!(type_paths))
+}
+
+fn get_components(world: &World, params: ComponentsParams) -> Result<Value, RemoteError> {
+    let entity = get_entity(world, params.entity)?;
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut values = Map::new();
+    for type_path in &params.components {
+        let (registration, reflect_component) = get_component(&registry, type_path)?;
+        let component = reflect_component.reflect(world, entity).ok_or_else(|| {
+            RemoteError::MissingComponent {
+                entity: params.entity,
+                type_path: type_path.clone(),
+            }
+        })?;
+        values.insert(
+            registration.type_path().to_string(),
+            serialize_component(&registry, registration, component)?,
+        );
+    }
+    Ok(Value::Object(values))
+}
+
+fn set_components(world: &mut World, params: ComponentValuesParams) -> Result<Value, RemoteError> {
+    let entity = get_entity(world, params.entity)?;
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let components = deserialize_components(&registry, params.components)?;
+    for (registration, reflect_component, _) in &components {
+        if reflect_component.reflect(world, entity).is_none() {
+            return Err(RemoteError::MissingComponent {
+                entity: params.entity,
+                type_path: registration.type_path().to_string(),
+            });
+        }
+    }
+    for (_, reflect_component, component) in &components {
+        reflect_component.apply(world, entity, &**component);
+    }
+    Ok(Value::Null)
+}
+
+fn insert_components(
+    world: &mut World,
+    params: ComponentValuesParams,
+) -> Result<Value, RemoteError> {
+    let entity = get_entity(world, params.entity)?;
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    for (_, reflect_component, component) in deserialize_components(&registry, params.components)? {
+        reflect_component.insert(world, entity, &*component);
+    }
+    Ok(Value::Null)
+}
+
+fn remove_components(world: &mut World, params: ComponentsParams) -> Result<Value, RemoteError> {
+    let entity = get_entity(world, params.entity)?;
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    let components = params
+        .components
+        .iter()
+        .map(|type_path| get_component(&registry, type_path).map(|(_, component)| component))
+        .collect::<Result<Vec<_>, _>>()?;
+    for reflect_component in components {
+        reflect_component.remove(world, entity);
+    }
+    Ok(Value::Null)
+}
+
+fn query(world: &World, params: QueryParams) -> Result<Value, RemoteError> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let fetched = params
+        .components
+        .iter()
+        .map(|type_path| get_component(&registry, type_path))
+        .collect::<Result<Vec<_>, _>>()?;
+    let component_ids = |type_paths: &[String]| {
+        type_paths
+            .iter()
+            .map(|type_path| {
+                let (registration, _) = get_component(&registry, type_path)?;
+                Ok(world.components().get_id(registration.type_id()))
+            })
+            .collect::<Result<Vec<Option<ComponentId>>, RemoteError>>()
+    };
+    let required = fetched
+        .iter()
+        .map(|(registration, _)| world.components().get_id(registration.type_id()))
+        .chain(component_ids(&params.with)?)
+        .collect::<Vec<_>>();
+    let excluded = component_ids(&params.without)?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+    let mut results = Vec::new();
+    for archetype in world.archetypes().iter() {
+        let matches = required
+            .iter()
+            .all(|id| id.map_or(false, |id| archetype.contains(id)))
+            && !excluded.iter().any(|&id| archetype.contains(id));
+        if !matches {
+            continue;
+        }
+        for archetype_entity in archetype.entities() {
+            let entity = archetype_entity.entity();
+            let mut values = Map::new();
+            for (registration, reflect_component) in &fetched {
+                if let Some(component) = reflect_component.reflect(world, entity) {
+                    values.insert(
+                        registration.type_path().to_string(),
+                        serialize_component(&registry, registration, component)?,
+                    );
+                }
+            }
+            results.push(json!({
+                "entity": entity.to_bits(),
+                "components": values,
+            }));
+        }
+    }
+    Ok(Value::Array(results))
+}
+
+fn spawn_scene(
+    world: &mut World,
+    params: SpawnSceneParams,
+) -> Result<(String, Handle<DynamicScene>, InstanceId), RemoteError> {
+    let handle = world
+        .get_resource::<AssetServer>()
+        .ok_or(RemoteError::ScenesUnavailable)?
+        .load(params.path.as_str());
+    let instance = world
+        .get_resource_mut::<SceneSpawner>()
+        .ok_or(RemoteError::ScenesUnavailable)?
+        .spawn_dynamic(handle.clone());
+    Ok((params.path, handle, instance))
+}
+
+/// Answers `spawn_scene` requests with the spawned entities, or an error if the scene failed
+/// to load.
+fn answer_spawned_scenes(world: &mut World) {
+    world.resource_scope(|world, mut pending: Mut<PendingScenes>| {
+        if pending.0.is_empty() {
+            return;
+        }
+        let scene_spawner = world.resource::<SceneSpawner>();
+        let asset_server = world.resource::<AssetServer>();
+        pending.0.retain(|scene| {
+            let result = if scene_spawner.instance_is_ready(scene.instance) {
+                let mut entities = scene_spawner
+                    .iter_instance_entities(scene.instance)
+                    .map(Entity::to_bits)
+                    .collect::<Vec<_>>();
+                entities.sort_unstable();
+                Ok(json!({ "entities": entities }))
+            } else if asset_server.get_load_state(&scene.handle) == LoadState::Failed {
+                Err(RemoteError::SceneLoad(scene.path.clone()))
+            } else {
+                return true;
+            };
+            let _ = scene.respond.send(result);
+            false
+        });
+    });
+}
+
+fn get_entity(world: &World, bits: u64) -> Result<Entity, RemoteError> {
+    let entity = Entity::from_bits(bits);
+    match world.get_entity(entity) {
+        Some(_) => Ok(entity),
+        None => Err(RemoteError::NoSuchEntity(bits)),
+    }
+}
+
+/// Finds a registered component by type path, or by short type path if it is unambiguous.
+fn get_component<'r>(
+    registry: &'r TypeRegistry,
+    type_path: &str,
+) -> Result<(&'r TypeRegistration, &'r ReflectComponent), RemoteError> {
+    registry
+        .get_with_type_path(type_path)
+        .or_else(|| registry.get_with_short_type_path(type_path))
+        .and_then(|registration| Some((registration, registration.data::<ReflectComponent>()?)))
+        .ok_or_else(|| RemoteError::UnregisteredComponent(type_path.to_string()))
+}
+
+fn serialize_component(
+    registry: &TypeRegistry,
+    registration: &TypeRegistration,
+    component: &dyn Reflect,
+) -> Result<Value, RemoteError> {
+    serde_json::to_value(TypedReflectSerializer::new(component, registry)).map_err(|error| {
+        RemoteError::Serialization {
+            type_path: registration.type_path().to_string(),
+            error,
+        }
+    })
+}
+
+type DeserializedComponent<'r> = (&'r TypeRegistration, &'r ReflectComponent, Box<dyn Reflect>);
+
+fn deserialize_components<'r>(
+    registry: &'r TypeRegistry,
+    values: Map<String, Value>,
+) -> Result<Vec<DeserializedComponent<'r>>, RemoteError> {
+    values
+        .into_iter()
+        .map(|(type_path, value)| {
+            let (registration, reflect_component) = get_component(registry, &type_path)?;
+            let component = TypedReflectDeserializer::new(registration, registry)
+                .deserialize(value)
+                .map_err(|error| RemoteError::InvalidValue { type_path, error })?;
+            Ok((registration, reflect_component, component))
+        })
+        .collect()
+}
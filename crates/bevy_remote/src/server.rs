@@ -0,0 +1,295 @@
+use crate::RemoteError;
+use bevy_ecs::system::Resource;
+use bevy_utils::tracing::warn;
+use crossbeam_channel::{Receiver, Sender, TryRecvError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    thread::{self, JoinHandle},
+};
+
+/// The socket opened by the [`RemotePlugin`](crate::RemotePlugin).
+///
+/// Connections are served on background threads, which forward each request to the app and
+/// wait for its response. Requests are answered by the world once per frame.
+///
+/// Dropping the server closes the socket and its connections, and waits for their threads.
+#[derive(Resource)]
+pub struct RemoteServer {
+    address: SocketAddr,
+    requests: Receiver<RemoteRequest>,
+    /// Never sends anything, its threads stop once it is disconnected.
+    shutdown: Option<Sender<()>>,
+    listener: Option<JoinHandle<()>>,
+}
+
+impl RemoteServer {
+    /// Opens a socket on the given address and starts accepting connections.
+    pub fn bind(address: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        let address = listener.local_addr()?;
+        let (sender, requests) = crossbeam_channel::unbounded();
+        let (shutdown, shutdown_receiver) = crossbeam_channel::bounded(0);
+        let listener = thread::Builder::new()
+            .name("remote listener".to_string())
+            .spawn(move || accept_connections(&listener, &sender, &shutdown_receiver))?;
+        Ok(Self {
+            address,
+            requests,
+            shutdown: Some(shutdown),
+            listener: Some(listener),
+        })
+    }
+
+    /// Returns the address the socket is bound to.
+    ///
+    /// This is useful to find the port picked by the system when binding on port 0.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Returns the requests received since the last call.
+    pub(crate) fn receive(&self) -> impl Iterator<Item = RemoteRequest> + '_ {
+        self.requests.try_iter()
+    }
+}
+
+impl Drop for RemoteServer {
+    fn drop(&mut self) {
+        self.shutdown = None;
+        // Drops the unanswered requests, so their connections stop waiting for a response.
+        self.requests = crossbeam_channel::never();
+
+        // Wakes the listener up from `accept`, so it notices the shutdown.
+        let mut address = self.address;
+        if address.ip().is_unspecified() {
+            address.set_ip(match address {
+                SocketAddr::V4(_) => Ipv4Addr::LOCALHOST.into(),
+                SocketAddr::V6(_) => Ipv6Addr::LOCALHOST.into(),
+            });
+        }
+        if let Err(err) = TcpStream::connect(address) {
+            warn!("Failed to stop the remote listener: {}", err);
+            return;
+        }
+        if let Some(listener) = self.listener.take() {
+            if listener.join().is_err() {
+                warn!("The remote listener panicked");
+            }
+        }
+    }
+}
+
+/// A request waiting to be answered by the world.
+pub(crate) struct RemoteRequest {
+    pub method: String,
+    pub params: Value,
+    pub respond: Sender<Result<Value, RemoteError>>,
+}
+
+/// A JSON-RPC 2.0 request, or a notification if it has no `id`.
+#[derive(Deserialize)]
+struct RpcRequest {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Serialize)]
+struct RpcResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(flatten)]
+    outcome: RpcOutcome,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+enum RpcOutcome {
+    Result(Value),
+    Error(RpcError),
+}
+
+#[derive(Serialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcResponse {
+    fn new(id: Value, result: Result<Value, RemoteError>) -> Self {
+        let outcome = match result {
+            Ok(value) => RpcOutcome::Result(value),
+            Err(err) => RpcOutcome::Error(RpcError {
+                code: err.code(),
+                message: err.to_string(),
+            }),
+        };
+        Self {
+            jsonrpc: "2.0",
+            id,
+            outcome,
+        }
+    }
+}
+
+fn is_shut_down(shutdown: &Receiver<()>) -> bool {
+    shutdown.try_recv() == Err(TryRecvError::Disconnected)
+}
+
+/// Serves each connection on its own thread until the server is dropped, then closes them.
+fn accept_connections(
+    listener: &TcpListener,
+    requests: &Sender<RemoteRequest>,
+    shutdown: &Receiver<()>,
+) {
+    let mut connections: Vec<(TcpStream, JoinHandle<()>)> = Vec::new();
+    for stream in listener.incoming() {
+        if is_shut_down(shutdown) {
+            break;
+        }
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(err) => {
+                warn!("Failed to accept a remote connection: {}", err);
+                continue;
+            }
+        };
+        let connection = match stream.try_clone() {
+            Ok(connection) => connection,
+            Err(err) => {
+                warn!("Failed to serve a remote connection: {}", err);
+                continue;
+            }
+        };
+        connections.retain(|(_, thread)| !thread.is_finished());
+
+        let requests = requests.clone();
+        let shutdown = shutdown.clone();
+        let spawned = thread::Builder::new()
+            .name("remote connection".to_string())
+            .spawn(move || {
+                if let Err(err) = serve_connection(connection, &requests, &shutdown) {
+                    warn!("Remote connection closed: {}", err);
+                }
+            });
+        match spawned {
+            Ok(thread) => connections.push((stream, thread)),
+            Err(err) => warn!("Failed to serve a remote connection: {}", err),
+        }
+    }
+
+    for (stream, thread) in connections {
+        // Ends the connections waiting for their client.
+        let _ = stream.shutdown(Shutdown::Both);
+        if thread.join().is_err() {
+            warn!("A remote connection panicked");
+        }
+    }
+}
+
+/// Answers the requests of a client, one per line, until it disconnects or the server is dropped.
+fn serve_connection(
+    stream: TcpStream,
+    requests: &Sender<RemoteRequest>,
+    shutdown: &Receiver<()>,
+) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let request = match parse_request(&line) {
+            Ok(request) => request,
+            Err((id, err)) => {
+                write_response(&mut writer, RpcResponse::new(id, Err(err)))?;
+                continue;
+            }
+        };
+
+        let (respond, response) = crossbeam_channel::bounded(1);
+        let sent = requests.send(RemoteRequest {
+            method: request.method,
+            params: request.params,
+            respond,
+        });
+        if sent.is_err() {
+            return Ok(());
+        }
+        // Requests still waiting for the world are dropped with the server.
+        let result = crossbeam_channel::select! {
+            recv(response) -> result => match result {
+                Ok(result) => result,
+                Err(_) => return Ok(()),
+            },
+            recv(shutdown) -> _ => return Ok(()),
+        };
+
+        // Notifications are executed without being answered.
+        if let Some(id) = request.id {
+            write_response(&mut writer, RpcResponse::new(id, result))?;
+        }
+    }
+    Ok(())
+}
+
+fn parse_request(line: &str) -> Result<RpcRequest, (Value, RemoteError)> {
+    let value: Value =
+        serde_json::from_str(line).map_err(|err| (Value::Null, RemoteError::Parse(err)))?;
+    let id = value.get("id").cloned().unwrap_or(Value::Null);
+    let request: RpcRequest = serde_json::from_value(value)
+        .map_err(|err| (id.clone(), RemoteError::InvalidRequest(err.to_string())))?;
+    if request.jsonrpc != "2.0" {
+        return Err((
+            id,
+            RemoteError::InvalidRequest(format!(
+                "unsupported JSON-RPC version `{}`",
+                request.jsonrpc
+            )),
+        ));
+    }
+    Ok(request)
+}
+
+fn write_response(writer: &mut TcpStream, response: RpcResponse) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, &response)?;
+    writer.write_all(b"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RemoteServer;
+    use std::{
+        io::{Read, Write},
+        net::{SocketAddr, TcpListener, TcpStream},
+        thread,
+    };
+
+    #[test]
+    fn drop_closes_connections() {
+        let server = RemoteServer::bind(SocketAddr::from(([127, 0, 0, 1], 0))).unwrap();
+        let address = server.local_addr();
+        let idle = TcpStream::connect(address).unwrap();
+        let mut waiting = TcpStream::connect(address).unwrap();
+        waiting
+            .write_all(b"{\"jsonrpc\": \"2.0\", \"id\": 1, \"method\": \"list_entities\"}\n")
+            .unwrap();
+        // The request is never answered, its connection waits for the world.
+        while server.requests.is_empty() {
+            thread::yield_now();
+        }
+
+        drop(server);
+        for mut stream in [idle, waiting] {
+            assert_eq!(stream.read(&mut [0; 16]).unwrap(), 0);
+        }
+        TcpListener::bind(address).unwrap();
+    }
+}
@@ -65,6 +65,8 @@ pub struct ExtractState<P: SystemParam + 'static> {
 // SAFETY: only accesses MainWorld resource with read only system params using ResState,
 // which is initialized in init()
 unsafe impl<P: SystemParam + 'static> SystemParamState for ExtractState<P> {
+    const HAS_DEFERRED: bool = false;
+
     fn init(world: &mut World, system_meta: &mut SystemMeta) -> Self {
         let mut main_world = world.resource_mut::<MainWorld>();
         Self {
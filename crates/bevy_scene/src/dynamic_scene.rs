@@ -1,12 +1,13 @@
 use crate::{serde::SceneSerializer, DynamicSceneBuilder, Scene, SceneSpawnError};
 use anyhow::Result;
 use bevy_app::AppTypeRegistry;
+use bevy_asset::{resolve_serialized_handles, AssetServer, HandleUntyped};
 use bevy_ecs::{
     entity::EntityMap,
     reflect::{ReflectComponent, ReflectMapEntities},
     world::World,
 };
-use bevy_reflect::{Reflect, TypeRegistryArc, TypeUuid};
+use bevy_reflect::{Reflect, TypePath, TypeRegistryArc, TypeUuid};
 use serde::Serialize;
 
 /// A collection of serializable dynamic entities, each with its own run-time defined set of components.
@@ -16,7 +17,7 @@ use serde::Serialize;
 /// * adding the [`Handle<DynamicScene>`](bevy_asset::Handle) to an entity (the scene will only be
 /// visible if the entity already has [`Transform`](bevy_transform::components::Transform) and
 /// [`GlobalTransform`](bevy_transform::components::GlobalTransform) components)
-#[derive(Default, TypeUuid)]
+#[derive(Default, TypeUuid, TypePath)]
 #[uuid = "749479b1-fb8c-4ff8-a775-623aa76014f5"]
 pub struct DynamicScene {
     pub entities: Vec<DynamicEntity>,
@@ -82,10 +83,21 @@ impl DynamicScene {
                         }
                     })?;
 
+                // Handles serialized as asset paths are loaded again. Without an asset
+                // server, they still refer to the asset with the same path.
+                let component = match world.get_resource::<AssetServer>() {
+                    Some(asset_server) => resolve_serialized_handles(&**component, |path| {
+                        asset_server.load_untyped(path.clone())
+                    }),
+                    None => resolve_serialized_handles(&**component, |path| {
+                        HandleUntyped::weak(path.get_id().into())
+                    }),
+                };
+
                 // If the entity already has the given component attached,
                 // just apply the (possibly) new value, otherwise add the
                 // component to the entity.
-                reflect_component.apply_or_insert(world, entity, &**component);
+                reflect_component.apply_or_insert(world, entity, &*component);
             }
         }
 
@@ -116,9 +128,29 @@ impl DynamicScene {
 
     // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into rust object notation (ron).
+    ///
+    /// Scenes extracted from a world with an [`AssetServer`] serialize handles to assets loaded
+    /// from a path as their [`AssetPath`](bevy_asset::AssetPath), see
+    /// [`DynamicSceneBuilder`]. Other handles are serialized as their
+    /// [`HandleId`](bevy_asset::HandleId).
     pub fn serialize_ron(&self, registry: &TypeRegistryArc) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into rust object notation (ron), with handles to assets loaded
+    /// from a path serialized as their [`AssetPath`](bevy_asset::AssetPath).
+    ///
+    /// This is only needed for scenes that weren't extracted from a world with an
+    /// [`AssetServer`], such as scenes built by hand. Unlike the [`HandleId`](bevy_asset::HandleId) of an asset, its path can be used to load it
+    /// again in another session: loading the resulting scene with the [`AssetServer`] also loads
+    /// the assets it refers to.
+    pub fn serialize_ron_with_asset_paths(
+        &self,
+        registry: &TypeRegistryArc,
+        asset_server: &AssetServer,
+    ) -> Result<String, ron::Error> {
+        serialize_ron(SceneSerializer::new(self, registry).with_asset_server(asset_server))
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
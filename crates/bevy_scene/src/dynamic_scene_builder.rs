@@ -1,5 +1,6 @@
 use crate::{DynamicEntity, DynamicScene};
 use bevy_app::AppTypeRegistry;
+use bevy_asset::AssetServer;
 use bevy_ecs::{prelude::Entity, reflect::ReflectComponent, world::World};
 use bevy_utils::default;
 use std::collections::BTreeMap;
@@ -12,6 +13,12 @@ use std::collections::BTreeMap;
 /// This means that inserting `Entity(1v0)` then `Entity(0v0)` will always result in the entities
 /// being ordered as `[Entity(0v0), Entity(1v0)]`.
 ///
+/// # Asset handles
+///
+/// If the world has an [`AssetServer`], handles to assets loaded from a path are extracted as
+/// [`SerializedHandle`](bevy_asset::SerializedHandle)s, so that the scene serializes them as
+/// their asset path. They are turned back into handles when the scene is written to a world.
+///
 /// # Example
 /// ```
 /// # use bevy_scene::DynamicSceneBuilder;
@@ -98,6 +105,7 @@ impl<'w> DynamicSceneBuilder<'w> {
     /// ```
     pub fn extract_entities(&mut self, entities: impl Iterator<Item = Entity>) -> &mut Self {
         let type_registry = self.type_registry.read();
+        let asset_server = self.world.get_resource::<AssetServer>();
 
         for entity in entities {
             let index = entity.index();
@@ -121,7 +129,12 @@ impl<'w> DynamicSceneBuilder<'w> {
 
                 if let Some(reflect_component) = reflect_component {
                     if let Some(component) = reflect_component.reflect(self.world, entity) {
-                        entry.components.push(component.clone_value());
+                        entry.components.push(match asset_server {
+                            Some(asset_server) => {
+                                asset_server.serialize_handles(component, &type_registry)
+                            }
+                            None => component.clone_value(),
+                        });
                     }
                 }
             }
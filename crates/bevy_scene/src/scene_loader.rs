@@ -32,8 +32,17 @@ impl AssetLoader for SceneLoader {
             let scene_deserializer = SceneDeserializer {
                 type_registry: &self.type_registry.read(),
             };
-            let scene = scene_deserializer.deserialize(&mut deserializer)?;
-            load_context.set_default_asset(LoadedAsset::new(scene));
+            let mut scene = scene_deserializer.deserialize(&mut deserializer)?;
+
+            // handles serialized as asset paths become dependencies of the scene
+            let mut dependencies = Vec::new();
+            for entity in &mut scene.entities {
+                for component in &mut entity.components {
+                    *component =
+                        load_context.resolve_serialized_handles(&**component, &mut dependencies);
+                }
+            }
+            load_context.set_default_asset(LoadedAsset::new(scene).with_dependencies(dependencies));
             Ok(())
         })
     }
@@ -54,10 +54,16 @@ pub enum SceneSpawnError {
 }
 
 impl SceneSpawner {
-    pub fn spawn_dynamic(&mut self, scene_handle: Handle<DynamicScene>) {
+    /// Queues the dynamic scene to be spawned once it is loaded.
+    ///
+    /// Like [`spawn`](Self::spawn), this returns the [`InstanceId`] of the new instance, which
+    /// can be passed to [`instance_is_ready`](Self::instance_is_ready) or
+    /// [`iter_instance_entities`](Self::iter_instance_entities).
+    pub fn spawn_dynamic(&mut self, scene_handle: Handle<DynamicScene>) -> InstanceId {
         let instance_id = InstanceId::new();
         self.dynamic_scenes_to_spawn
             .push((scene_handle, instance_id));
+        instance_id
     }
 
     pub fn spawn_dynamic_as_child(
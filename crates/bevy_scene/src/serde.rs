@@ -1,5 +1,6 @@
 use crate::{DynamicEntity, DynamicScene};
 use anyhow::Result;
+use bevy_asset::AssetServer;
 use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
 use bevy_reflect::{serde::UntypedReflectDeserializer, Reflect, TypeRegistry, TypeRegistryArc};
 use bevy_utils::HashSet;
@@ -20,11 +21,26 @@ pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 pub struct SceneSerializer<'a> {
     pub scene: &'a DynamicScene,
     pub registry: &'a TypeRegistryArc,
+    pub asset_server: Option<&'a AssetServer>,
 }
 
 impl<'a> SceneSerializer<'a> {
     pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistryArc) -> Self {
-        SceneSerializer { scene, registry }
+        SceneSerializer {
+            scene,
+            registry,
+            asset_server: None,
+        }
+    }
+
+    /// Serializes handles to assets loaded from a path as their [`AssetPath`](bevy_asset::AssetPath),
+    /// so that the scene can load them again in another session.
+    ///
+    /// See [`SerializedHandle`](bevy_asset::SerializedHandle).
+    #[must_use]
+    pub fn with_asset_server(mut self, asset_server: &'a AssetServer) -> Self {
+        self.asset_server = Some(asset_server);
+        self
     }
 }
 
@@ -39,6 +55,7 @@ impl<'a> Serialize for SceneSerializer<'a> {
             &EntitiesSerializer {
                 entities: &self.scene.entities,
                 registry: self.registry,
+                asset_server: self.asset_server,
             },
         )?;
         state.end()
@@ -48,6 +65,7 @@ impl<'a> Serialize for SceneSerializer<'a> {
 pub struct EntitiesSerializer<'a> {
     pub entities: &'a [DynamicEntity],
     pub registry: &'a TypeRegistryArc,
+    pub asset_server: Option<&'a AssetServer>,
 }
 
 impl<'a> Serialize for EntitiesSerializer<'a> {
@@ -62,6 +80,7 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
                 &EntitySerializer {
                     entity,
                     registry: self.registry,
+                    asset_server: self.asset_server,
                 },
             )?;
         }
@@ -72,6 +91,7 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
 pub struct EntitySerializer<'a> {
     pub entity: &'a DynamicEntity,
     pub registry: &'a TypeRegistryArc,
+    pub asset_server: Option<&'a AssetServer>,
 }
 
 impl<'a> Serialize for EntitySerializer<'a> {
@@ -85,6 +105,7 @@ impl<'a> Serialize for EntitySerializer<'a> {
             &ComponentsSerializer {
                 components: &self.entity.components,
                 registry: self.registry,
+                asset_server: self.asset_server,
             },
         )?;
         state.end()
@@ -94,6 +115,7 @@ impl<'a> Serialize for EntitySerializer<'a> {
 pub struct ComponentsSerializer<'a> {
     pub components: &'a [Box<dyn Reflect>],
     pub registry: &'a TypeRegistryArc,
+    pub asset_server: Option<&'a AssetServer>,
 }
 
 impl<'a> Serialize for ComponentsSerializer<'a> {
@@ -101,12 +123,27 @@ impl<'a> Serialize for ComponentsSerializer<'a> {
     where
         S: serde::Serializer,
     {
+        let registry = self.registry.read();
         let mut state = serializer.serialize_map(Some(self.components.len()))?;
         for component in self.components {
-            state.serialize_entry(
-                component.type_name(),
-                &TypedReflectSerializer::new(&**component, &self.registry.read()),
-            )?;
+            let type_path = registry
+                .get_with_value(&**component)
+                .map_or(component.type_name(), |registration| {
+                    registration.type_path()
+                });
+            match self.asset_server {
+                Some(asset_server) => state.serialize_entry(
+                    type_path,
+                    &TypedReflectSerializer::new(
+                        &*asset_server.serialize_handles(&**component, &registry),
+                        &registry,
+                    ),
+                )?,
+                None => state.serialize_entry(
+                    type_path,
+                    &TypedReflectSerializer::new(&**component, &registry),
+                )?,
+            }
         }
         state.end()
     }
@@ -357,9 +394,11 @@ impl<'a, 'de> Visitor<'de> for ComponentVisitor<'a> {
                 return Err(Error::custom(format!("duplicate component: `{key}`")));
             }
 
+            // Scenes saved before type paths were used are keyed by type name
             let registration = self
                 .registry
-                .get_with_name(key)
+                .get_with_type_path(key)
+                .or_else(|| self.registry.get_with_name(key))
                 .ok_or_else(|| Error::custom(format!("no registration found for `{key}`")))?;
             components.push(
                 map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?,
@@ -388,10 +427,11 @@ impl<'a, 'de> Visitor<'de> for ComponentVisitor<'a> {
 mod tests {
     use crate::serde::{SceneDeserializer, SceneSerializer};
     use crate::{DynamicScene, DynamicSceneBuilder};
-    use bevy_app::AppTypeRegistry;
-    use bevy_ecs::entity::EntityMap;
+    use bevy_app::{App, AppTypeRegistry};
+    use bevy_asset::{AddAsset, AssetPlugin, AssetServer, Handle};
+    use bevy_ecs::entity::{Entity, EntityMap};
     use bevy_ecs::prelude::{Component, ReflectComponent, World};
-    use bevy_reflect::{FromReflect, Reflect, ReflectSerialize};
+    use bevy_reflect::{FromReflect, Reflect, ReflectSerialize, TypePath, TypeUuid};
     use bincode::Options;
     use serde::de::DeserializeSeed;
 
@@ -609,6 +649,59 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_handles_as_asset_paths() {
+        #[derive(TypeUuid, TypePath)]
+        #[uuid = "0b7f9e8c-2f0e-4a8e-9a51-7d1a3c6b5e42"]
+        struct Image;
+
+        #[derive(Component, Reflect, Default)]
+        #[reflect(Component)]
+        struct Sprite {
+            image: Handle<Image>,
+        }
+
+        let mut app = App::new();
+        app.add_plugin(bevy_core::CorePlugin::default())
+            .add_plugin(AssetPlugin::default())
+            .add_asset::<Image>()
+            .register_type::<Sprite>();
+
+        let image: Handle<Image> = app.world.resource::<AssetServer>().load("sprites/hero.png");
+        let entity = app
+            .world
+            .spawn(Sprite {
+                image: image.clone_weak(),
+            })
+            .id();
+
+        let registry = app.world.resource::<AppTypeRegistry>().clone();
+        let scene = DynamicScene::from_world(&app.world, &registry);
+        let ron = scene.serialize_ron(&registry.0).unwrap();
+        assert!(ron.contains(r#"path: "sprites/hero.png""#));
+
+        // handles are loaded again through the asset server
+        let mut entity_map = EntityMap::default();
+        scene
+            .write_to_world(&mut app.world, &mut entity_map)
+            .unwrap();
+        let copy = entity_map.get(Entity::from_raw(entity.index())).unwrap();
+        assert_ne!(copy, entity);
+        assert_eq!(
+            app.world.get::<Sprite>(copy).unwrap().image.id(),
+            image.id()
+        );
+
+        // or refer to the asset with the same path without one
+        let mut world = World::new();
+        world.insert_resource(registry);
+        scene
+            .write_to_world(&mut world, &mut EntityMap::default())
+            .unwrap();
+        let mut sprites = world.query::<&Sprite>();
+        assert_eq!(sprites.single(&world).image.id(), image.id());
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
@@ -9,7 +9,7 @@ pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
 
-use bevy_ecs::system::{Res, ResMut};
+use bevy_ecs::system::{AsyncSystems, Res, ResMut};
 use bevy_utils::{tracing::warn, Duration, Instant};
 use crossbeam_channel::{Receiver, Sender};
 
@@ -41,7 +41,11 @@ impl Plugin for TimePlugin {
             .register_type::<Stopwatch>()
             // time system is added as an "exclusive system" to ensure it runs before other systems
             // in CoreStage::First
-            .add_system_to_stage(CoreStage::First, time_system.at_start().label(TimeSystem));
+            .add_system_to_stage(CoreStage::First, time_system.at_start().label(TimeSystem))
+            .add_system_to_stage(
+                CoreStage::First,
+                async_systems_time_system.after(TimeSystem),
+            );
     }
 }
 
@@ -106,3 +110,11 @@ fn time_system(
         }
     }
 }
+
+/// Advances the clock of the [`AsyncSystems`] by the [`Time`] delta, so that their delays follow
+/// its pause and relative speed.
+fn async_systems_time_system(time: Res<Time>, async_systems: Option<ResMut<AsyncSystems>>) {
+    if let Some(mut async_systems) = async_systems {
+        async_systems.advance(time.delta());
+    }
+}
@@ -28,7 +28,8 @@ fn noop_raw_waker() -> RawWaker {
     RawWaker::new(std::ptr::null(), &NOOP_WAKER_VTABLE)
 }
 
-fn noop_waker() -> Waker {
+/// Returns a [`Waker`] that does nothing when woken, for futures that are polled periodically.
+pub fn noop_waker() -> Waker {
     // SAFETY: the `RawWakerVTable` is just a big noop and doesn't violate any of the rules in `RawWakerVTable`s documentation
     // (which talks about retaining and releasing any "resources", of which there are none in this case)
     unsafe { Waker::from_raw(noop_raw_waker()) }
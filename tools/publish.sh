@@ -32,6 +32,7 @@ crates=(
     bevy_pbr
     bevy_gltf
     bevy_scene
+    bevy_remote
     bevy_sprite
     bevy_text
     bevy_ui